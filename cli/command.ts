@@ -98,11 +98,13 @@ programCommand('add_treasury')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .option('-a, --address <string>', 'team treasury account pubkey')
   .option('-r, --rate <number>', 'treasury distribution rate as permyraid')
+  .option('-l, --label <string>', 'treasury name for reporting')
   .action(async (directory, cmd) => {
     const {
       env,
       address,
       rate,
+      label,
     } = cmd.opts();
     console.log('Solana config: ', env);
     await setClusterConfig(env);
@@ -114,7 +116,7 @@ programCommand('add_treasury')
       console.log("Error Treasury Rate Input");
       return;
     }
-    await addTreasury(new PublicKey(address), parseInt(rate));
+    await addTreasury(new PublicKey(address), parseInt(rate), label ?? '');
 });
 
 programCommand('remove_treasury')
@@ -190,6 +192,8 @@ programCommand('list')
   .option('-a, --address <string>', 'nft mint pubkey')
   .option('-p, --price_sol <number>', 'sell sol price')
   .option('-t, --price_token <number>', 'sell token price')
+  .option('-q, --quantity <number>', 'listed units of the token', '1')
+  .option('-b, --reserved_buyer <string>', 'only buyer of a private listing')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
     const {
@@ -197,6 +201,8 @@ programCommand('list')
       address,
       price_sol,
       price_token,
+      quantity,
+      reserved_buyer,
     } = cmd.opts();
 
     console.log('Solana config: ', env);
@@ -215,7 +221,18 @@ programCommand('list')
       return;
     }
     
-    await listNftForSale(new PublicKey(address), parseFloat(price_sol) * LAMPORTS_PER_SOL, parseFloat(price_token) * ABB_TOKEN_DECIMAL);
+    if (isNaN(parseInt(quantity)) || parseInt(quantity) <= 0) {
+      console.log("Error Quantity input");
+      return;
+    }
+    
+    await listNftForSale(
+      new PublicKey(address),
+      parseFloat(price_sol) * LAMPORTS_PER_SOL,
+      parseFloat(price_token) * ABB_TOKEN_DECIMAL,
+      parseInt(quantity),
+      reserved_buyer === undefined ? PublicKey.default : new PublicKey(reserved_buyer),
+    );
 });
 
 programCommand('delist')
@@ -240,13 +257,17 @@ programCommand('delist')
 
 programCommand('purchase')
   .option('-a, --address <string>', 'nft mint pubkey')
+  .option('-s, --seller <string>', 'seller address')
   .option('-t, --by_token <number>', 'purchase nft By ABB token')
+  .option('-q, --quantity <number>', 'purchased units of the token', '1')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
     const {
       env,
       address,
+      seller,
       by_token,
+      quantity,
     } = cmd.opts();
 
     console.log('Solana config: ', env);
@@ -261,11 +282,21 @@ programCommand('purchase')
       return;
     }
     
-    await purchase(new PublicKey(address), parseInt(by_token) == 1);
+    if (seller === undefined) {
+      console.log("Error Seller input");
+      return;
+    }
+    if (isNaN(parseInt(quantity)) || parseInt(quantity) <= 0) {
+      console.log("Error Quantity input");
+      return;
+    }
+    
+    await purchase(new PublicKey(address), new PublicKey(seller), parseInt(by_token) == 1, parseInt(quantity));
 });
 
 programCommand('make_offer')
   .option('-a, --address <string>', 'nft mint pubkey')
+  .option('-s, --seller <string>', 'seller address')
   .option('-p, --price <number>', 'offer price')
   .option('-t, --by_token <number>', 'offer by token')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
@@ -273,6 +304,7 @@ programCommand('make_offer')
     const {
       env,
       address,
+      seller,
       price,
       by_token,
     } = cmd.opts();
@@ -293,17 +325,24 @@ programCommand('make_offer')
       return;
     }
     
+    if (seller === undefined) {
+      console.log("Error Seller input");
+      return;
+    }
+    
     let byToken: boolean = parseInt(by_token) == 1 ? true : false;
-    await makeOffer(new PublicKey(address), parseFloat(price) * (byToken ? ABB_TOKEN_DECIMAL : LAMPORTS_PER_SOL), byToken);
+    await makeOffer(new PublicKey(address), new PublicKey(seller), parseFloat(price) * (byToken ? ABB_TOKEN_DECIMAL : LAMPORTS_PER_SOL), byToken);
 });
 
 programCommand('cancel_offer')
   .option('-a, --address <string>', 'nft mint pubkey')
+  .option('-s, --seller <string>', 'seller address')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
     const {
       env,
       address,
+      seller,
     } = cmd.opts();
 
     console.log('Solana config: ', env);
@@ -314,7 +353,12 @@ programCommand('cancel_offer')
       return;
     }
     
-    await cancelOffer(new PublicKey(address));
+    if (seller === undefined) {
+      console.log("Error Seller input");
+      return;
+    }
+    
+    await cancelOffer(new PublicKey(address), new PublicKey(seller));
 });
 
 programCommand('accept_offer')
@@ -398,12 +442,14 @@ programCommand('create_auction')
 programCommand('place_bid')
   .option('-a, --address <string>', 'nft mint pubkey')
   .option('-p, --price <number>', 'auction price')
+  .option('-e, --by_escrow <number>', 'bid from the escrow balance', '0')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
     const {
       env,
       address,
       price,
+      by_escrow,
     } = cmd.opts();
 
     console.log('Solana config: ', env);
@@ -418,7 +464,7 @@ programCommand('place_bid')
       return;
     }
     
-    await placeBid(new PublicKey(address), parseFloat(price) * 1e9);
+    await placeBid(new PublicKey(address), parseFloat(price) * 1e9, parseInt(by_escrow) == 1);
 });
 
 
@@ -464,31 +510,35 @@ programCommand('cancel_auction')
 
 programCommand('listed_nft_data')
   .option('-a, --address <string>', 'nft mint pubkey')
+  .option('-s, --seller <string>', 'seller address pubkey')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
     const {
       env,
       address,
+      seller,
     } = cmd.opts();
 
     console.log('Solana config: ', env);
     await setClusterConfig(env);
 
-    if (address === undefined) {
+    if (address === undefined || seller === undefined) {
       console.log("Error input");
       return;
     }
-    console.log(await getNFTPoolInfo(new PublicKey(address)));
+    console.log(await getNFTPoolInfo(new PublicKey(address), new PublicKey(seller)));
 });
 
 programCommand('get_offer_data')
   .option('-a, --address <string>', 'nft mint pubkey')
+  .option('-s, --seller <string>', 'seller address pubkey')
   .option('-b, --buyer <string>', 'buyer address pubkey')
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
     const {
       env,
       address,
+      seller,
       buyer,
     } = cmd.opts();
 
@@ -503,7 +553,11 @@ programCommand('get_offer_data')
       console.log("Error Buyer input");
       return;
     }
-    console.log(await getOfferDataInfo(new PublicKey(address), new PublicKey(buyer)));
+    if (seller === undefined) {
+      console.log("Error Seller input");
+      return;
+    }
+    console.log(await getOfferDataInfo(new PublicKey(address), new PublicKey(seller), new PublicKey(buyer)));
 });
 
 programCommand('get_auction_data')
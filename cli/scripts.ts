@@ -7,7 +7,7 @@ import fs from 'fs';
 import path from 'path';
 import NodeWallet from '@project-serum/anchor/dist/cjs/nodewallet';
 
-import { AuctionData, AUCTION_DATA_SEED, GlobalPool, GLOBAL_AUTHORITY_SEED, MARKETPLACE_PROGRAM_ID, OfferData, OFFER_DATA_SEED, SellData, SELL_DATA_SEED, UserData, USER_DATA_SEED } from '../lib/types';
+import { AuctionData, GlobalPool, GLOBAL_AUTHORITY_SEED, MARKETPLACE_PROGRAM_ID, OfferData, SellData, TreasuryConfig, UserData } from '../lib/types';
 import {IDL as MarketplaceIDL} from "../target/types/astro_marketplace";
 import {
     createAcceptOfferTx,
@@ -18,10 +18,7 @@ import {
     createCreateAuctionTx,
     createDelistNftTx,
     createDepositTx,
-    createInitAuctionDataTx,
     createInitializeTx,
-    createInitOfferDataTx,
-    createInitSellDataTx,
     createInitUserTx,
     createListForSellNftTx,
     createMakeOfferTx,
@@ -37,6 +34,7 @@ import {
     getGlobalState,
     getNFTPoolState,
     getOfferDataState,
+    getTreasuryConfigState,
     getUserPoolState
 } from '../lib/scripts';
 import { isInitializedUser } from '../lib/utils';
@@ -116,19 +114,6 @@ export const initProject = async (
     console.log("txHash =", txId);
 }
 
-export const initSellData = async (
-    mint: PublicKey,
-) => {
-    const tx = await createInitSellDataTx(mint, payer.publicKey, program);
-    const {blockhash} = await solConnection.getRecentBlockhash('finalized');
-    tx.feePayer = payer.publicKey;
-    tx.recentBlockhash = blockhash;
-    payer.signTransaction(tx);
-    let txId = await solConnection.sendTransaction(tx, [(payer as NodeWallet).payer]);
-    await solConnection.confirmTransaction(txId, "finalized");
-    console.log("Your transaction signature", txId);
-}
-
 export const initUserPool = async () => {
     const tx = await createInitUserTx(payer.publicKey, program);
     const {blockhash} = await solConnection.getRecentBlockhash('finalized');
@@ -140,19 +125,6 @@ export const initUserPool = async () => {
     console.log("Your transaction signature", txId);
 }
 
-export const initAuctionData = async (
-    mint: PublicKey,
-) => {
-    const tx = await createInitAuctionDataTx(mint, payer.publicKey, program);
-    const {blockhash} = await solConnection.getRecentBlockhash('finalized');
-    tx.feePayer = payer.publicKey;
-    tx.recentBlockhash = blockhash;
-    payer.signTransaction(tx);
-    let txId = await solConnection.sendTransaction(tx, [(payer as NodeWallet).payer]);
-    await solConnection.confirmTransaction(txId, "finalized");
-    console.log("Your transaction signature", txId);
-}
-
 export const updateFee = async (
     solFee: number,
     tokenFee: number,
@@ -171,10 +143,11 @@ export const updateFee = async (
 export const addTreasury = async (
     treasury: PublicKey,
     rate: number,
+    label: string,
 ) => {
-    console.log(treasury.toBase58(), rate);
+    console.log(treasury.toBase58(), rate, label);
 
-    const tx = await createAddTreasuryTx(payer.publicKey, treasury, rate, program, solConnection);
+    const tx = await createAddTreasuryTx(payer.publicKey, treasury, rate, label, program, solConnection);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -247,26 +220,17 @@ export const listNftForSale = async (
     mint: PublicKey,
     priceSol: number,
     priceToken: number,
+    quantity: number,
+    reservedBuyer: PublicKey,
 ) => {
-    console.log(mint.toBase58(), priceSol, priceToken);
+    console.log(mint.toBase58(), priceSol, priceToken, quantity, reservedBuyer.toBase58());
 
     if (!await isInitializedUser(payer.publicKey, solConnection)) {
         console.log('User PDA is not Initialized. Should Init User PDA for first usage');
         return;
     }
 
-    const [sellData, _] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
-        MARKETPLACE_PROGRAM_ID,
-    );
-    console.log('Sell Data PDA: ', sellData.toBase58());
-
-    let poolAccount = await solConnection.getAccountInfo(sellData);
-    if (poolAccount === null || poolAccount.data === null) {
-        await initSellData(mint);
-    }
-
-    const tx = await createListForSellNftTx(mint, payer.publicKey, program, solConnection, priceSol, priceToken);
+    const tx = await createListForSellNftTx(mint, payer.publicKey, program, solConnection, priceSol, priceToken, 0, quantity, reservedBuyer);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -298,18 +262,18 @@ export const delistNft = async (
 
 export const purchase = async (
     mint: PublicKey,
+    seller: PublicKey,
     byToken: boolean,
+    quantity: number,
 ) => {
-    console.log(mint.toBase58(), byToken);
+    console.log(mint.toBase58(), seller.toBase58(), byToken, quantity);
     
     if (!await isInitializedUser(payer.publicKey, solConnection)) {
         console.log('User PDA is not Initialized. Should Init User PDA for first usage');
         return;
     }
 
-    const globalPool: GlobalPool = await getGlobalState(program);
-
-    const tx = await createPurchaseTx(mint, payer.publicKey, byToken, globalPool.teamTreasury.slice(0, globalPool.teamCount.toNumber()), program, solConnection);
+    const tx = await createPurchaseTx(mint, seller, payer.publicKey, byToken, quantity, program, solConnection);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -319,43 +283,20 @@ export const purchase = async (
     console.log("Your transaction signature", txId);
 }
 
-export const initOfferData = async (
-    mint: PublicKey,
-) => {
-    const tx = await createInitOfferDataTx(mint, payer.publicKey, program);
-    const {blockhash} = await solConnection.getRecentBlockhash('finalized');
-    tx.feePayer = payer.publicKey;
-    tx.recentBlockhash = blockhash;
-    payer.signTransaction(tx);
-    let txId = await solConnection.sendTransaction(tx, [(payer as NodeWallet).payer]);
-    await solConnection.confirmTransaction(txId, "finalized");
-    console.log("Your transaction signature", txId);
-}
-
 export const makeOffer = async (
     mint: PublicKey,
+    seller: PublicKey,
     price: number,
     byToken: boolean,
 ) => {
-    console.log(mint.toBase58(), price, byToken);
+    console.log(mint.toBase58(), seller.toBase58(), price, byToken);
 
     if (!await isInitializedUser(payer.publicKey, solConnection)) {
         console.log('User PDA is not Initialized. Should Init User PDA for first usage');
         return;
     }
 
-    const [offerData, _] = await PublicKey.findProgramAddress(
-        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), payer.publicKey.toBuffer()],
-        MARKETPLACE_PROGRAM_ID,
-    );
-    console.log('Offer Data PDA: ', offerData.toBase58());
-
-    let poolAccount = await solConnection.getAccountInfo(offerData);
-    if (poolAccount === null || poolAccount.data === null) {
-        await initOfferData(mint);
-    }
-
-    const tx = await createMakeOfferTx(mint, payer.publicKey, price, byToken, program, solConnection);
+    const tx = await createMakeOfferTx(mint, seller, payer.publicKey, price, byToken, program, solConnection);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -367,15 +308,16 @@ export const makeOffer = async (
 
 export const cancelOffer = async (
     mint: PublicKey,
+    seller: PublicKey,
 ) => {
-    console.log(mint.toBase58());
+    console.log(mint.toBase58(), seller.toBase58());
 
     if (!await isInitializedUser(payer.publicKey, solConnection)) {
         console.log('User PDA is not Initialized. Should Init User PDA for first usage');
         return;
     }
 
-    const tx = await createCancelOfferTx(mint, payer.publicKey, program);
+    const tx = await createCancelOfferTx(mint, seller, payer.publicKey, program);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -396,9 +338,7 @@ export const acceptOffer = async (
         return;
     }
 
-    const globalPool: GlobalPool = await getGlobalState(program);
-
-    const tx = await createAcceptOfferTx(mint, buyer, globalPool.teamTreasury.slice(0, globalPool.teamCount.toNumber()), program, solConnection);
+    const tx = await createAcceptOfferTx(mint, payer.publicKey, buyer, program, solConnection);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -422,17 +362,6 @@ export const createAuction = async (
         return;
     }
 
-    const [auctionData, _] = await PublicKey.findProgramAddress(
-        [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-        MARKETPLACE_PROGRAM_ID,
-    );
-    console.log('Auction Data PDA: ', auctionData.toBase58());
-
-    let poolAccount = await solConnection.getAccountInfo(auctionData);
-    if (poolAccount === null || poolAccount.data === null) {
-        await initAuctionData(mint);
-    }
-
     const tx = await createCreateAuctionTx(
         mint,
         payer.publicKey,
@@ -455,15 +384,16 @@ export const createAuction = async (
 export const placeBid = async (
     mint: PublicKey,
     price: number,
+    byEscrow: boolean,
 ) => {
-    console.log(mint.toBase58(), price);
+    console.log(mint.toBase58(), price, byEscrow);
 
     if (!await isInitializedUser(payer.publicKey, solConnection)) {
         console.log('User PDA is not Initialized. Should Init User PDA for first usage');
         return;
     }
 
-    const tx = await createPlaceBidTx(mint, payer.publicKey, price, program, solConnection);
+    const tx = await createPlaceBidTx(mint, payer.publicKey, price, byEscrow, program, solConnection);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -483,9 +413,7 @@ export const claimAuction = async (
         return;
     }
 
-    const globalPool: GlobalPool = await getGlobalState(program);
-
-    const tx = await createClaimAuctionTx(mint, payer.publicKey, globalPool.teamTreasury.slice(0, globalPool.teamCount.toNumber()), program, solConnection);
+    const tx = await createClaimAuctionTx(mint, payer.publicKey, program, solConnection);
     const {blockhash} = await solConnection.getRecentBlockhash('confirmed');
     tx.feePayer = payer.publicKey;
     tx.recentBlockhash = blockhash;
@@ -517,8 +445,9 @@ export const cancelAuction = async (
 
 export const getNFTPoolInfo = async (
     mint: PublicKey,
+    seller: PublicKey,
 ) => {
-    const nftData: SellData = await getNFTPoolState(mint, program);
+    const nftData: SellData = await getNFTPoolState(mint, seller, program);
     return {
       mint: nftData.mint.toBase58(),
       seller: nftData.seller.toBase58(),
@@ -527,17 +456,21 @@ export const getNFTPoolInfo = async (
       priceToken: nftData.priceToken.toNumber(),
       listedDate: nftData.listedDate.toNumber(),
       active: nftData.active.toNumber(),
+      quantity: nftData.quantity.toNumber(),
+      reservedBuyer: nftData.reservedBuyer.toBase58(),
     };
 }
 
 export const getOfferDataInfo = async (
     mint: PublicKey,
+    seller: PublicKey,
     userAddress: PublicKey,
 ) => {
-    const offerData: OfferData = await getOfferDataState(mint, userAddress, program);
+    const offerData: OfferData = await getOfferDataState(mint, seller, userAddress, program);
     return {
       mint: offerData.mint.toBase58(),
       buyer: offerData.buyer.toBase58(),
+      seller: offerData.seller.toBase58(),
       offerPrice: offerData.offerPrice.toNumber(),
       offerListingDate: offerData.offerListingDate.toNumber(),
       byToken: offerData.byToken.toNumber(),
@@ -578,13 +511,14 @@ export const getUserPoolInfo = async (
 
 export const getGlobalInfo = async () => {
     const globalPool: GlobalPool = await getGlobalState(program);
+    const treasuryConfig: TreasuryConfig = await getTreasuryConfigState(program);
     const result = {
       admin: globalPool.superAdmin.toBase58(),
       marketFeeSol: globalPool.marketFeeSol.toNumber(),
       marketFeeToken: globalPool.marketFeeToken.toNumber(),
-      teamCount: globalPool.teamCount.toNumber(),
-      teamTreasury: globalPool.teamTreasury.slice(0, globalPool.teamCount.toNumber()).map((info) => info.toBase58()),
-      treasuryRate: globalPool.treasuryRate.slice(0, globalPool.teamCount.toNumber()).map((info) => info.toNumber()),
+      primaryTreasury: globalPool.primaryTreasury.toBase58(),
+      teamTreasury: treasuryConfig.treasuries.map((info) => info.address.toBase58()),
+      treasuryRate: treasuryConfig.treasuries.map((info) => info.rate.toNumber()),
     };
 
     return result;
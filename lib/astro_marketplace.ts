@@ -15,11 +15,21 @@ export type AstroMarketplace = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "escrowVault",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -31,16 +41,7 @@ export type AstroMarketplace = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "escrowBump",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
       "name": "updateFee",
@@ -57,10 +58,6 @@ export type AstroMarketplace = {
         }
       ],
       "args": [
-        {
-          "name": "globalBump",
-          "type": "u8"
-        },
         {
           "name": "solFee",
           "type": "u64"
@@ -72,7 +69,7 @@ export type AstroMarketplace = {
       ]
     },
     {
-      "name": "addTeamTreasury",
+      "name": "updateFeeTiers",
       "accounts": [
         {
           "name": "admin",
@@ -87,21 +84,21 @@ export type AstroMarketplace = {
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "address",
-          "type": "publicKey"
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": "FeeTier"
+            }
+          }
         },
         {
-          "name": "rate",
+          "name": "bySeller",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "removeTeamTreasury",
+      "name": "updateReferralShare",
       "accounts": [
         {
           "name": "admin",
@@ -116,86 +113,79 @@ export type AstroMarketplace = {
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "address",
-          "type": "publicKey"
+          "name": "share",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "initUserPool",
+      "name": "updateFeePayer",
       "accounts": [
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userPool",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "buyerPaysFee",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "initSellData",
+      "name": "updateCollectionFallback",
       "accounts": [
         {
-          "name": "payer",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "sellDataInfo",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "creatorFallback",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateListingMode",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "globalAuthority",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "nft",
-          "type": "publicKey"
-        },
-        {
-          "name": "bump",
-          "type": "u8"
+          "name": "restricted",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "listNftForSale",
+      "name": "updateDustPolicy",
       "accounts": [
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
@@ -205,115 +195,141 @@ export type AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "sellDataInfo",
-          "isMut": true,
+          "name": "treasuryConfig",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "userTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "policy",
+          "type": "u64"
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "primaryTreasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addTeamTreasury",
+      "accounts": [
+        {
+          "name": "admin",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "nftMint",
+          "name": "globalAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mintMetadata",
+          "name": "treasuryConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "sellBump",
-          "type": "u8"
+          "name": "address",
+          "type": "publicKey"
         },
         {
-          "name": "priceSol",
+          "name": "rate",
           "type": "u64"
         },
         {
-          "name": "priceToken",
-          "type": "u64"
+          "name": "label",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "delistNft",
+      "name": "updateTeamTreasury",
       "accounts": [
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
           "name": "globalAuthority",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellDataInfo",
+          "name": "treasuryConfig",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
         },
         {
-          "name": "userTokenAccount",
+          "name": "rate",
+          "type": "u64"
+        },
+        {
+          "name": "label",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeTeamTreasury",
+      "accounts": [
+        {
+          "name": "admin",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
-          "isMut": false,
+          "name": "treasuryConfig",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "sellBump",
-          "type": "u8"
+          "name": "address",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "purchase",
+      "name": "claimTreasuryFees",
       "accounts": [
         {
-          "name": "buyer",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
@@ -323,50 +339,35 @@ export type AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "sellDataInfo",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buyerUserPool",
+          "name": "treasuryConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userNftTokenAccount",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "seller",
+          "name": "treasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerUserPool",
+          "name": "treasuryTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "paymentMint",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "userTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellerTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -379,22 +380,6 @@ export type AstroMarketplace = {
         }
       ],
       "args": [
-        {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "nftBump",
-          "type": "u8"
-        },
-        {
-          "name": "sellerBump",
-          "type": "u8"
-        },
-        {
-          "name": "buyerBump",
-          "type": "u8"
-        },
         {
           "name": "byToken",
           "type": "u8"
@@ -402,93 +387,91 @@ export type AstroMarketplace = {
       ]
     },
     {
-      "name": "depositToEscrow",
+      "name": "setCollectionFee",
       "accounts": [
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userPool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "escrowVault",
-          "isMut": true,
+          "name": "globalAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "collectionFee",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
-          "isMut": true,
+          "name": "collection",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "userBump",
-          "type": "u8"
-        },
-        {
-          "name": "escrowBump",
-          "type": "u8"
-        },
-        {
-          "name": "sol",
+          "name": "solFee",
           "type": "u64"
         },
         {
-          "name": "token",
+          "name": "tokenFee",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "withdrawFromEscrow",
+      "name": "removeCollectionFee",
       "accounts": [
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userPool",
-          "isMut": true,
+          "name": "globalAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowVault",
+          "name": "collectionFee",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerCollection",
+      "accounts": [
         {
-          "name": "userTokenAccount",
+          "name": "admin",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "escrowTokenAccount",
-          "isMut": true,
+          "name": "globalAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "collectionInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
           "isMut": false,
           "isSigner": false
         },
@@ -496,40 +479,78 @@ export type AstroMarketplace = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "userBump",
-          "type": "u8"
+          "name": "name",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
+          "name": "symbol",
+          "type": {
+            "array": [
+              "u8",
+              10
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "unregisterCollection",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "sol",
-          "type": "u64"
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "token",
-          "type": "u64"
+          "name": "collectionInfo",
+          "isMut": true,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initOfferData",
+      "name": "addDenyEntry",
       "accounts": [
         {
-          "name": "payer",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "offerDataInfo",
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "denyEntry",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "address",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -543,147 +564,195 @@ export type AstroMarketplace = {
       ],
       "args": [
         {
-          "name": "nft",
-          "type": "publicKey"
+          "name": "kind",
+          "type": "u64"
         },
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "reason",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "makeOffer",
+      "name": "removeDenyEntry",
       "accounts": [
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "sellDataInfo",
-          "isMut": true,
+          "name": "globalAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "offerDataInfo",
+          "name": "denyEntry",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveReferral",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "nftMint",
+          "name": "globalAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userPool",
+          "name": "referralData",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approved",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initUserPool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "escrowVault",
+          "name": "userPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initReferral",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "referralData",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "claimReferralFees",
+      "accounts": [
         {
-          "name": "sellBump",
-          "type": "u8"
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "offerBump",
-          "type": "u8"
+          "name": "referralData",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "userBump",
-          "type": "u8"
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "price",
-          "type": "u64"
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "byToken",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "cancelOffer",
-      "accounts": [
-        {
-          "name": "owner",
+          "name": "referrerTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "offerDataInfo",
-          "isMut": true,
+          "name": "paymentMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "offerBump",
+          "name": "byToken",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "acceptOffer",
+      "name": "listNftForSale",
       "accounts": [
         {
-          "name": "seller",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "sellDataInfo",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyer",
+          "name": "sellDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "offerDataInfo",
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerUserPool",
+          "name": "destNftTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -693,118 +762,120 @@ export type AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "globalAuthority",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buyerUserPool",
+          "name": "mintMetadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userNftTokenAccount",
-          "isMut": true,
+          "name": "collectionInfo",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
-          "isMut": true,
+          "name": "mintDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowVault",
-          "isMut": true,
+          "name": "ownerDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
+          "name": "nftTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
-          "isMut": true,
+          "name": "tokenMetadataProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "nftBump",
-          "type": "u8"
+          "name": "priceSol",
+          "type": "u64"
         },
         {
-          "name": "offerBump",
-          "type": "u8"
+          "name": "priceToken",
+          "type": "u64"
         },
         {
-          "name": "buyerBump",
+          "name": "feeMode",
           "type": "u8"
         },
         {
-          "name": "sellerBump",
-          "type": "u8"
+          "name": "quantity",
+          "type": "u64"
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
+          "name": "reservedBuyer",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "initAuctionData",
+      "name": "delistNft",
       "accounts": [
         {
-          "name": "payer",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "auctionDataInfo",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "sellDataInfo",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "nft",
-          "type": "publicKey"
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "createAuction",
+      "name": "purchase",
       "accounts": [
         {
-          "name": "owner",
+          "name": "buyer",
           "isMut": true,
           "isSigner": true
         },
@@ -814,99 +885,103 @@ export type AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "auctionDataInfo",
+          "name": "treasuryConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "sellDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "buyerUserPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
-          "isMut": false,
+          "name": "userNftTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "destNftTokenAccount",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "globalBump",
-          "type": "u8"
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "auctionBump",
-          "type": "u8"
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "startPrice",
-          "type": "u64"
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "minIncrease",
-          "type": "u64"
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "byToken",
-          "type": "u64"
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "endDate",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "placeBid",
-      "accounts": [
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
         {
-          "name": "bidder",
+          "name": "userTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "auctionDataInfo",
+          "name": "sellerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
-          "isMut": false,
+          "name": "feeVault",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowVault",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bidderTokenAccount",
-          "isMut": true,
+          "name": "mintDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
-          "isMut": true,
+          "name": "buyerDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "outBidder",
-          "isMut": true,
+          "name": "sellerDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "outBidderTokenAccount",
-          "isMut": true,
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -922,49 +997,51 @@ export type AstroMarketplace = {
       ],
       "args": [
         {
-          "name": "auctionBump",
-          "type": "u8"
-        },
-        {
-          "name": "escrowBump",
+          "name": "byToken",
           "type": "u8"
         },
         {
-          "name": "price",
+          "name": "quantity",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "claimAuction",
+      "name": "closeSellData",
       "accounts": [
         {
-          "name": "bidder",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "globalAuthority",
+          "name": "sellDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionDataInfo",
-          "isMut": true,
+          "name": "nftMint",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "depositToEscrow",
+      "accounts": [
         {
-          "name": "userTokenAccount",
+          "name": "owner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "userPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "globalAuthority",
           "isMut": false,
           "isSigner": false
         },
@@ -973,31 +1050,81 @@ export type AstroMarketplace = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "escrowTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bidderUserPool",
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sol",
+          "type": "u64"
+        },
+        {
+          "name": "token",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFromEscrow",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creatorTokenAccount",
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creatorUserPool",
+          "name": "escrowTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -1011,34 +1138,55 @@ export type AstroMarketplace = {
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "auctionBump",
-          "type": "u8"
+          "name": "sol",
+          "type": "u64"
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
+          "name": "token",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "cancelAuction",
+      "name": "makeOffer",
       "accounts": [
         {
-          "name": "creator",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "globalAuthority",
+          "name": "sellDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionDataInfo",
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
           "isMut": true,
           "isSigner": false
         },
@@ -1048,12 +1196,27 @@ export type AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "escrowTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
           "isMut": false,
           "isSigner": false
         },
@@ -1061,401 +1224,217 @@ export type AstroMarketplace = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
+          "name": "price",
+          "type": "u64"
         },
         {
-          "name": "auctionBump",
-          "type": "u8"
+          "name": "byToken",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "globalPool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "superAdmin",
-            "type": "publicKey"
-          },
-          {
-            "name": "marketFeeSol",
-            "type": "u64"
-          },
-          {
-            "name": "marketFeeToken",
-            "type": "u64"
-          },
-          {
-            "name": "teamCount",
-            "type": "u64"
-          },
-          {
-            "name": "teamTreasury",
-            "type": {
-              "array": [
-                "publicKey",
-                8
-              ]
-            }
-          },
-          {
-            "name": "treasuryRate",
-            "type": {
-              "array": [
-                "u64",
-                8
-              ]
-            }
-          }
-        ]
-      }
+      "name": "cancelOffer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "sellData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "seller",
-            "type": "publicKey"
-          },
-          {
-            "name": "collection",
-            "type": "publicKey"
-          },
-          {
-            "name": "priceSol",
-            "type": "u64"
-          },
-          {
-            "name": "priceToken",
-            "type": "u64"
-          },
-          {
-            "name": "listedDate",
-            "type": "i64"
-          },
-          {
-            "name": "active",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "acceptOffer",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "offerData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyer",
-            "type": "publicKey"
-          },
-          {
-            "name": "offerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "offerListingDate",
-            "type": "i64"
-          },
-          {
-            "name": "byToken",
-            "type": "u64"
-          },
-          {
-            "name": "active",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "closeOfferData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "auctionData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "startPrice",
-            "type": "u64"
-          },
-          {
-            "name": "minIncreaseAmount",
-            "type": "u64"
-          },
-          {
-            "name": "byToken",
-            "type": "u64"
-          },
-          {
-            "name": "endDate",
-            "type": "i64"
-          },
-          {
-            "name": "lastBidDate",
-            "type": "i64"
-          },
-          {
-            "name": "lastBidder",
-            "type": "publicKey"
-          },
-          {
-            "name": "highestBid",
-            "type": "u64"
-          },
-          {
-            "name": "status",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "userData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "publicKey"
-          },
-          {
-            "name": "tradedVolume",
-            "type": "u64"
-          },
-          {
-            "name": "tradedTokenVolume",
-            "type": "u64"
-          },
-          {
-            "name": "escrowSolBalance",
-            "type": "u64"
-          },
-          {
-            "name": "escrowTokenBalance",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidSuperOwner",
-      "msg": "Invalid Super Owner"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidOwner",
-      "msg": "Invalid Owner"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidGlobalPool",
-      "msg": "Invalid Global Pool Address"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidFeePercent",
-      "msg": "Marketplace Fee is Permyriad"
-    },
-    {
-      "code": 6004,
-      "name": "MaxTeamCountExceed",
-      "msg": "Max Team Count is 8"
-    },
-    {
-      "code": 6005,
-      "name": "NoTeamTreasuryYet",
-      "msg": "Treasury Wallet Not Configured"
-    },
-    {
-      "code": 6006,
-      "name": "TreasuryAddressNotFound",
-      "msg": "Treasury Address Not Exist"
-    },
-    {
-      "code": 6007,
-      "name": "TreasuryAddressAlreadyAdded",
-      "msg": "Treasury Address Already Exist"
-    },
-    {
-      "code": 6008,
-      "name": "MaxTreasuryRateSumExceed",
-      "msg": "Total Treasury Rate Sum Should Less Than 100%"
-    },
-    {
-      "code": 6009,
-      "name": "TeamTreasuryCountMismatch",
-      "msg": "Team Treasury Wallet Count Mismatch"
-    },
-    {
-      "code": 6010,
-      "name": "TeamTreasuryAddressMismatch",
-      "msg": "Team Treasury Wallet Address Mismatch"
-    },
-    {
-      "code": 6011,
-      "name": "Uninitialized",
-      "msg": "Uninitialized Account"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidParamInput",
-      "msg": "Instruction Parameter is Invalid"
-    },
-    {
-      "code": 6013,
-      "name": "SellerMismatch",
-      "msg": "Payer Mismatch with NFT Seller"
-    },
-    {
-      "code": 6014,
-      "name": "InvalidNFTDataAcount",
-      "msg": "Invalid NFT Data Account"
-    },
-    {
-      "code": 6015,
-      "name": "NotListedNFT",
-      "msg": "The NFT Is Not Listed"
-    },
-    {
-      "code": 6016,
-      "name": "SellerAccountMismatch",
-      "msg": "Seller Account Mismatch with NFT Seller Data"
-    },
-    {
-      "code": 6017,
-      "name": "InsufficientBuyerSolBalance",
-      "msg": "Buyer Sol Balance is Less than NFT SOL Price"
-    },
-    {
-      "code": 6018,
-      "name": "InsufficientBuyerTokenBalance",
-      "msg": "Buyer Token Balance is Less than NFT Token Price"
-    },
-    {
-      "code": 6019,
-      "name": "InvaliedMetadata",
-      "msg": "Invalid Metadata Address"
-    },
-    {
-      "code": 6020,
-      "name": "MetadataCreatorParseError",
-      "msg": "Can't Parse The NFT's Creators"
-    },
-    {
-      "code": 6021,
-      "name": "InvalidOfferDataMint",
-      "msg": "Offer Data Mint mismatch with NFT Pubkey"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidOfferDataBuyer",
-      "msg": "Offer Data Buyer mismatch with Payer Pubkey"
-    },
-    {
-      "code": 6023,
-      "name": "OfferForNotListedNFT",
-      "msg": "Making Offer for Not Listed NFT"
-    },
-    {
-      "code": 6024,
-      "name": "InvalidOfferPrice",
-      "msg": "Offer Price Over Thank Listed Price"
-    },
-    {
-      "code": 6025,
-      "name": "DisabledOffer",
-      "msg": "Already Canceled Offer"
-    },
-    {
-      "code": 6026,
-      "name": "OfferForExpiredListingNFT",
-      "msg": "Offer For Sold Or Canceled NFT Listing"
-    },
-    {
-      "code": 6027,
-      "name": "EndedAuction",
-      "msg": "Placing Bid For Ended Auction"
-    },
-    {
-      "code": 6028,
-      "name": "InvalidBidPrice",
-      "msg": "Placing Bid With Lower Than Highest Bid"
-    },
-    {
-      "code": 6029,
-      "name": "DoubleBidFromOneBidder",
-      "msg": "Placing Bid Double From One Bidder"
-    },
-    {
-      "code": 6030,
-      "name": "OutBidderMismatch",
-      "msg": "Out Bidder Account Mismatch With LastBidder Data"
-    },
-    {
-      "code": 6031,
-      "name": "NotEndedAuction",
-      "msg": "Claiming Auction For Not Ended Auction"
-    },
-    {
-      "code": 6032,
-      "name": "CreatorAccountMismatch",
-      "msg": "Creator Account Mismatch with Auction Data"
-    },
-    {
-      "code": 6033,
-      "name": "BidderAccountMismatch",
-      "msg": "Bidder Account Mismatch with Auction Data"
-    },
-    {
-      "code": 6034,
-      "name": "AuctionHasBid",
-      "msg": "Canceling Auction which has Bid"
-    },
-    {
-      "code": 6035,
-      "name": "BidFromAuctionCreator",
-      "msg": "Placing Bid From Auction Creator"
-    }
-  ]
-};
-
-export const IDL: AstroMarketplace = {
-  "version": "0.1.0",
-  "name": "astro_marketplace",
-  "instructions": [
-    {
-      "name": "initialize",
+      "name": "listCompressedNft",
       "accounts": [
         {
-          "name": "admin",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
@@ -1465,126 +1444,58 @@ export const IDL: AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "escrowVault",
+          "name": "sellDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "assetId",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "collectionInfo",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "globalBump",
-          "type": "u8"
+          "name": "assetDenyEntry",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "updateFee",
-      "accounts": [
-        {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "globalAuthority",
-          "isMut": true,
+          "name": "ownerDenyEntry",
+          "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "solFee",
-          "type": "u64"
-        },
-        {
-          "name": "tokenFee",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "addTeamTreasury",
-      "accounts": [
-        {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
         },
         {
-          "name": "globalAuthority",
-          "isMut": true,
+          "name": "treeAuthority",
+          "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "globalBump",
-          "type": "u8"
         },
         {
-          "name": "address",
-          "type": "publicKey"
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "rate",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "removeTeamTreasury",
-      "accounts": [
-        {
-          "name": "admin",
+          "name": "merkleTree",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "globalAuthority",
-          "isMut": true,
+          "name": "logWrapper",
+          "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "globalBump",
-          "type": "u8"
         },
         {
-          "name": "address",
-          "type": "publicKey"
-        }
-      ]
-    },
-    {
-      "name": "initUserPool",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "userPool",
-          "isMut": true,
+          "name": "bubblegumProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1600,48 +1511,37 @@ export const IDL: AstroMarketplace = {
       ],
       "args": [
         {
-          "name": "bump",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "initSellData",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "priceSol",
+          "type": "u64"
         },
         {
-          "name": "sellDataInfo",
-          "isMut": true,
-          "isSigner": false
+          "name": "priceToken",
+          "type": "u64"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeMode",
+          "type": "u8"
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "nft",
+          "name": "reservedBuyer",
           "type": "publicKey"
         },
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "CompressedMetadata"
+          }
         }
       ]
     },
     {
-      "name": "listNftForSale",
+      "name": "delistCompressedNft",
       "accounts": [
         {
           "name": "owner",
@@ -1659,60 +1559,55 @@ export const IDL: AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
+          "name": "assetId",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "logWrapper",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "mintMetadata",
-          "isMut": true,
+          "name": "compressionProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "bubblegumProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "sellBump",
-          "type": "u8"
-        },
-        {
-          "name": "priceSol",
-          "type": "u64"
-        },
-        {
-          "name": "priceToken",
-          "type": "u64"
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
         }
       ]
     },
     {
-      "name": "delistNft",
+      "name": "purchaseCompressedNft",
       "accounts": [
         {
-          "name": "owner",
+          "name": "buyer",
           "isMut": true,
           "isSigner": true
         },
@@ -1721,99 +1616,109 @@ export const IDL: AstroMarketplace = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "sellDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "buyerUserPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "seller",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
-          "isMut": false,
+          "name": "sellerUserPool",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "assetId",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "globalBump",
-          "type": "u8"
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "sellBump",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "purchase",
-      "accounts": [
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
         {
-          "name": "buyer",
+          "name": "userTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "globalAuthority",
+          "name": "sellerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellDataInfo",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerUserPool",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userNftTokenAccount",
-          "isMut": true,
+          "name": "assetDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
-          "isMut": true,
+          "name": "buyerDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "seller",
-          "isMut": true,
+          "name": "sellerDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerUserPool",
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "logWrapper",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
+          "name": "compressionProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTokenAccount",
-          "isMut": true,
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1829,180 +1734,179 @@ export const IDL: AstroMarketplace = {
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
-        },
-        {
-          "name": "nftBump",
-          "type": "u8"
-        },
-        {
-          "name": "sellerBump",
+          "name": "byToken",
           "type": "u8"
         },
         {
-          "name": "buyerBump",
-          "type": "u8"
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
         },
         {
-          "name": "byToken",
-          "type": "u8"
+          "name": "metadata",
+          "type": {
+            "defined": "CompressedMetadata"
+          }
         }
       ]
     },
     {
-      "name": "depositToEscrow",
+      "name": "acceptCompressedOffer",
       "accounts": [
         {
-          "name": "owner",
+          "name": "seller",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userPool",
+          "name": "sellDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowVault",
+          "name": "buyer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "offerDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
+          "name": "sellerUserPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "assetId",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "collectionFee",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "userBump",
-          "type": "u8"
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "sol",
-          "type": "u64"
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "token",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "withdrawFromEscrow",
-      "accounts": [
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "owner",
+          "name": "escrowVault",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "userPool",
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowVault",
+          "name": "escrowTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "assetDenyEntry",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "buyerDenyEntry",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "userBump",
-          "type": "u8"
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "sol",
-          "type": "u64"
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "token",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "initOfferData",
-      "accounts": [
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "offerDataInfo",
-          "isMut": true,
+          "name": "bubblegumProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "paymentMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "nft",
-          "type": "publicKey"
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
         },
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "metadata",
+          "type": {
+            "defined": "CompressedMetadata"
+          }
         }
       ]
     },
     {
-      "name": "makeOffer",
+      "name": "listBundle",
       "accounts": [
         {
           "name": "owner",
@@ -2010,80 +1914,62 @@ export const IDL: AstroMarketplace = {
           "isSigner": true
         },
         {
-          "name": "sellDataInfo",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "offerDataInfo",
+          "name": "bundleDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "bundleMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userPool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "escrowVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount",
-          "isMut": true,
+          "name": "ownerDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
-          "isMut": true,
+          "name": "nftTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "sellBump",
-          "type": "u8"
-        },
-        {
-          "name": "offerBump",
-          "type": "u8"
+          "name": "priceSol",
+          "type": "u64"
         },
         {
-          "name": "userBump",
-          "type": "u8"
+          "name": "priceToken",
+          "type": "u64"
         },
         {
-          "name": "escrowBump",
+          "name": "feeMode",
           "type": "u8"
         },
         {
-          "name": "price",
-          "type": "u64"
-        },
-        {
-          "name": "byToken",
-          "type": "u64"
+          "name": "reservedBuyer",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "cancelOffer",
+      "name": "delistBundle",
       "accounts": [
         {
           "name": "owner",
@@ -2091,131 +1977,146 @@ export const IDL: AstroMarketplace = {
           "isSigner": true
         },
         {
-          "name": "offerDataInfo",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "bundleDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleMint",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "offerBump",
-          "type": "u8"
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "acceptOffer",
+      "name": "purchaseBundle",
       "accounts": [
         {
-          "name": "seller",
+          "name": "buyer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "sellDataInfo",
+          "name": "globalAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyer",
+          "name": "treasuryConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "offerDataInfo",
+          "name": "bundleDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerUserPool",
+          "name": "buyerUserPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
-          "isMut": false,
+          "name": "seller",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "globalAuthority",
+          "name": "sellerUserPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerUserPool",
-          "isMut": true,
+          "name": "bundleMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userNftTokenAccount",
-          "isMut": true,
+          "name": "collectionFee",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowVault",
+          "name": "sellerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "escrowTokenAccount",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "buyerDenyEntry",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "sellerDenyEntry",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "globalBump",
-          "type": "u8"
         },
         {
-          "name": "nftBump",
-          "type": "u8"
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "offerBump",
-          "type": "u8"
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "buyerBump",
-          "type": "u8"
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "sellerBump",
-          "type": "u8"
-        },
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
-          "name": "escrowBump",
+          "name": "byToken",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "initAuctionData",
+      "name": "closeBundleData",
       "accounts": [
         {
           "name": "payer",
@@ -2223,34 +2124,20 @@ export const IDL: AstroMarketplace = {
           "isSigner": true
         },
         {
-          "name": "auctionDataInfo",
+          "name": "bundleDataInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
+          "name": "bundleMint",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "nft",
-          "type": "publicKey"
-        },
-        {
-          "name": "bump",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createAuction",
+      "name": "makeSwapOffer",
       "accounts": [
         {
           "name": "owner",
@@ -2258,12 +2145,27 @@ export const IDL: AstroMarketplace = {
           "isSigner": true
         },
         {
-          "name": "globalAuthority",
+          "name": "swapOfferInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionDataInfo",
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
           "isMut": true,
           "isSigner": false
         },
@@ -2273,73 +2175,188 @@ export const IDL: AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "escrowTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "mintMetadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "collectionFee",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "globalBump",
-          "type": "u8"
+          "name": "targetDenyEntry",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "auctionBump",
-          "type": "u8"
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "startPrice",
-          "type": "u64"
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "minIncrease",
-          "type": "u64"
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "byToken",
-          "type": "u64"
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "endDate",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "placeBid",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "byToken",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelSwapOffer",
       "accounts": [
         {
-          "name": "bidder",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "auctionDataInfo",
+          "name": "swapOfferInfo",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptSwapOffer",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapOfferInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "escrowVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bidderTokenAccount",
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -2349,15 +2366,45 @@ export const IDL: AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "outBidder",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "outBidderTokenAccount",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "targetDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -2369,26 +2416,39 @@ export const IDL: AstroMarketplace = {
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "closeSwapOfferData",
+      "accounts": [
         {
-          "name": "auctionBump",
-          "type": "u8"
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "escrowBump",
-          "type": "u8"
+          "name": "swapOfferInfo",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "price",
-          "type": "u64"
+          "name": "buyer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "claimAuction",
+      "name": "createAuction",
       "accounts": [
         {
-          "name": "bidder",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
@@ -2418,295 +2478,5467 @@ export const IDL: AstroMarketplace = {
           "isSigner": false
         },
         {
-          "name": "escrowVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "escrowTokenAccount",
-          "isMut": true,
+          "name": "mintMetadata",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bidderUserPool",
-          "isMut": true,
+          "name": "collectionInfo",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "creator",
-          "isMut": true,
+          "name": "mintDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "creatorTokenAccount",
-          "isMut": true,
+          "name": "ownerDenyEntry",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "creatorUserPool",
-          "isMut": true,
+          "name": "nftTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "globalBump",
-          "type": "u8"
+          "name": "startPrice",
+          "type": "u64"
         },
         {
-          "name": "auctionBump",
-          "type": "u8"
+          "name": "minIncrease",
+          "type": "u64"
+        },
+        {
+          "name": "byToken",
+          "type": "u64"
+        },
+        {
+          "name": "endDate",
+          "type": "i64"
         },
         {
-          "name": "escrowBump",
+          "name": "feeMode",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "cancelAuction",
+      "name": "placeBid",
       "accounts": [
         {
-          "name": "creator",
+          "name": "bidder",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionDataInfo",
+          "name": "bidderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "escrowTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destNftTokenAccount",
+          "name": "outBidder",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "outBidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outBidderUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "mintDenyEntry",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "globalBump",
-          "type": "u8"
+          "name": "bidderDenyEntry",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "auctionBump",
-          "type": "u8"
-        }
-      ]
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "byEscrow",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimAuction",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAuction",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceCancelAuction",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeAuctionData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "globalPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "superAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "marketFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "marketFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "type": "u8"
+          },
+          {
+            "name": "primaryTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "dustPolicy",
+            "type": "u64"
+          },
+          {
+            "name": "feeVaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "feeTierCount",
+            "type": "u64"
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeTierBySeller",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "feeActivationDate",
+            "type": "i64"
+          },
+          {
+            "name": "referralShare",
+            "type": "u64"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFallback",
+            "type": "u64"
+          },
+          {
+            "name": "restrictedListing",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeTierCount",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "pendingFeeTierBySeller",
+            "type": "u64"
+          },
+          {
+            "name": "feeTierActivationDate",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasuryConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "treasuries",
+            "type": {
+              "vec": {
+                "defined": "TeamTreasury"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "marketFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "marketFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "feeActivationDate",
+            "type": "i64"
+          },
+          {
+            "name": "removalDate",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "array": [
+                "u8",
+                10
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "denyEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "kind",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": "u64"
+          },
+          {
+            "name": "deniedDate",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "priceSol",
+            "type": "u64"
+          },
+          {
+            "name": "priceToken",
+            "type": "u64"
+          },
+          {
+            "name": "listedDate",
+            "type": "i64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "collectionMethod",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u64"
+          },
+          {
+            "name": "reservedBuyer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "mints",
+            "type": {
+              "array": [
+                "publicKey",
+                5
+              ]
+            }
+          },
+          {
+            "name": "itemCount",
+            "type": "u64"
+          },
+          {
+            "name": "priceSol",
+            "type": "u64"
+          },
+          {
+            "name": "priceToken",
+            "type": "u64"
+          },
+          {
+            "name": "listedDate",
+            "type": "i64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "reservedBuyer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "offerData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "offerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "offerListingDate",
+            "type": "i64"
+          },
+          {
+            "name": "byToken",
+            "type": "u64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feeRate",
+            "type": "u64"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapOfferData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "offeredMints",
+            "type": {
+              "array": [
+                "publicKey",
+                5
+              ]
+            }
+          },
+          {
+            "name": "itemCount",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "byToken",
+            "type": "u64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeRate",
+            "type": "u64"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncreaseAmount",
+            "type": "u64"
+          },
+          {
+            "name": "byToken",
+            "type": "u64"
+          },
+          {
+            "name": "endDate",
+            "type": "i64"
+          },
+          {
+            "name": "lastBidDate",
+            "type": "i64"
+          },
+          {
+            "name": "lastBidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "highestBid",
+            "type": "u64"
+          },
+          {
+            "name": "bidByEscrow",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": "u64"
+          },
+          {
+            "name": "cancelReason",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "bidFeeDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "bidFeeRate",
+            "type": "u64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "tradedVolume",
+            "type": "u64"
+          },
+          {
+            "name": "tradedTokenVolume",
+            "type": "u64"
+          },
+          {
+            "name": "escrowSolBalance",
+            "type": "u64"
+          },
+          {
+            "name": "escrowTokenBalance",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "earnedSol",
+            "type": "u64"
+          },
+          {
+            "name": "earnedToken",
+            "type": "u64"
+          },
+          {
+            "name": "claimableSol",
+            "type": "u64"
+          },
+          {
+            "name": "claimableToken",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "approved",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "FeeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "solVolume",
+            "type": "u64"
+          },
+          {
+            "name": "tokenVolume",
+            "type": "u64"
+          },
+          {
+            "name": "discount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TeamTreasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "rate",
+            "type": "u64"
+          },
+          {
+            "name": "claimableSol",
+            "type": "u64"
+          },
+          {
+            "name": "claimableToken",
+            "type": "u64"
+          },
+          {
+            "name": "label",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedCollection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedUses",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": "u8"
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedMetadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": "bool"
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "CompressedCollection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "CompressedUses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": "u8"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CompressedCreator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedLeaf",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidSuperOwner",
+      "msg": "Invalid Super Owner"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidOwner",
+      "msg": "Invalid Owner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidGlobalPool",
+      "msg": "Invalid Global Pool Address"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidFeePercent",
+      "msg": "Marketplace Fee is Permyriad"
+    },
+    {
+      "code": 6004,
+      "name": "MaxTeamCountExceed",
+      "msg": "Max Team Count is 8"
+    },
+    {
+      "code": 6005,
+      "name": "NoTeamTreasuryYet",
+      "msg": "Treasury Wallet Not Configured"
+    },
+    {
+      "code": 6006,
+      "name": "TreasuryAddressNotFound",
+      "msg": "Treasury Address Not Exist"
+    },
+    {
+      "code": 6007,
+      "name": "TreasuryAddressAlreadyAdded",
+      "msg": "Treasury Address Already Exist"
+    },
+    {
+      "code": 6008,
+      "name": "MaxTreasuryRateSumExceed",
+      "msg": "Total Treasury Rate Sum Should Less Than 100%"
+    },
+    {
+      "code": 6009,
+      "name": "TeamTreasuryCountMismatch",
+      "msg": "Team Treasury Wallet Count Mismatch"
+    },
+    {
+      "code": 6010,
+      "name": "TeamTreasuryAddressMismatch",
+      "msg": "Team Treasury Wallet Address Mismatch"
+    },
+    {
+      "code": 6011,
+      "name": "Uninitialized",
+      "msg": "Uninitialized Account"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidParamInput",
+      "msg": "Instruction Parameter is Invalid"
+    },
+    {
+      "code": 6013,
+      "name": "SellerMismatch",
+      "msg": "Payer Mismatch with NFT Seller"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidNFTDataAcount",
+      "msg": "Invalid NFT Data Account"
+    },
+    {
+      "code": 6015,
+      "name": "NotListedNFT",
+      "msg": "The NFT Is Not Listed"
+    },
+    {
+      "code": 6016,
+      "name": "SellerAccountMismatch",
+      "msg": "Seller Account Mismatch with NFT Seller Data"
+    },
+    {
+      "code": 6017,
+      "name": "InsufficientBuyerSolBalance",
+      "msg": "Buyer Sol Balance is Less than NFT SOL Price"
+    },
+    {
+      "code": 6018,
+      "name": "InsufficientBuyerTokenBalance",
+      "msg": "Buyer Token Balance is Less than NFT Token Price"
+    },
+    {
+      "code": 6019,
+      "name": "InvaliedMetadata",
+      "msg": "Invalid Metadata Address"
+    },
+    {
+      "code": 6020,
+      "name": "MetadataCreatorParseError",
+      "msg": "Can't Parse The NFT's Creators"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidOfferDataMint",
+      "msg": "Offer Data Mint mismatch with NFT Pubkey"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidOfferDataBuyer",
+      "msg": "Offer Data Buyer mismatch with Payer Pubkey"
+    },
+    {
+      "code": 6023,
+      "name": "OfferForNotListedNFT",
+      "msg": "Making Offer for Not Listed NFT"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidOfferPrice",
+      "msg": "Offer Price Over Thank Listed Price"
+    },
+    {
+      "code": 6025,
+      "name": "DisabledOffer",
+      "msg": "Already Canceled Offer"
+    },
+    {
+      "code": 6026,
+      "name": "OfferForExpiredListingNFT",
+      "msg": "Offer For Sold Or Canceled NFT Listing"
+    },
+    {
+      "code": 6027,
+      "name": "EndedAuction",
+      "msg": "Placing Bid For Ended Auction"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidBidPrice",
+      "msg": "Placing Bid With Lower Than Highest Bid"
+    },
+    {
+      "code": 6029,
+      "name": "DoubleBidFromOneBidder",
+      "msg": "Placing Bid Double From One Bidder"
+    },
+    {
+      "code": 6030,
+      "name": "OutBidderMismatch",
+      "msg": "Out Bidder Account Mismatch With LastBidder Data"
+    },
+    {
+      "code": 6031,
+      "name": "NotEndedAuction",
+      "msg": "Claiming Auction For Not Ended Auction"
+    },
+    {
+      "code": 6032,
+      "name": "CreatorAccountMismatch",
+      "msg": "Creator Account Mismatch with Auction Data"
+    },
+    {
+      "code": 6033,
+      "name": "BidderAccountMismatch",
+      "msg": "Bidder Account Mismatch with Auction Data"
+    },
+    {
+      "code": 6034,
+      "name": "AuctionHasBid",
+      "msg": "Canceling Auction which has Bid"
+    },
+    {
+      "code": 6035,
+      "name": "BidFromAuctionCreator",
+      "msg": "Placing Bid From Auction Creator"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidBump",
+      "msg": "Invalid Bump"
+    },
+    {
+      "code": 6037,
+      "name": "NonZeroError",
+      "msg": "Value Must be Non Zero"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidMinimumIncreaseValue",
+      "msg": "Invalid Minimum Increase Value"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidEndDate",
+      "msg": "Invalid End Date"
+    },
+    {
+      "code": 6040,
+      "name": "MismatchAccountPubkey",
+      "msg": "Account Pubkey Does not Matched"
+    },
+    {
+      "code": 6041,
+      "name": "InsufficientBidderSolBalance",
+      "msg": "Bidder Escrow Sol Balance is Less than Bid Price"
+    },
+    {
+      "code": 6042,
+      "name": "InsufficientBidderTokenBalance",
+      "msg": "Bidder Escrow Token Balance is Less than Bid Price"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidUserPoolAccount",
+      "msg": "Invalid User PDA Account"
+    },
+    {
+      "code": 6044,
+      "name": "RentPayerMismatch",
+      "msg": "Payer Mismatch with Data Account Rent Payer"
+    },
+    {
+      "code": 6045,
+      "name": "ActiveDataAccount",
+      "msg": "Closing Data Account which is Still Active"
+    },
+    {
+      "code": 6046,
+      "name": "AlreadyListedNFT",
+      "msg": "The NFT Is Already Listed"
+    },
+    {
+      "code": 6047,
+      "name": "AlreadyLiveAuction",
+      "msg": "The NFT Is Already In Live Auction"
+    },
+    {
+      "code": 6048,
+      "name": "MathOverflow",
+      "msg": "Math Operation Overflow"
+    },
+    {
+      "code": 6049,
+      "name": "MathUnderflow",
+      "msg": "Math Operation Underflow"
+    },
+    {
+      "code": 6050,
+      "name": "MathDivisionByZero",
+      "msg": "Math Operation Division By Zero"
+    },
+    {
+      "code": 6051,
+      "name": "RoyaltyCreatorCountMismatch",
+      "msg": "Royalty Creator Accounts Count Mismatch with Metadata"
+    },
+    {
+      "code": 6052,
+      "name": "RoyaltyCreatorAddressMismatch",
+      "msg": "Royalty Creator Account Mismatch with Metadata"
+    },
+    {
+      "code": 6053,
+      "name": "PayoutMismatch",
+      "msg": "Payout Breakdown Does Not Sum To The Price"
+    },
+    {
+      "code": 6054,
+      "name": "UnclaimedTreasuryFees",
+      "msg": "Treasury Has Unclaimed Fees"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidCollectionFeeAccount",
+      "msg": "Invalid Collection Fee Account"
+    },
+    {
+      "code": 6056,
+      "name": "MaxFeeTierCountExceed",
+      "msg": "Max Fee Tier Count is 4"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidFeeTierOrder",
+      "msg": "Fee Tiers Should Be In Ascending Order"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidReferralAccount",
+      "msg": "Invalid Referral Data Account"
+    },
+    {
+      "code": 6059,
+      "name": "SelfReferral",
+      "msg": "Trader Can't Refer The Own Trade"
+    },
+    {
+      "code": 6060,
+      "name": "ReferralNotApproved",
+      "msg": "Referrer Is Not Approved"
+    },
+    {
+      "code": 6061,
+      "name": "NoVerifiedCollection",
+      "msg": "NFT Has No Verified Collection"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidCollectionInfoAccount",
+      "msg": "Invalid Collection Info Account"
+    },
+    {
+      "code": 6063,
+      "name": "CollectionNotRegistered",
+      "msg": "Collection Is Not Registered"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidDenyEntryAccount",
+      "msg": "Invalid Deny Entry Account"
+    },
+    {
+      "code": 6065,
+      "name": "DeniedAddress",
+      "msg": "NFT Or Wallet Is Denylisted"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidPnftAccounts",
+      "msg": "Invalid Programmable NFT Transfer Accounts"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidTokenProgram",
+      "msg": "Invalid Token Program"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidNftTokenAccount",
+      "msg": "Invalid NFT Token Account"
+    },
+    {
+      "code": 6069,
+      "name": "NftTransferFee",
+      "msg": "NFT Mint Has Transfer Fee"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidCompressedNftAccounts",
+      "msg": "Invalid Compressed NFT Accounts"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidCompressedMetadata",
+      "msg": "Compressed NFT Metadata Mismatch With The Leaf"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidBundleSize",
+      "msg": "Invalid Bundle Size"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidBundleAccounts",
+      "msg": "Invalid Bundle Item Accounts"
+    },
+    {
+      "code": 6074,
+      "name": "MixedBundleCollections",
+      "msg": "Bundle Items Are Of Different Collections"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidSwapItemCount",
+      "msg": "Invalid Swap Offer Item Count"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidSwapItemAccounts",
+      "msg": "Invalid Swap Offer Item Accounts"
+    },
+    {
+      "code": 6077,
+      "name": "ReservedListing",
+      "msg": "Listing Is Reserved For Another Buyer"
+    }
+  ]
+};
+
+export const IDL: AstroMarketplace = {
+  "version": "0.1.0",
+  "name": "astro_marketplace",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateFee",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solFee",
+          "type": "u64"
+        },
+        {
+          "name": "tokenFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateFeeTiers",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": "FeeTier"
+            }
+          }
+        },
+        {
+          "name": "bySeller",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateReferralShare",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "share",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateFeePayer",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "buyerPaysFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateCollectionFallback",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "creatorFallback",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateListingMode",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "restricted",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateDustPolicy",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "policy",
+          "type": "u64"
+        },
+        {
+          "name": "primaryTreasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addTeamTreasury",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        },
+        {
+          "name": "rate",
+          "type": "u64"
+        },
+        {
+          "name": "label",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTeamTreasury",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        },
+        {
+          "name": "rate",
+          "type": "u64"
+        },
+        {
+          "name": "label",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeTeamTreasury",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "claimTreasuryFees",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "byToken",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setCollectionFee",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solFee",
+          "type": "u64"
+        },
+        {
+          "name": "tokenFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeCollectionFee",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerCollection",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "symbol",
+          "type": {
+            "array": [
+              "u8",
+              10
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "unregisterCollection",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionInfo",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addDenyEntry",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "denyEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "address",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "kind",
+          "type": "u64"
+        },
+        {
+          "name": "reason",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeDenyEntry",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "denyEntry",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveReferral",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approved",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initUserPool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initReferral",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "referralData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralFees",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "referralData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "byToken",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "listNftForSale",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionInfo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "priceSol",
+          "type": "u64"
+        },
+        {
+          "name": "priceToken",
+          "type": "u64"
+        },
+        {
+          "name": "feeMode",
+          "type": "u8"
+        },
+        {
+          "name": "quantity",
+          "type": "u64"
+        },
+        {
+          "name": "reservedBuyer",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "delistNft",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "purchase",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "byToken",
+          "type": "u8"
+        },
+        {
+          "name": "quantity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeSellData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "depositToEscrow",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sol",
+          "type": "u64"
+        },
+        {
+          "name": "token",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFromEscrow",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sol",
+          "type": "u64"
+        },
+        {
+          "name": "token",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "makeOffer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "byToken",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelOffer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptOffer",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeOfferData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listCompressedNft",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetId",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionInfo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "priceSol",
+          "type": "u64"
+        },
+        {
+          "name": "priceToken",
+          "type": "u64"
+        },
+        {
+          "name": "feeMode",
+          "type": "u8"
+        },
+        {
+          "name": "reservedBuyer",
+          "type": "publicKey"
+        },
+        {
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "CompressedMetadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "delistCompressedNft",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetId",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
+        }
+      ]
+    },
+    {
+      "name": "purchaseCompressedNft",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetId",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "byToken",
+          "type": "u8"
+        },
+        {
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "CompressedMetadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptCompressedOffer",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "offerDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetId",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "leaf",
+          "type": {
+            "defined": "CompressedLeaf"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "CompressedMetadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "listBundle",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "priceSol",
+          "type": "u64"
+        },
+        {
+          "name": "priceToken",
+          "type": "u64"
+        },
+        {
+          "name": "feeMode",
+          "type": "u8"
+        },
+        {
+          "name": "reservedBuyer",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "delistBundle",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "purchaseBundle",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "byToken",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeBundleData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bundleDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "makeSwapOffer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "swapOfferInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "byToken",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelSwapOffer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "swapOfferInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptSwapOffer",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapOfferInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "targetDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeSwapOfferData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "swapOfferInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createAuction",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionInfo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "startPrice",
+          "type": "u64"
+        },
+        {
+          "name": "minIncrease",
+          "type": "u64"
+        },
+        {
+          "name": "byToken",
+          "type": "u64"
+        },
+        {
+          "name": "endDate",
+          "type": "i64"
+        },
+        {
+          "name": "feeMode",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "placeBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outBidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outBidderUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "byEscrow",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimAuction",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorUserPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorDenyEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAuction",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceCancelAuction",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeAuctionData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionDataInfo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destNftTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
     {
-      "name": "globalPool",
+      "name": "globalPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "superAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "marketFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "marketFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "type": "u8"
+          },
+          {
+            "name": "primaryTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "dustPolicy",
+            "type": "u64"
+          },
+          {
+            "name": "feeVaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "feeTierCount",
+            "type": "u64"
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeTierBySeller",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "feeActivationDate",
+            "type": "i64"
+          },
+          {
+            "name": "referralShare",
+            "type": "u64"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFallback",
+            "type": "u64"
+          },
+          {
+            "name": "restrictedListing",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeTierCount",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "pendingFeeTierBySeller",
+            "type": "u64"
+          },
+          {
+            "name": "feeTierActivationDate",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasuryConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "treasuries",
+            "type": {
+              "vec": {
+                "defined": "TeamTreasury"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "marketFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "marketFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingFeeSol",
+            "type": "u64"
+          },
+          {
+            "name": "pendingFeeToken",
+            "type": "u64"
+          },
+          {
+            "name": "feeActivationDate",
+            "type": "i64"
+          },
+          {
+            "name": "removalDate",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "array": [
+                "u8",
+                10
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "denyEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "kind",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": "u64"
+          },
+          {
+            "name": "deniedDate",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "priceSol",
+            "type": "u64"
+          },
+          {
+            "name": "priceToken",
+            "type": "u64"
+          },
+          {
+            "name": "listedDate",
+            "type": "i64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "collectionMethod",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u64"
+          },
+          {
+            "name": "reservedBuyer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "mints",
+            "type": {
+              "array": [
+                "publicKey",
+                5
+              ]
+            }
+          },
+          {
+            "name": "itemCount",
+            "type": "u64"
+          },
+          {
+            "name": "priceSol",
+            "type": "u64"
+          },
+          {
+            "name": "priceToken",
+            "type": "u64"
+          },
+          {
+            "name": "listedDate",
+            "type": "i64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "reservedBuyer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "offerData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "offerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "offerListingDate",
+            "type": "i64"
+          },
+          {
+            "name": "byToken",
+            "type": "u64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feeRate",
+            "type": "u64"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapOfferData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "offeredMints",
+            "type": {
+              "array": [
+                "publicKey",
+                5
+              ]
+            }
+          },
+          {
+            "name": "itemCount",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "byToken",
+            "type": "u64"
+          },
+          {
+            "name": "active",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeRate",
+            "type": "u64"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncreaseAmount",
+            "type": "u64"
+          },
+          {
+            "name": "byToken",
+            "type": "u64"
+          },
+          {
+            "name": "endDate",
+            "type": "i64"
+          },
+          {
+            "name": "lastBidDate",
+            "type": "i64"
+          },
+          {
+            "name": "lastBidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "highestBid",
+            "type": "u64"
+          },
+          {
+            "name": "bidByEscrow",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": "u64"
+          },
+          {
+            "name": "cancelReason",
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "buyerPaysFee",
+            "type": "u64"
+          },
+          {
+            "name": "bidFeeDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "bidFeeRate",
+            "type": "u64"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "superAdmin",
+            "name": "address",
             "type": "publicKey"
           },
           {
-            "name": "marketFeeSol",
+            "name": "tradedVolume",
             "type": "u64"
           },
           {
-            "name": "marketFeeToken",
+            "name": "tradedTokenVolume",
             "type": "u64"
           },
           {
-            "name": "teamCount",
+            "name": "escrowSolBalance",
             "type": "u64"
           },
           {
-            "name": "teamTreasury",
-            "type": {
-              "array": [
-                "publicKey",
-                8
-              ]
-            }
+            "name": "escrowTokenBalance",
+            "type": "u64"
           },
           {
-            "name": "treasuryRate",
-            "type": {
-              "array": [
-                "u64",
-                8
-              ]
-            }
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "sellData",
+      "name": "referralData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
+            "name": "referrer",
             "type": "publicKey"
           },
           {
-            "name": "seller",
-            "type": "publicKey"
+            "name": "earnedSol",
+            "type": "u64"
           },
           {
-            "name": "collection",
-            "type": "publicKey"
+            "name": "earnedToken",
+            "type": "u64"
           },
           {
-            "name": "priceSol",
+            "name": "claimableSol",
             "type": "u64"
           },
           {
-            "name": "priceToken",
+            "name": "claimableToken",
             "type": "u64"
           },
           {
-            "name": "listedDate",
-            "type": "i64"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "active",
+            "name": "approved",
             "type": "u64"
           }
         ]
       }
-    },
+    }
+  ],
+  "types": [
     {
-      "name": "offerData",
+      "name": "FeeTier",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
-            "type": "publicKey"
+            "name": "solVolume",
+            "type": "u64"
           },
           {
-            "name": "buyer",
-            "type": "publicKey"
+            "name": "tokenVolume",
+            "type": "u64"
           },
           {
-            "name": "offerPrice",
+            "name": "discount",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TeamTreasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
           },
           {
-            "name": "offerListingDate",
-            "type": "i64"
+            "name": "rate",
+            "type": "u64"
           },
           {
-            "name": "byToken",
+            "name": "claimableSol",
             "type": "u64"
           },
           {
-            "name": "active",
+            "name": "claimableToken",
             "type": "u64"
+          },
+          {
+            "name": "label",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "auctionData",
+      "name": "CompressedCreator",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
+            "name": "address",
             "type": "publicKey"
           },
           {
-            "name": "creator",
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedCollection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
             "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedUses",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": "u8"
           },
           {
-            "name": "startPrice",
+            "name": "remaining",
             "type": "u64"
           },
           {
-            "name": "minIncreaseAmount",
+            "name": "total",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedMetadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
           },
           {
-            "name": "byToken",
-            "type": "u64"
+            "name": "symbol",
+            "type": "string"
           },
           {
-            "name": "endDate",
-            "type": "i64"
+            "name": "uri",
+            "type": "string"
           },
           {
-            "name": "lastBidDate",
-            "type": "i64"
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
           },
           {
-            "name": "lastBidder",
-            "type": "publicKey"
+            "name": "primarySaleHappened",
+            "type": "bool"
           },
           {
-            "name": "highestBid",
-            "type": "u64"
+            "name": "isMutable",
+            "type": "bool"
           },
           {
-            "name": "status",
-            "type": "u64"
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "CompressedCollection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "CompressedUses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": "u8"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CompressedCreator"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "userData",
+      "name": "CompressedLeaf",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "address",
-            "type": "publicKey"
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "tradedVolume",
-            "type": "u64"
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "tradedTokenVolume",
-            "type": "u64"
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "escrowSolBalance",
+            "name": "nonce",
             "type": "u64"
           },
           {
-            "name": "escrowTokenBalance",
-            "type": "u64"
+            "name": "index",
+            "type": "u32"
           }
         ]
       }
@@ -2892,6 +8124,216 @@ export const IDL: AstroMarketplace = {
       "code": 6035,
       "name": "BidFromAuctionCreator",
       "msg": "Placing Bid From Auction Creator"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidBump",
+      "msg": "Invalid Bump"
+    },
+    {
+      "code": 6037,
+      "name": "NonZeroError",
+      "msg": "Value Must be Non Zero"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidMinimumIncreaseValue",
+      "msg": "Invalid Minimum Increase Value"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidEndDate",
+      "msg": "Invalid End Date"
+    },
+    {
+      "code": 6040,
+      "name": "MismatchAccountPubkey",
+      "msg": "Account Pubkey Does not Matched"
+    },
+    {
+      "code": 6041,
+      "name": "InsufficientBidderSolBalance",
+      "msg": "Bidder Escrow Sol Balance is Less than Bid Price"
+    },
+    {
+      "code": 6042,
+      "name": "InsufficientBidderTokenBalance",
+      "msg": "Bidder Escrow Token Balance is Less than Bid Price"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidUserPoolAccount",
+      "msg": "Invalid User PDA Account"
+    },
+    {
+      "code": 6044,
+      "name": "RentPayerMismatch",
+      "msg": "Payer Mismatch with Data Account Rent Payer"
+    },
+    {
+      "code": 6045,
+      "name": "ActiveDataAccount",
+      "msg": "Closing Data Account which is Still Active"
+    },
+    {
+      "code": 6046,
+      "name": "AlreadyListedNFT",
+      "msg": "The NFT Is Already Listed"
+    },
+    {
+      "code": 6047,
+      "name": "AlreadyLiveAuction",
+      "msg": "The NFT Is Already In Live Auction"
+    },
+    {
+      "code": 6048,
+      "name": "MathOverflow",
+      "msg": "Math Operation Overflow"
+    },
+    {
+      "code": 6049,
+      "name": "MathUnderflow",
+      "msg": "Math Operation Underflow"
+    },
+    {
+      "code": 6050,
+      "name": "MathDivisionByZero",
+      "msg": "Math Operation Division By Zero"
+    },
+    {
+      "code": 6051,
+      "name": "RoyaltyCreatorCountMismatch",
+      "msg": "Royalty Creator Accounts Count Mismatch with Metadata"
+    },
+    {
+      "code": 6052,
+      "name": "RoyaltyCreatorAddressMismatch",
+      "msg": "Royalty Creator Account Mismatch with Metadata"
+    },
+    {
+      "code": 6053,
+      "name": "PayoutMismatch",
+      "msg": "Payout Breakdown Does Not Sum To The Price"
+    },
+    {
+      "code": 6054,
+      "name": "UnclaimedTreasuryFees",
+      "msg": "Treasury Has Unclaimed Fees"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidCollectionFeeAccount",
+      "msg": "Invalid Collection Fee Account"
+    },
+    {
+      "code": 6056,
+      "name": "MaxFeeTierCountExceed",
+      "msg": "Max Fee Tier Count is 4"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidFeeTierOrder",
+      "msg": "Fee Tiers Should Be In Ascending Order"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidReferralAccount",
+      "msg": "Invalid Referral Data Account"
+    },
+    {
+      "code": 6059,
+      "name": "SelfReferral",
+      "msg": "Trader Can't Refer The Own Trade"
+    },
+    {
+      "code": 6060,
+      "name": "ReferralNotApproved",
+      "msg": "Referrer Is Not Approved"
+    },
+    {
+      "code": 6061,
+      "name": "NoVerifiedCollection",
+      "msg": "NFT Has No Verified Collection"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidCollectionInfoAccount",
+      "msg": "Invalid Collection Info Account"
+    },
+    {
+      "code": 6063,
+      "name": "CollectionNotRegistered",
+      "msg": "Collection Is Not Registered"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidDenyEntryAccount",
+      "msg": "Invalid Deny Entry Account"
+    },
+    {
+      "code": 6065,
+      "name": "DeniedAddress",
+      "msg": "NFT Or Wallet Is Denylisted"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidPnftAccounts",
+      "msg": "Invalid Programmable NFT Transfer Accounts"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidTokenProgram",
+      "msg": "Invalid Token Program"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidNftTokenAccount",
+      "msg": "Invalid NFT Token Account"
+    },
+    {
+      "code": 6069,
+      "name": "NftTransferFee",
+      "msg": "NFT Mint Has Transfer Fee"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidCompressedNftAccounts",
+      "msg": "Invalid Compressed NFT Accounts"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidCompressedMetadata",
+      "msg": "Compressed NFT Metadata Mismatch With The Leaf"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidBundleSize",
+      "msg": "Invalid Bundle Size"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidBundleAccounts",
+      "msg": "Invalid Bundle Item Accounts"
+    },
+    {
+      "code": 6074,
+      "name": "MixedBundleCollections",
+      "msg": "Bundle Items Are Of Different Collections"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidSwapItemCount",
+      "msg": "Invalid Swap Offer Item Count"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidSwapItemAccounts",
+      "msg": "Invalid Swap Offer Item Accounts"
+    },
+    {
+      "code": 6077,
+      "name": "ReservedListing",
+      "msg": "Listing Is Reserved For Another Buyer"
     }
   ]
 };
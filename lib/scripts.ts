@@ -12,6 +12,7 @@ import {
     MARKETPLACE_PROGRAM_ID,
     GLOBAL_AUTHORITY_SEED,
     GlobalPool,
+    TreasuryConfig,
     SellData,
     SELL_DATA_SEED,
     SELL_DATA_SIZE,
@@ -25,6 +26,12 @@ import {
     AUCTION_DATA_SEED,
     AuctionData,
     AUCTION_DATA_SIZE,
+    FEE_VAULT_SEED,
+    TREASURY_CONFIG_SEED,
+    COLLECTION_FEE_SEED,
+    COLLECTION_INFO_SEED,
+    REFERRAL_DATA_SEED,
+    DENY_ENTRY_SEED,
 } from './types';
 import {
     getAssociatedTokenAccount,
@@ -32,6 +39,7 @@ import {
     getNFTTokenAccount,
     getOwnerOfNFT,
     getMetadata,
+    getMetadataCreators,
     isExistAccount,
     getTokenAccount,
     METAPLEX,
@@ -76,6 +84,15 @@ export const getAllListedNFTs = async (connection: Connection, rpcUrl: string |
             let listedDate = (new anchor.BN(buf));
             buf = data.slice(128, 136).reverse();
             let active = (new anchor.BN(buf));
+            let payer = new PublicKey(data.slice(136, 168));
+            let bump = data[168];
+            buf = data.slice(169, 177).reverse();
+            let buyerPaysFee = (new anchor.BN(buf));
+            buf = data.slice(177, 185).reverse();
+            let collectionMethod = (new anchor.BN(buf));
+            buf = data.slice(185, 193).reverse();
+            let quantity = (new anchor.BN(buf));
+            let reservedBuyer = new PublicKey(data.slice(193, 225));
 
             if (active.toNumber() == 1)
                 result.push({
@@ -86,6 +103,12 @@ export const getAllListedNFTs = async (connection: Connection, rpcUrl: string |
                     priceToken,
                     listedDate,
                     active,
+                    payer,
+                    bump,
+                    buyerPaysFee,
+                    collectionMethod,
+                    quantity,
+                    reservedBuyer,
                 });
         }
     } catch (e) {
@@ -104,6 +127,9 @@ export const getAllListedNFTs = async (connection: Connection, rpcUrl: string |
                 priceToken: info.priceToken.toNumber(),
                 listedDate: info.listedDate.toNumber(),
                 active: info.active.toNumber(),
+                buyerPaysFee: info.buyerPaysFee.toNumber(),
+                quantity: info.quantity.toNumber(),
+                reservedBuyer: info.reservedBuyer.toBase58(),
             }
         })
     }
@@ -152,6 +178,11 @@ export const getAllOffersForListedNFT = async (mint: string, connection: Connect
             let byToken = (new anchor.BN(buf));
             buf = data.slice(96, 104).reverse();
             let active = (new anchor.BN(buf));
+            let payer = new PublicKey(data.slice(104, 136));
+            let bump = data[136];
+            buf = data.slice(137, 145).reverse();
+            let feeRate = (new anchor.BN(buf));
+            let seller = new PublicKey(data.slice(145, 177));
 
             if (active.toNumber() == 1)
                 result.push({
@@ -161,6 +192,10 @@ export const getAllOffersForListedNFT = async (mint: string, connection: Connect
                     byToken,
                     offerListingDate,
                     active,
+                    payer,
+                    bump,
+                    feeRate,
+                    seller,
                 });
         }
     } catch (e) {
@@ -178,6 +213,7 @@ export const getAllOffersForListedNFT = async (mint: string, connection: Connect
                 offerListingDate: info.offerListingDate.toNumber(),
                 byToken: info.byToken.toNumber(),
                 active: info.active.toNumber(),
+                seller: info.seller.toBase58(),
             }
         })
     }
@@ -225,7 +261,20 @@ export const getAllStartedAuctions = async (connection: Connection, rpcUrl: stri
             buf = data.slice(144, 152).reverse();
             let highestBid = (new anchor.BN(buf));
             buf = data.slice(152, 160).reverse();
+            let bidByEscrow = (new anchor.BN(buf));
+            buf = data.slice(160, 168).reverse();
             let status = (new anchor.BN(buf));
+            buf = data.slice(168, 176).reverse();
+            let cancelReason = (new anchor.BN(buf));
+            let payer = new PublicKey(data.slice(176, 208));
+            let bump = data[208];
+            buf = data.slice(209, 217).reverse();
+            let buyerPaysFee = (new anchor.BN(buf));
+            buf = data.slice(217, 225).reverse();
+            let bidFeeDeposit = (new anchor.BN(buf));
+            buf = data.slice(225, 233).reverse();
+            let bidFeeRate = (new anchor.BN(buf));
+            let collection = new PublicKey(data.slice(233, 265));
 
             // if (status.toNumber() !== 0)
                 result.push({
@@ -238,7 +287,15 @@ export const getAllStartedAuctions = async (connection: Connection, rpcUrl: stri
                     lastBidDate,
                     lastBidder,
                     highestBid,
+                    bidByEscrow,
                     status,
+                    cancelReason,
+                    payer,
+                    bump,
+                    buyerPaysFee,
+                    bidFeeDeposit,
+                    bidFeeRate,
+                    collection,
                 });
         }
     } catch (e) {
@@ -260,6 +317,8 @@ export const getAllStartedAuctions = async (connection: Connection, rpcUrl: stri
                 lastBidder: info.lastBidder.toBase58(),
                 highestBid: info.highestBid.toNumber(),
                 status: info.status.toNumber(),
+                buyerPaysFee: info.buyerPaysFee.toNumber(),
+                collection: info.collection.toBase58(),
             }
         })
     }
@@ -280,6 +339,21 @@ export const getGlobalState = async (
     }
 }
 
+export const getTreasuryConfigState = async (
+    program: anchor.Program,
+): Promise<TreasuryConfig | null> => {
+    const [treasuryConfig, _] = await PublicKey.findProgramAddress(
+        [Buffer.from(TREASURY_CONFIG_SEED)],
+        MARKETPLACE_PROGRAM_ID
+    );
+    try {
+        let treasuryState = await program.account.treasuryConfig.fetch(treasuryConfig);
+        return treasuryState as unknown as TreasuryConfig;
+    } catch {
+        return null;
+    }
+}
+
 export const getUserPoolState = async (
     userAddress: PublicKey,
     program: anchor.Program,
@@ -301,12 +375,13 @@ export const getUserPoolState = async (
 
 export const getNFTPoolState = async (
     mint: PublicKey,
+    seller: PublicKey,
     program: anchor.Program,
 ): Promise<SellData | null> => {
     if (!mint) return null;
 
     const [sellData, _] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+        [Buffer.from(SELL_DATA_SEED), mint.toBuffer(), seller.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
     console.log('Sell Data PDA: ', sellData.toBase58());
@@ -339,13 +414,14 @@ export const getAuctionDataState = async (
 
 export const getOfferDataState = async (
     mint: PublicKey,
+    seller: PublicKey,
     userAddress: PublicKey,
     program: anchor.Program,
 ): Promise<OfferData | null> => {
     if (!mint) return null;
 
     const [offerData, _] = await PublicKey.findProgramAddress(
-        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), seller.toBuffer(), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
     console.log('Offer Data PDA: ', offerData.toBase58());
@@ -357,28 +433,111 @@ export const getOfferDataState = async (
     }
 }
 
+export const getDenyEntry = async (
+    address: PublicKey,
+): Promise<PublicKey> => {
+    const [denyEntry] = await PublicKey.findProgramAddress(
+        [Buffer.from(DENY_ENTRY_SEED), address.toBuffer()],
+        MARKETPLACE_PROGRAM_ID,
+    );
+    return denyEntry;
+}
+
+export const getCollectionFee = async (
+    collection: PublicKey,
+): Promise<PublicKey> => {
+    const [collectionFee] = await PublicKey.findProgramAddress(
+        [Buffer.from(COLLECTION_FEE_SEED), collection.toBuffer()],
+        MARKETPLACE_PROGRAM_ID,
+    );
+    return collectionFee;
+}
+
+export const getCollectionInfo = async (
+    collection: PublicKey,
+): Promise<PublicKey> => {
+    const [collectionInfo] = await PublicKey.findProgramAddress(
+        [Buffer.from(COLLECTION_INFO_SEED), collection.toBuffer()],
+        MARKETPLACE_PROGRAM_ID,
+    );
+    return collectionInfo;
+}
+
+/** The program id is passed as the Referral Data PDA for the trade without referrer */
+export const getReferralData = async (
+    referrer?: PublicKey,
+): Promise<PublicKey> => {
+    if (!referrer) return MARKETPLACE_PROGRAM_ID;
+
+    const [referralData] = await PublicKey.findProgramAddress(
+        [Buffer.from(REFERRAL_DATA_SEED), referrer.toBuffer()],
+        MARKETPLACE_PROGRAM_ID,
+    );
+    return referralData;
+}
+
+/** Token or Token-2022 program owning the NFT mint */
+export const getNftTokenProgram = async (
+    mint: PublicKey,
+    connection: Connection,
+): Promise<PublicKey> => {
+    let mintInfo = await connection.getAccountInfo(mint);
+    if (!mintInfo) throw 'Error: Nft mint does not exist';
+    return mintInfo.owner;
+}
+
+/** Royalty creators passed as remaining accounts, the abb token accounts for token payouts */
+const getCreatorAccounts = async (
+    mint: PublicKey,
+    byToken: boolean,
+    connection: Connection,
+) => {
+    let creatorAccounts: PublicKey[] = [];
+    for (let address of await getMetadataCreators(mint, connection)) {
+        creatorAccounts.push(byToken ? await getAssociatedTokenAccount(address, ABB_TOKEN_MINT) : address);
+    }
+    console.log("=> Creator Accounts:", creatorAccounts.map((address) => address.toBase58()));
+
+    return creatorAccounts.map((address) => {
+        return {
+            pubkey: address,
+            isWritable: true,
+            isSigner: false,
+        }
+    });
+}
+
 export const createInitializeTx = async (
     userAddress: PublicKey,
     program: anchor.Program,
 ) => {
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
-    const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    const [treasuryConfig] = await PublicKey.findProgramAddress(
+        [Buffer.from(TREASURY_CONFIG_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
+    const [escrowVault] = await PublicKey.findProgramAddress(
         [Buffer.from(ESCROW_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
+    const [feeVault] = await PublicKey.findProgramAddress(
+        [Buffer.from(FEE_VAULT_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
     
     let tx = new Transaction();
     console.log('==>initializing program', globalAuthority.toBase58());
 
-    tx.add(program.instruction.initialize(
-        bump, escrow_bump, {
+    tx.add(program.instruction.initialize({
         accounts: {
             admin: userAddress,
             globalAuthority,
+            treasuryConfig,
             escrowVault,
+            feeVault,
             systemProgram: SystemProgram.programId,
             rent: SYSVAR_RENT_PUBKEY,
         },
@@ -395,7 +554,7 @@ export const createUpdateFeeTx = async (
     solFee: number,
     tokenFee: number,
 ) => {
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -404,7 +563,7 @@ export const createUpdateFeeTx = async (
     console.log('==>updating fee', globalAuthority.toBase58(), solFee, tokenFee);
 
     tx.add(program.instruction.updateFee(
-        bump, new anchor.BN(solFee), new anchor.BN(tokenFee), {
+        new anchor.BN(solFee), new anchor.BN(tokenFee), {
         accounts: {
             admin: userAddress,
             globalAuthority,
@@ -420,13 +579,18 @@ export const createAddTreasuryTx = async (
     userAddress: PublicKey,
     address: PublicKey,
     rate: number,
+    label: string,
     program: anchor.Program,
     connection: Connection,
 ) => {
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
+    const [treasuryConfig] = await PublicKey.findProgramAddress(
+        [Buffer.from(TREASURY_CONFIG_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
     
     let tx = new Transaction();
     
@@ -438,14 +602,18 @@ export const createAddTreasuryTx = async (
     );
     console.log("Treasury ABB Account = ", ret1.destinationAccounts[0].toBase58());
 
+    let labelBytes = Buffer.alloc(32);
+    labelBytes.write(label);
 
     if (ret1.instructions.length > 0) ret1.instructions.map((ix) => tx.add(ix));
-    console.log('==>adding team treasury', globalAuthority.toBase58(), address.toBase58(), rate);
+    console.log('==>adding team treasury', globalAuthority.toBase58(), address.toBase58(), rate, label);
     tx.add(program.instruction.addTeamTreasury(
-        bump, address, new anchor.BN(rate), {
+        address, new anchor.BN(rate), [...labelBytes], {
         accounts: {
             admin: userAddress,
             globalAuthority,
+            treasuryConfig,
+            systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
@@ -459,19 +627,25 @@ export const createRemoveTreasuryTx = async (
     program: anchor.Program,
     address: PublicKey,
 ) => {
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
+    const [treasuryConfig] = await PublicKey.findProgramAddress(
+        [Buffer.from(TREASURY_CONFIG_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
     
     let tx = new Transaction();
     console.log('==>removing team treasury', globalAuthority.toBase58(), address.toBase58());
 
     tx.add(program.instruction.removeTeamTreasury(
-        bump, address, {
+        address, {
         accounts: {
             admin: userAddress,
             globalAuthority,
+            treasuryConfig,
+            systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
@@ -484,7 +658,7 @@ export const createInitUserTx = async (
     userAddress: PublicKey,
     program: anchor.Program,
 ) => {
-    const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    const [userPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -492,8 +666,7 @@ export const createInitUserTx = async (
     let tx = new Transaction();
     console.log('==>initializing user pool', userPool.toBase58());
 
-    tx.add(program.instruction.initUserPool(
-        user_bump, {
+    tx.add(program.instruction.initUserPool({
         accounts: {
             owner: userAddress,
             userPool,
@@ -536,12 +709,17 @@ export const createDepositTx = async (
     }
     console.log("User ABB Account = ", userTokenAccount.toBase58());
 
-    const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
+        [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
+    const [escrowVault] = await PublicKey.findProgramAddress(
         [Buffer.from(ESCROW_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    const [userPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -559,13 +737,15 @@ export const createDepositTx = async (
     if (ret1.instructions.length > 0) ret1.instructions.map((ix) => tx.add(ix));
     console.log('==> Depositing', userAddress.toBase58(), 'Sol', sol, 'Token:', token);
     tx.add(program.instruction.depositToEscrow(
-        user_bump, escrow_bump, new anchor.BN(sol), new anchor.BN(token), {
+        new anchor.BN(sol), new anchor.BN(token), {
         accounts: {
             owner: userAddress,
             userPool,
+            globalAuthority,
             escrowVault,
             userTokenAccount,
             escrowTokenAccount: ret1.destinationAccounts[0],
+            paymentMint: ABB_TOKEN_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
         },
@@ -583,7 +763,12 @@ export const createWithdrawTx = async (
     program: anchor.Program,
     connection: Connection,
 ) => {
-    const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
+        [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
+    const [escrowVault] = await PublicKey.findProgramAddress(
         [Buffer.from(ESCROW_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -591,7 +776,7 @@ export const createWithdrawTx = async (
     console.log('escrowVault = ', escrowVault.toBase58());
     console.log("Escrow ABB Account = ", escrowTokenAccount.toBase58());
 
-    const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    const [userPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -609,13 +794,15 @@ export const createWithdrawTx = async (
     if (ret1.instructions.length > 0) ret1.instructions.map((ix) => tx.add(ix));
     console.log('==> Withdrawing', userAddress.toBase58(), 'Sol', sol, 'Token:', token);
     tx.add(program.instruction.withdrawFromEscrow(
-        user_bump, escrow_bump, new anchor.BN(sol), new anchor.BN(token), {
+        new anchor.BN(sol), new anchor.BN(token), {
         accounts: {
             owner: userAddress,
             userPool,
+            globalAuthority,
             escrowVault,
             userTokenAccount: ret1.destinationAccounts[0],
             escrowTokenAccount,
+            paymentMint: ABB_TOKEN_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
         },
@@ -626,34 +813,13 @@ export const createWithdrawTx = async (
     return tx;
 }
 
-export const createInitSellDataTx = async (
-    mint: PublicKey,
-    userAddress: PublicKey,
-    program: anchor.Program,
-) => {
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
-        MARKETPLACE_PROGRAM_ID,
-    );
-    
-    let tx = new Transaction();
-    console.log('==>initializing sell PDA', mint.toBase58(), nftData.toBase58());
-
-    tx.add(program.instruction.initSellData(
-        mint, nft_bump, {
-        accounts: {
-            payer: userAddress,
-            sellDataInfo: nftData,
-            systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
-        },
-        instructions: [],
-        signers: [],
-    }));
-
-    return tx;
-}
-
+/**
+ * The Sell Data PDA is created by the listing itself
+ *
+ * feeMode - 0: Global default fee payer, 1: Seller, 2: Buyer pays the fee on top of the price
+ * collection - verified collection of the NFT, checked only when the listing is restricted
+ * reservedBuyer - only buyer of a private listing, PublicKey.default for public listings
+ */
 export const createListForSellNftTx = async (
     mint: PublicKey,
     userAddress: PublicKey,
@@ -661,12 +827,16 @@ export const createListForSellNftTx = async (
     connection: Connection,
     priceSol: number,
     priceToken: number,
+    feeMode: number = 0,
+    quantity: number = 1,
+    reservedBuyer: PublicKey = PublicKey.default,
+    collection: PublicKey = PublicKey.default,
 ) => {
-    if (priceSol < 0 || priceToken < 0) {
+    if (priceSol < 0 || priceToken < 0 || quantity <= 0) {
         throw 'Invalid Price Value';
     }
 
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -684,8 +854,8 @@ export const createListForSellNftTx = async (
     }
     console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    const [nftData] = await PublicKey.findProgramAddress(
+        [Buffer.from(SELL_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
 
@@ -704,10 +874,12 @@ export const createListForSellNftTx = async (
     let tx = new Transaction();
 
     if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
-    console.log('==>listing', mint.toBase58(), priceSol, priceToken);
+    console.log('==>listing', mint.toBase58(), priceSol, priceToken, 'Quantity:', quantity,
+        'ReservedBuyer:', reservedBuyer.toBase58());
 
     tx.add(program.instruction.listNftForSale(
-        bump, nft_bump, new anchor.BN(priceSol), new anchor.BN(priceToken), {
+        new anchor.BN(priceSol), new anchor.BN(priceToken), feeMode,
+        new anchor.BN(quantity), reservedBuyer, {
         accounts: {
             owner: userAddress,
             globalAuthority,
@@ -716,8 +888,13 @@ export const createListForSellNftTx = async (
             destNftTokenAccount: destinationAccounts[0],
             nftMint: mint,
             mintMetadata: metadata,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            collectionInfo: await getCollectionInfo(collection),
+            mintDenyEntry: await getDenyEntry(mint),
+            ownerDenyEntry: await getDenyEntry(userAddress),
+            nftTokenProgram: await getNftTokenProgram(mint, connection),
             tokenMetadataProgram: METAPLEX,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
         },
         instructions: [],
         signers: [],
@@ -741,13 +918,13 @@ export const createDelistNftTx = async (
     let userTokenAccount = ret.destinationAccounts[0];
     console.log("User NFT = ", mint.toBase58(), userTokenAccount.toBase58());
 
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID
     );
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    const [nftData] = await PublicKey.findProgramAddress(
+        [Buffer.from(SELL_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID
     );
 
@@ -764,8 +941,7 @@ export const createDelistNftTx = async (
 
     if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
     console.log('==> withdrawing', mint.toBase58());
-    tx.add(program.instruction.delistNft(
-        bump, nft_bump, {
+    tx.add(program.instruction.delistNft({
         accounts: {
             owner: userAddress,
             globalAuthority,
@@ -773,7 +949,8 @@ export const createDelistNftTx = async (
             userTokenAccount,
             destNftTokenAccount: destinationAccounts[0],
             nftMint: mint,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            nftTokenProgram: await getNftTokenProgram(mint, connection),
+            systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
@@ -784,11 +961,13 @@ export const createDelistNftTx = async (
 
 export const createPurchaseTx = async (
     mint: PublicKey,
+    seller: PublicKey,
     userAddress: PublicKey,
     byToken: boolean,
-    treasuryAddresses: PublicKey[],
+    quantity: number,
     program: anchor.Program,
     connection: Connection,
+    referrer?: PublicKey,
 ) => {
     let ret = await getATokenAccountsNeedCreate(
         connection,
@@ -819,35 +998,37 @@ export const createPurchaseTx = async (
     }
     console.log("User ABB Account = ", userTokenAccount.toBase58());
 
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID
     );
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
-        MARKETPLACE_PROGRAM_ID
+    const [treasuryConfig] = await PublicKey.findProgramAddress(
+        [Buffer.from(TREASURY_CONFIG_SEED)],
+        MARKETPLACE_PROGRAM_ID,
     );
 
-    const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    const [feeVault] = await PublicKey.findProgramAddress(
+        [Buffer.from(FEE_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
 
+    const [nftData] = await PublicKey.findProgramAddress(
+        [Buffer.from(SELL_DATA_SEED), mint.toBuffer(), seller.toBuffer()],
+        MARKETPLACE_PROGRAM_ID
+    );
 
-    let { destinationAccounts } = await getATokenAccountsNeedCreate(
-        connection,
-        userAddress,
-        globalAuthority,
-        [mint]
+    const [buyerUserPool] = await PublicKey.findProgramAddress(
+        [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+        MARKETPLACE_PROGRAM_ID,
     );
 
-    console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
+    let destNftTokenAccount = await getAssociatedTokenAccount(globalAuthority, mint);
+    console.log("Dest NFT Account = ", destNftTokenAccount.toBase58());
 
-    let sellInfo = await getNFTPoolState(mint, program);
-    let seller = sellInfo.seller;
+    let sellInfo = await getNFTPoolState(mint, seller, program);
 
-    const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    const [sellerUserPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -861,78 +1042,57 @@ export const createPurchaseTx = async (
     console.log('Seller = ', seller.toBase58());
     console.log("seller ABB Account = ", ret1.destinationAccounts[0].toBase58());
 
-    let treasuryAccounts: PublicKey[] = treasuryAddresses;
-    if (byToken) {
-        for (let idx in treasuryAccounts) {
-            treasuryAccounts[idx] = await getAssociatedTokenAccount(treasuryAccounts[idx], ABB_TOKEN_MINT);
-        }
-    }
-    console.log("=> Treasury Accounts:", treasuryAccounts.map((address) => address.toBase58()));
+    let ret3 = await getATokenAccountsNeedCreate(
+        connection,
+        userAddress,
+        feeVault,
+        [ABB_TOKEN_MINT]
+    );
 
     if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
     if (ret1.instructions.length > 0) ret1.instructions.map((ix) => tx.add(ix));
-    console.log('==> Purchasing', mint.toBase58(), 'By Token:', byToken);
+    if (ret3.instructions.length > 0) ret3.instructions.map((ix) => tx.add(ix));
+    console.log('==> Purchasing', mint.toBase58(), 'By Token:', byToken, 'Quantity:', quantity);
     tx.add(program.instruction.purchase(
-        bump, nft_bump, buyer_bump, seller_bump, new anchor.BN(byToken ? 1 : 0), {
+        byToken ? 1 : 0, new anchor.BN(quantity), {
         accounts: {
             buyer: userAddress,
             globalAuthority,
-            buyerUserPool,
+            treasuryConfig,
             sellDataInfo: nftData,
+            buyerUserPool,
             userNftTokenAccount,
-            destNftTokenAccount: destinationAccounts[0],
-            nftMint: mint,
+            destNftTokenAccount,
             seller,
             sellerUserPool,
+            nftMint: mint,
+            mintMetadata: await getMetadata(mint),
+            collectionFee: await getCollectionFee(sellInfo.collection),
+            referralData: await getReferralData(referrer),
             userTokenAccount,
             sellerTokenAccount: ret1.destinationAccounts[0],
+            feeVault,
+            feeVaultTokenAccount: ret3.destinationAccounts[0],
+            mintDenyEntry: await getDenyEntry(mint),
+            buyerDenyEntry: await getDenyEntry(userAddress),
+            sellerDenyEntry: await getDenyEntry(seller),
+            nftTokenProgram: await getNftTokenProgram(mint, connection),
+            paymentMint: ABB_TOKEN_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
-        remainingAccounts: treasuryAccounts.map((address) => {
-            return {
-                pubkey: address,
-                isWritable: true,
-                isSigner: false,
-            }
-        }),
-    }));
-
-    return tx;
-}
-
-export const createInitOfferDataTx = async (
-    mint: PublicKey,
-    userAddress: PublicKey,
-    program: anchor.Program,
-) => {
-    const [offerData, bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
-        MARKETPLACE_PROGRAM_ID,
-    );
-    
-    let tx = new Transaction();
-    console.log('==>initializing offer PDA', mint.toBase58(), offerData.toBase58());
-
-    tx.add(program.instruction.initOfferData(
-        mint, bump, {
-        accounts: {
-            payer: userAddress,
-            offerDataInfo: offerData,
-            systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
-        },
-        instructions: [],
-        signers: [],
+        remainingAccounts: await getCreatorAccounts(mint, byToken, connection),
     }));
 
     return tx;
 }
 
+/** The Offer Data PDA is created by the offer itself */
 export const createMakeOfferTx = async (
     mint: PublicKey,
+    seller: PublicKey,
     userAddress: PublicKey,
     price: number,
     byToken: boolean,
@@ -959,26 +1119,33 @@ export const createMakeOfferTx = async (
     }
     console.log("User ABB Account = ", userTokenAccount.toBase58());
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    const [globalAuthority] = await PublicKey.findProgramAddress(
+        [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+        MARKETPLACE_PROGRAM_ID
+    );
+
+    const [nftData] = await PublicKey.findProgramAddress(
+        [Buffer.from(SELL_DATA_SEED), mint.toBuffer(), seller.toBuffer()],
         MARKETPLACE_PROGRAM_ID
     );
 
-    const [offerData, offer_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+    const [offerData] = await PublicKey.findProgramAddress(
+        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), seller.toBuffer(), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    const [escrowVault] = await PublicKey.findProgramAddress(
         [Buffer.from(ESCROW_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    const [userPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
 
+    let sellInfo = await getNFTPoolState(mint, seller, program);
+
     let ret1 = await getATokenAccountsNeedCreate(
         connection,
         userAddress,
@@ -991,18 +1158,25 @@ export const createMakeOfferTx = async (
     if (ret1.instructions.length > 0) ret1.instructions.map((ix) => tx.add(ix));
     console.log('==> making Offer', mint.toBase58(), userAddress.toBase58(), 'Price:', price, 'ByToken:', byToken);
     tx.add(program.instruction.makeOffer(
-        nft_bump, offer_bump, user_bump, escrow_bump, new anchor.BN(price), new anchor.BN(byToken ? 1 : 0), {
+        new anchor.BN(price), new anchor.BN(byToken ? 1 : 0), {
         accounts: {
             owner: userAddress,
             sellDataInfo: nftData,
             offerDataInfo: offerData,
             nftMint: mint,
+            seller,
             userPool,
+            globalAuthority,
             escrowVault,
             userTokenAccount,
             escrowTokenAccount: ret1.destinationAccounts[0],
+            collectionFee: await getCollectionFee(sellInfo.collection),
+            mintDenyEntry: await getDenyEntry(mint),
+            ownerDenyEntry: await getDenyEntry(userAddress),
+            paymentMint: ABB_TOKEN_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
         },
         instructions: [],
         signers: [],
@@ -1013,18 +1187,18 @@ export const createMakeOfferTx = async (
 
 export const createCancelOfferTx = async (
     mint: PublicKey,
+    seller: PublicKey,
     userAddress: PublicKey,
     program: anchor.Program,
 ) => {
     let tx = new Transaction();
 
-    const [offerData, offer_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+    const [offerData] = await PublicKey.findProgramAddress(
+        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), seller.toBuffer(), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
     console.log('==> canceling Offer', mint.toBase58(), userAddress.toBase58());
-    tx.add(program.instruction.cancelOffer(
-        offer_bump, {
+    tx.add(program.instruction.cancelOffer({
         accounts: {
             owner: userAddress,
             offerDataInfo: offerData,
@@ -1039,43 +1213,53 @@ export const createCancelOfferTx = async (
 
 export const createAcceptOfferTx = async (
     mint: PublicKey,
+    seller: PublicKey,
     buyer: PublicKey,
-    treasuryAddresses: PublicKey[],
     program: anchor.Program,
     connection: Connection,
+    referrer?: PublicKey,
 ) => {
-    let sellInfo = await getNFTPoolState(mint, program);
-    let seller = sellInfo.seller;
-    let offerInfo = await getOfferDataState(mint, buyer, program);
+    let sellInfo = await getNFTPoolState(mint, seller, program);
+    let offerInfo = await getOfferDataState(mint, seller, buyer, program);
 
     let tx = new Transaction();
 
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID
     );
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    const [treasuryConfig] = await PublicKey.findProgramAddress(
+        [Buffer.from(TREASURY_CONFIG_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
+    const [feeVault] = await PublicKey.findProgramAddress(
+        [Buffer.from(FEE_VAULT_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
+    const [nftData] = await PublicKey.findProgramAddress(
+        [Buffer.from(SELL_DATA_SEED), mint.toBuffer(), seller.toBuffer()],
         MARKETPLACE_PROGRAM_ID
     );
 
-    const [offerData, offer_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+    const [offerData] = await PublicKey.findProgramAddress(
+        [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), seller.toBuffer(), buyer.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    const [escrowVault] = await PublicKey.findProgramAddress(
         [Buffer.from(ESCROW_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    const [buyerUserPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    const [sellerUserPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -1098,7 +1282,14 @@ export const createAcceptOfferTx = async (
         seller,
         [ABB_TOKEN_MINT]
     );
-    console.log("Seller ABB Account = ", ret.destinationAccounts[0].toBase58());
+    console.log("Seller ABB Account = ", ret1.destinationAccounts[0].toBase58());
+
+    let ret2 = await getATokenAccountsNeedCreate(
+        connection,
+        seller,
+        feeVault,
+        [ABB_TOKEN_MINT]
+    );
 
     let escrowTokenAccount = await getAssociatedTokenAccount(
         escrowVault,
@@ -1109,21 +1300,13 @@ export const createAcceptOfferTx = async (
 
     if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
     if (ret1.instructions.length > 0) ret1.instructions.map((ix) => tx.add(ix));
-
-    let treasuryAccounts: PublicKey[] = treasuryAddresses;
-    if (offerInfo.byToken.toNumber()) {
-        for (let idx in treasuryAccounts) {
-            treasuryAccounts[idx] = await getAssociatedTokenAccount(treasuryAccounts[idx], ABB_TOKEN_MINT);
-        }
-    }
-    console.log("=> Treasury Accounts:", treasuryAccounts.map((address) => address.toBase58()));
+    if (ret2.instructions.length > 0) ret2.instructions.map((ix) => tx.add(ix));
 
     console.log('==> Accept Offer  Mint:', mint.toBase58(),
         'Buyer:', buyer.toBase58(), 'Seller:', seller.toBase58(),
         'OfferPrice:', offerInfo.offerPrice.toNumber(), 'ByToken:', offerInfo.byToken.toNumber());
 
-    tx.add(program.instruction.acceptOffer(
-        bump, nft_bump, offer_bump, buyer_bump, seller_bump, escrow_bump, {
+    tx.add(program.instruction.acceptOffer({
         accounts: {
             seller,
             sellDataInfo: nftData,
@@ -1131,58 +1314,41 @@ export const createAcceptOfferTx = async (
             offerDataInfo: offerData,
             sellerUserPool,
             nftMint: mint,
+            mintMetadata: await getMetadata(mint),
+            collectionFee: await getCollectionFee(sellInfo.collection),
+            referralData: await getReferralData(referrer),
             globalAuthority,
+            treasuryConfig,
             buyerUserPool,
             userNftTokenAccount: ret.destinationAccounts[0],
             destNftTokenAccount,
             escrowVault,
             userTokenAccount: ret1.destinationAccounts[0],
             escrowTokenAccount,
+            feeVault,
+            feeVaultTokenAccount: ret2.destinationAccounts[0],
+            mintDenyEntry: await getDenyEntry(mint),
+            buyerDenyEntry: await getDenyEntry(buyer),
+            sellerDenyEntry: await getDenyEntry(seller),
+            nftTokenProgram: await getNftTokenProgram(mint, connection),
+            paymentMint: ABB_TOKEN_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
-        remainingAccounts: treasuryAccounts.map((address) => {
-            return {
-                pubkey: address,
-                isWritable: true,
-                isSigner: false,
-            }
-        }),
-    }));
-
-    return tx;
-}
-
-export const createInitAuctionDataTx = async (
-    mint: PublicKey,
-    userAddress: PublicKey,
-    program: anchor.Program,
-) => {
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-        [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-        MARKETPLACE_PROGRAM_ID,
-    );
-    
-    let tx = new Transaction();
-    console.log('==>initializing auction PDA', mint.toBase58(), nftData.toBase58());
-
-    tx.add(program.instruction.initAuctionData(
-        mint, nft_bump, {
-        accounts: {
-            payer: userAddress,
-            auctionDataInfo: nftData,
-            systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
-        },
-        instructions: [],
-        signers: [],
+        remainingAccounts: await getCreatorAccounts(mint, offerInfo.byToken.toNumber() == 1, connection),
     }));
 
     return tx;
 }
 
+/**
+ * The Auction Data PDA is created by the auction itself
+ *
+ * feeMode - 0: Global default fee payer, 1: Seller, 2: Buyer pays the fee on top of the bid
+ * collection - verified collection of the NFT, checked only when the listing is restricted
+ */
 export const createCreateAuctionTx = async (
     mint: PublicKey,
     userAddress: PublicKey,
@@ -1192,12 +1358,14 @@ export const createCreateAuctionTx = async (
     endDate: number,
     program: anchor.Program,
     connection: Connection,
+    feeMode: number = 0,
+    collection: PublicKey = PublicKey.default,
 ) => {
     if (startPrice < 0 || minIncrease < 0 || endDate < 0) {
         throw 'Invalid Price Value';
     }
 
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -1215,7 +1383,7 @@ export const createCreateAuctionTx = async (
     }
     console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    const [nftData] = await PublicKey.findProgramAddress(
         [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -1236,9 +1404,9 @@ export const createCreateAuctionTx = async (
         mint.toBase58(), startPrice, minIncrease, byToken, endDate);
 
     tx.add(program.instruction.createAuction(
-        bump, nft_bump, new anchor.BN(startPrice),
+        new anchor.BN(startPrice),
         new anchor.BN(minIncrease), new anchor.BN(byToken ? 1 : 0),
-        new anchor.BN(endDate), {
+        new anchor.BN(endDate), feeMode, {
         accounts: {
             owner: userAddress,
             globalAuthority,
@@ -1246,7 +1414,13 @@ export const createCreateAuctionTx = async (
             userTokenAccount,
             destNftTokenAccount: destinationAccounts[0],
             nftMint: mint,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            mintMetadata: await getMetadata(mint),
+            collectionInfo: await getCollectionInfo(collection),
+            mintDenyEntry: await getDenyEntry(mint),
+            ownerDenyEntry: await getDenyEntry(userAddress),
+            nftTokenProgram: await getNftTokenProgram(mint, connection),
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
         },
         instructions: [],
         signers: [],
@@ -1259,6 +1433,7 @@ export const createPlaceBidTx = async (
     mint: PublicKey,
     userAddress: PublicKey,
     price: number,
+    byEscrow: boolean,
     program: anchor.Program,
     connection: Connection,
 ) => {
@@ -1278,22 +1453,32 @@ export const createPlaceBidTx = async (
             let accountOfABB = await getTokenAccount(ABB_TOKEN_MINT, userAddress, connection);
             userTokenAccount = accountOfABB;
         } catch (e) {
-            if (!auctionInfo.byToken.toNumber()) tx.add(ret.instructions[0]);
+            if (!auctionInfo.byToken.toNumber() || byEscrow) tx.add(ret.instructions[0]);
             else throw 'No ABB Token Account for this user';
         }
     }
     console.log("Bidder ABB Account = ", userTokenAccount.toBase58());
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
+        [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+        MARKETPLACE_PROGRAM_ID
+    );
+
+    const [nftData] = await PublicKey.findProgramAddress(
         [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
         MARKETPLACE_PROGRAM_ID
     );
 
-    const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    const [escrowVault] = await PublicKey.findProgramAddress(
         [Buffer.from(ESCROW_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
 
+    const [bidderUserPool] = await PublicKey.findProgramAddress(
+        [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
     let ret1 = await getATokenAccountsNeedCreate(
         connection,
         userAddress,
@@ -1320,20 +1505,32 @@ export const createPlaceBidTx = async (
         if (ret2.instructions.length > 0) ret2.instructions.map((ix) => tx.add(ix));
     }
 
+    const [outBidderUserPool] = await PublicKey.findProgramAddress(
+        [Buffer.from(USER_DATA_SEED), outBidder.toBuffer()],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
     console.log('==> placing Bid', mint.toBase58(),
-        userAddress.toBase58(), 'Price:', price, 'ByToken:', auctionInfo.byToken.toNumber(),
+        userAddress.toBase58(), 'Price:', price, 'ByToken:', auctionInfo.byToken.toNumber(), 'ByEscrow:', byEscrow,
         'LastBidder:', outBidder.toBase58(), 'LastBidderATA:', outBidderTokenAccount.toBase58());
     tx.add(program.instruction.placeBid(
-        nft_bump, escrow_bump, new anchor.BN(price), {
+        new anchor.BN(price), byEscrow ? 1 : 0, {
         accounts: {
             bidder: userAddress,
             auctionDataInfo: nftData,
             nftMint: mint,
+            globalAuthority,
             escrowVault,
             bidderTokenAccount: userTokenAccount,
             escrowTokenAccount: ret1.destinationAccounts[0],
             outBidder,
             outBidderTokenAccount,
+            outBidderUserPool,
+            bidderUserPool,
+            collectionFee: await getCollectionFee(auctionInfo.collection),
+            mintDenyEntry: await getDenyEntry(mint),
+            bidderDenyEntry: await getDenyEntry(userAddress),
+            paymentMint: ABB_TOKEN_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
         },
@@ -1347,9 +1544,9 @@ export const createPlaceBidTx = async (
 export const createClaimAuctionTx = async (
     mint: PublicKey,
     userAddress: PublicKey,
-    treasuryAddresses: PublicKey[],
     program: anchor.Program,
     connection: Connection,
+    referrer?: PublicKey,
 ) => {
     let ret = await getATokenAccountsNeedCreate(
         connection,
@@ -1358,17 +1555,27 @@ export const createClaimAuctionTx = async (
         [mint]
     );
 
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    const [treasuryConfig] = await PublicKey.findProgramAddress(
+        [Buffer.from(TREASURY_CONFIG_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
+    const [feeVault] = await PublicKey.findProgramAddress(
+        [Buffer.from(FEE_VAULT_SEED)],
+        MARKETPLACE_PROGRAM_ID,
+    );
+
+    const [nftData] = await PublicKey.findProgramAddress(
         [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
         MARKETPLACE_PROGRAM_ID
     );
 
-    const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    const [escrowVault] = await PublicKey.findProgramAddress(
         [Buffer.from(ESCROW_VAULT_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -1389,57 +1596,62 @@ export const createClaimAuctionTx = async (
         creator,
         [ABB_TOKEN_MINT]
     );
-        
+
+    let ret2 = await getATokenAccountsNeedCreate(
+        connection,
+        userAddress,
+        feeVault,
+        [ABB_TOKEN_MINT]
+    );
+
     if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
     if (ret1.instructions.length > 0) ret1.instructions.map((ix) => tx.add(ix));
-    
-    const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    if (ret2.instructions.length > 0) ret2.instructions.map((ix) => tx.add(ix));
+
+    const [userPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [creatorUserPool, creator_bump] = await PublicKey.findProgramAddress(
+    const [creatorUserPool] = await PublicKey.findProgramAddress(
         [Buffer.from(USER_DATA_SEED), creator.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    let treasuryAccounts: PublicKey[] = treasuryAddresses;
-    if (auctionInfo.byToken.toNumber()) {
-        for (let idx in treasuryAccounts) {
-            treasuryAccounts[idx] = await getAssociatedTokenAccount(treasuryAccounts[idx], ABB_TOKEN_MINT);
-        }
-    }
-    console.log("=> Treasury Accounts:", treasuryAccounts.map((address) => address.toBase58()));
-
     console.log('==> claiming Auction', mint.toBase58(), userAddress.toBase58(),
         'Creator:', creator.toBase58(), 'creatorATA:', ret1.destinationAccounts[0].toBase58());
-    tx.add(program.instruction.claimAuction(
-        bump, nft_bump, escrow_bump, {
+    tx.add(program.instruction.claimAuction({
         accounts: {
             bidder: userAddress,
             globalAuthority,
+            treasuryConfig,
             auctionDataInfo: nftData,
             userTokenAccount: ret.destinationAccounts[0],
             destNftTokenAccount,
             nftMint: mint,
+            mintMetadata: await getMetadata(mint),
+            collectionFee: await getCollectionFee(auctionInfo.collection),
+            referralData: await getReferralData(referrer),
             escrowVault,
             escrowTokenAccount,
+            bidderTokenAccount: await getAssociatedTokenAccount(userAddress, ABB_TOKEN_MINT),
+            bidderUserPool: userPool,
             creator,
             creatorTokenAccount: ret1.destinationAccounts[0],
-            bidderUserPool: userPool,
             creatorUserPool,
+            feeVault,
+            feeVaultTokenAccount: ret2.destinationAccounts[0],
+            mintDenyEntry: await getDenyEntry(mint),
+            bidderDenyEntry: await getDenyEntry(userAddress),
+            creatorDenyEntry: await getDenyEntry(creator),
+            nftTokenProgram: await getNftTokenProgram(mint, connection),
+            paymentMint: ABB_TOKEN_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
-        remainingAccounts: treasuryAddresses.map((address) => {
-            return {
-                pubkey: address,
-                isWritable: true,
-                isSigner: false,
-            }
-        }),
+        remainingAccounts: await getCreatorAccounts(mint, auctionInfo.byToken.toNumber() == 1, connection),
     }));
 
     return tx;
@@ -1451,12 +1663,12 @@ export const createCancelAuctionTx = async (
     program: anchor.Program,
     connection: Connection,
 ) => {
-    const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    const [globalAuthority] = await PublicKey.findProgramAddress(
         [Buffer.from(GLOBAL_AUTHORITY_SEED)],
         MARKETPLACE_PROGRAM_ID,
     );
 
-    const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    const [nftData] = await PublicKey.findProgramAddress(
         [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
@@ -1476,8 +1688,7 @@ export const createCancelAuctionTx = async (
     if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
 
     console.log('==> canceling Auction', mint.toBase58());
-    tx.add(program.instruction.cancelAuction(
-        bump, nft_bump, {
+    tx.add(program.instruction.cancelAuction({
         accounts: {
             creator: userAddress,
             globalAuthority,
@@ -1485,11 +1696,12 @@ export const createCancelAuctionTx = async (
             userTokenAccount: destinationAccounts[0],
             destNftTokenAccount,
             nftMint: mint,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            nftTokenProgram: await getNftTokenProgram(mint, connection),
+            systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
     }));
 
     return tx;
-}
\ No newline at end of file
+}
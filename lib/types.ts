@@ -1,32 +1,78 @@
 import * as anchor from '@project-serum/anchor';
 import { PublicKey } from '@solana/web3.js';
 
-export const GLOBAL_AUTHORITY_SEED = "global-authority-v1";
-export const SELL_DATA_SEED = "sell-info-v1";
-export const SELL_DATA_SIZE = 136;
-export const OFFER_DATA_SEED = "offer-info-v1";
-export const OFFER_DATA_SIZE = 104;
-export const USER_DATA_SEED = "user-info-v1";
-export const AUCTION_DATA_SEED = "auction-info-v1";
-export const AUCTION_DATA_SIZE = 160;
+export const GLOBAL_AUTHORITY_SEED = "global-authority-v2";
+export const SELL_DATA_SEED = "sell-info-v2";
+export const SELL_DATA_SIZE = 225;
+export const OFFER_DATA_SEED = "offer-info-v2";
+export const OFFER_DATA_SIZE = 177;
+export const USER_DATA_SEED = "user-info-v2";
+export const AUCTION_DATA_SEED = "auction-info-v2";
+export const AUCTION_DATA_SIZE = 265;
 export const ESCROW_VAULT_SEED = "escrow-vault";
+export const FEE_VAULT_SEED = "fee-vault";
+export const TREASURY_CONFIG_SEED = "treasury-config-v1";
+export const COLLECTION_FEE_SEED = "collection-fee-v1";
+export const REFERRAL_DATA_SEED = "referral-info-v1";
+export const COLLECTION_INFO_SEED = "collection-info-v1";
+export const DENY_ENTRY_SEED = "deny-entry-v1";
+export const BUNDLE_DATA_SEED = "bundle-info-v1";
+export const SWAP_OFFER_SEED = "swap-offer-info-v1";
 
 export const MARKETPLACE_PROGRAM_ID = new PublicKey("C48to8F9VJSrsAjNQrefoF5ZhP54CdKA4xxYy1QTzNTe");
 export const ABB_TOKEN_MINT = new PublicKey("8EoML7gaBJsgJtepm25wq3GuUCqLYHBoqd3HP1JxtyBx");
 export const ABB_TOKEN_DECIMAL = 1_000_000_000;   // ABB Token Decimal
 
 export interface GlobalPool {
-    // 8 + 376
-    superAdmin: PublicKey,      // 32
-    marketFeeSol: anchor.BN,    // 8
-    marketFeeToken: anchor.BN,  // 8
-    teamCount: anchor.BN,       // 8
-    teamTreasury: PublicKey[],  // 8 * 32
-    treasuryRate: anchor.BN[],  // 8 * 8
+    // 8 + 379
+    superAdmin: PublicKey,          // 32
+    marketFeeSol: anchor.BN,        // 8
+    marketFeeToken: anchor.BN,      // 8
+    bump: number,                   // 1
+    escrowBump: number,             // 1
+    primaryTreasury: PublicKey,     // 32
+    dustPolicy: anchor.BN,          // 8
+    feeVaultBump: number,           // 1
+    feeTierCount: anchor.BN,        // 8
+    feeTiers: FeeTier[],            // 4 * 24
+    feeTierBySeller: anchor.BN,     // 8
+    pendingFeeSol: anchor.BN,       // 8
+    pendingFeeToken: anchor.BN,     // 8
+    feeActivationDate: anchor.BN,   // 8
+    referralShare: anchor.BN,       // 8
+    buyerPaysFee: anchor.BN,        // 8
+    creatorFallback: anchor.BN,     // 8
+    restrictedListing: anchor.BN,   // 8
+    pendingFeeTierCount: anchor.BN,     // 8
+    pendingFeeTiers: FeeTier[],         // 4 * 24
+    pendingFeeTierBySeller: anchor.BN,  // 8
+    feeTierActivationDate: anchor.BN,   // 8
+}
+
+export interface FeeTier {
+    // 24
+    solVolume: anchor.BN,           // 8
+    tokenVolume: anchor.BN,         // 8
+    discount: anchor.BN,            // 8
+}
+
+export interface TeamTreasury {
+    // 88
+    address: PublicKey,             // 32
+    rate: anchor.BN,                // 8
+    claimableSol: anchor.BN,        // 8
+    claimableToken: anchor.BN,      // 8
+    label: number[],                // 32
+}
+
+export interface TreasuryConfig {
+    // 8 + 5 + 88 * treasuries.length
+    bump: number,                   // 1
+    treasuries: TeamTreasury[],     // 4 + 88 * len
 }
 
 export interface SellData {
-    // 8 + 128
+    // 8 + 217
     mint: PublicKey,            // 32
     seller: PublicKey,          // 32
     collection: PublicKey,      // 32
@@ -34,20 +80,30 @@ export interface SellData {
     priceToken: anchor.BN,      // 8
     listedDate: anchor.BN,      // 8
     active: anchor.BN,          // 8
+    payer: PublicKey,           // 32
+    bump: number,               // 1
+    buyerPaysFee: anchor.BN,    // 8
+    collectionMethod: anchor.BN,    // 8
+    quantity: anchor.BN,        // 8
+    reservedBuyer: PublicKey,   // 32
 }
 
 export interface OfferData {
-    // 8 + 96
+    // 8 + 169
     mint: PublicKey,                // 32
     buyer: PublicKey,               // 32
     offerPrice: anchor.BN,          // 8
     offerListingDate: anchor.BN,    // 8
     byToken: anchor.BN,             // 8
     active: anchor.BN,              // 8
+    payer: PublicKey,               // 32
+    bump: number,                   // 1
+    feeRate: anchor.BN,             // 8
+    seller: PublicKey,              // 32
 }
 
 export interface AuctionData {
-    // 8 + 152
+    // 8 + 257
     mint: PublicKey,                // 32
     creator: PublicKey,             // 32
     startPrice: anchor.BN,          // 8
@@ -57,14 +113,23 @@ export interface AuctionData {
     lastBidDate: anchor.BN,         // 8
     lastBidder: PublicKey,          // 32
     highestBid: anchor.BN,          // 8
+    bidByEscrow: anchor.BN,         // 8
     status: anchor.BN,              // 8
+    cancelReason: anchor.BN,        // 8
+    payer: PublicKey,               // 32
+    bump: number,                   // 1
+    buyerPaysFee: anchor.BN,        // 8
+    bidFeeDeposit: anchor.BN,       // 8
+    bidFeeRate: anchor.BN,          // 8
+    collection: PublicKey,          // 32
 }
 
 export interface UserData {
-    // 8 + 64
+    // 8 + 65
     address: PublicKey,             // 32
     tradedVolume: anchor.BN,        // 8
     tradedTokenVolume: anchor.BN,   // 8
     escrowSolBalance: anchor.BN,    // 8
     escrowTokenBalance: anchor.BN,  // 8
-}
\ No newline at end of file
+    bump: number,                   // 1
+}
@@ -156,6 +156,147 @@ export const getMetadata = async (mint: PublicKey): Promise<PublicKey> => {
     )[0];
 };
 
+/** Get the creators of the metaplex mint metadata in order, the royalty recipients of a sale */
+export const getMetadataCreators = async (mint: PublicKey, connection: Connection): Promise<PublicKey[]> => {
+    let metadataInfo = await connection.getAccountInfo(await getMetadata(mint));
+    if (!metadataInfo) return [];
+
+    let data = metadataInfo.data;
+    // key, update authority and mint
+    let offset = 1 + 32 + 32;
+    // name, symbol and uri strings
+    for (let idx = 0; idx < 3; idx++) {
+        offset += 4 + data.readUInt32LE(offset);
+    }
+    // seller fee basis points
+    offset += 2;
+    if (data[offset] == 0) return [];
+    offset += 1;
+
+    let creators: PublicKey[] = [];
+    let count = data.readUInt32LE(offset);
+    offset += 4;
+    for (let idx = 0; idx < count; idx++) {
+        // address, verified and share
+        creators.push(new PublicKey(data.slice(offset, offset + 32)));
+        offset += 34;
+    }
+    return creators;
+};
+
+/** Get metaplex master edition account address */
+export const getMasterEdition = async (mint: PublicKey): Promise<PublicKey> => {
+    return (
+        await PublicKey.findProgramAddress(
+            [Buffer.from('metadata'), METAPLEX.toBuffer(), mint.toBuffer(), Buffer.from('edition')],
+            METAPLEX,
+        )
+    )[0];
+};
+
+export interface MetadataCreator {
+    address: PublicKey,
+    verified: boolean,
+    share: number,
+}
+
+const encodeString = (value: string) => {
+    const data = Buffer.from(value);
+    const length = Buffer.alloc(4);
+    length.writeUInt32LE(data.length);
+    return Buffer.concat([length, data]);
+}
+
+/** Create the metaplex mint metadata with CreateMetadataAccountV3, the collection is unverified */
+export const createMetadataInstruction = async (
+    mint: PublicKey,
+    authority: PublicKey,
+    name: string,
+    symbol: string,
+    sellerFeeBasisPoints: number,
+    creators: MetadataCreator[],
+    collection?: PublicKey,
+) => {
+    const sellerFee = Buffer.alloc(2);
+    sellerFee.writeUInt16LE(sellerFeeBasisPoints);
+    const creatorCount = Buffer.alloc(4);
+    creatorCount.writeUInt32LE(creators.length);
+
+    const data = Buffer.concat([
+        Buffer.from([33]),
+        encodeString(name),
+        encodeString(symbol),
+        encodeString(''),
+        sellerFee,
+        Buffer.from([1]),
+        creatorCount,
+        ...creators.map((creator) => Buffer.concat([
+            creator.address.toBuffer(),
+            Buffer.from([creator.verified ? 1 : 0, creator.share]),
+        ])),
+        // collection, uses, is mutable and collection details
+        collection ? Buffer.concat([Buffer.from([1, 0]), collection.toBuffer()]) : Buffer.from([0]),
+        Buffer.from([0, 1, 0]),
+    ]);
+
+    return new TransactionInstruction({
+        keys: [
+            { pubkey: await getMetadata(mint), isSigner: false, isWritable: true },
+            { pubkey: mint, isSigner: false, isWritable: false },
+            { pubkey: authority, isSigner: true, isWritable: false },
+            { pubkey: authority, isSigner: true, isWritable: true },
+            { pubkey: authority, isSigner: true, isWritable: false },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        ],
+        programId: METAPLEX,
+        data,
+    });
+}
+
+/** Create the metaplex master edition of the minted NFT with CreateMasterEditionV3 */
+export const createMasterEditionInstruction = async (
+    mint: PublicKey,
+    authority: PublicKey,
+) => {
+    return new TransactionInstruction({
+        keys: [
+            { pubkey: await getMasterEdition(mint), isSigner: false, isWritable: true },
+            { pubkey: mint, isSigner: false, isWritable: true },
+            { pubkey: authority, isSigner: true, isWritable: false },
+            { pubkey: authority, isSigner: true, isWritable: false },
+            { pubkey: authority, isSigner: true, isWritable: true },
+            { pubkey: await getMetadata(mint), isSigner: false, isWritable: true },
+            { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        ],
+        programId: METAPLEX,
+        // max supply Some(0)
+        data: Buffer.from([17, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+    });
+}
+
+/** Verify the metaplex collection of the mint metadata with VerifyCollection */
+export const verifyCollectionInstruction = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    authority: PublicKey,
+) => {
+    return new TransactionInstruction({
+        keys: [
+            { pubkey: await getMetadata(mint), isSigner: false, isWritable: true },
+            { pubkey: authority, isSigner: true, isWritable: true },
+            { pubkey: authority, isSigner: true, isWritable: true },
+            { pubkey: collectionMint, isSigner: false, isWritable: false },
+            { pubkey: await getMetadata(collectionMint), isSigner: false, isWritable: false },
+            { pubkey: await getMasterEdition(collectionMint), isSigner: false, isWritable: false },
+        ],
+        programId: METAPLEX,
+        data: Buffer.from([18]),
+    });
+}
+
 export const airdropSOL = async (address: PublicKey, amount: number, connection: Connection) => {
   try {
     const txId = await connection.requestAirdrop(address, amount);
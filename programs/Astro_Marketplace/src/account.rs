@@ -1,46 +1,276 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::TEAM_TREASURY_SIZE;
+use crate::math::*;
+
 #[account]
 #[derive(Default)]
 pub struct GlobalPool {
-    // 8 + 376
+    // 8 + 379
     pub super_admin: Pubkey,        // 32
     pub market_fee_sol: u64,        // 8    Permyriad
     pub market_fee_token: u64,      // 8    Permyriad
-    pub team_count: u64,            // 8
-    pub team_treasury: [Pubkey; 8], // 8 * 32
-    pub treasury_rate: [u64; 8],    // 8 * 8
+    pub bump: u8,                   // 1
+    pub escrow_bump: u8,            // 1
+    pub primary_treasury: Pubkey,   // 32   Receives fee dust
+    pub dust_policy: u64,           // 8    0: Primary treasury, 1: Seller
+    pub fee_vault_bump: u8,         // 1
+    pub fee_tier_count: u64,        // 8
+    pub fee_tiers: [FeeTier; 4],    // 4 * 24
+    pub fee_tier_by_seller: u64,    // 8    0: Buyer volume, 1: Seller volume
+    pub pending_fee_sol: u64,       // 8    Zero when no pending increase
+    pub pending_fee_token: u64,     // 8
+    pub fee_activation_date: i64,   // 8    Pending fees apply since this date
+    pub referral_share: u64,        // 8    Permyriad of the fee for the referrer
+    pub buyer_pays_fee: u64,        // 8    Default fee payer, 0: Seller, 1: Buyer
+    pub creator_fallback: u64,      // 8    1: First verified creator as collection without verified collection
+    pub restricted_listing: u64,    // 8    1: Only registered collections can be listed or auctioned
+    pub pending_fee_tier_count: u64,        // 8
+    pub pending_fee_tiers: [FeeTier; 4],    // 4 * 24
+    pub pending_fee_tier_by_seller: u64,    // 8
+    pub fee_tier_activation_date: i64,      // 8    Pending fee tiers apply since this date, zero when none
+}
+
+impl GlobalPool {
+    /// Marketplace fee in effect at the timestamp including the activated pending fee
+    pub fn market_fee(&self, by_token: bool, timestamp: i64) -> u64 {
+        let (current, pending) = if by_token {
+            (self.market_fee_token, self.pending_fee_token)
+        } else {
+            (self.market_fee_sol, self.pending_fee_sol)
+        };
+        effective_fee(current, pending, self.fee_activation_date, timestamp)
+    }
+
+    /// Fee tiers and the tier volume owner in effect at the timestamp including the activated pending tiers
+    pub fn fee_tiers(&self, timestamp: i64) -> (&[FeeTier], u64) {
+        if self.fee_tier_activation_date > 0 && timestamp >= self.fee_tier_activation_date {
+            (
+                &self.pending_fee_tiers[..self.pending_fee_tier_count as usize],
+                self.pending_fee_tier_by_seller,
+            )
+        } else {
+            (
+                &self.fee_tiers[..self.fee_tier_count as usize],
+                self.fee_tier_by_seller,
+            )
+        }
+    }
+}
+
+/// Fee in effect at the timestamp, the pending fee once its activation date is passed
+pub fn effective_fee(current: u64, pending: u64, activation_date: i64, timestamp: i64) -> u64 {
+    if activation_date > 0 && timestamp >= activation_date && pending > 0 {
+        pending
+    } else {
+        current
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeTier {
+    // 24
+    pub sol_volume: u64,            // 8    Minimum traded volume
+    pub token_volume: u64,          // 8    Minimum traded token volume
+    pub discount: u64,              // 8    Fee discount by Permyriad
+}
+
+impl FeeTier {
+    /// Discount of the highest tier reached by the traded volume
+    pub fn discount(tiers: &[FeeTier], volume: u64, by_token: bool) -> u64 {
+        let mut discount: u64 = 0;
+        for tier in tiers.iter() {
+            let threshold = if by_token {
+                tier.token_volume
+            } else {
+                tier.sol_volume
+            };
+            if volume >= threshold {
+                discount = tier.discount;
+            }
+        }
+        discount
+    }
+
+    /// Some traded volume gets a lower discount with the new tiers than with the current tiers
+    pub fn lowers_discount(current: &[FeeTier], new: &[FeeTier]) -> bool {
+        [false, true].iter().any(|&by_token| {
+            // The discounts only change at the tier thresholds
+            std::iter::once(0)
+                .chain(current.iter().chain(new.iter()).map(|tier| {
+                    if by_token {
+                        tier.token_volume
+                    } else {
+                        tier.sol_volume
+                    }
+                }))
+                .any(|volume| {
+                    Self::discount(new, volume, by_token) < Self::discount(current, volume, by_token)
+                })
+        })
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct TreasuryConfig {
+    // 8 + 5 + 88 * treasuries.len()
+    pub bump: u8,                           // 1
+    pub treasuries: Vec<TeamTreasury>,      // 4 + 88 * len
+}
+
+impl TreasuryConfig {
+    pub fn space(count: usize) -> usize {
+        8 + 5 + TEAM_TREASURY_SIZE * count
+    }
+
+    pub fn position(&self, address: &Pubkey) -> Option<usize> {
+        self.treasuries
+            .iter()
+            .position(|treasury| treasury.address.eq(address))
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct CollectionFee {
+    // 8 + 81
+    pub collection: Pubkey,         // 32
+    pub market_fee_sol: u64,        // 8    Permyriad
+    pub market_fee_token: u64,      // 8    Permyriad
+    pub bump: u8,                   // 1
+    pub pending_fee_sol: u64,       // 8    Zero when no pending increase
+    pub pending_fee_token: u64,     // 8
+    pub fee_activation_date: i64,   // 8    Pending fees apply since this date
+    pub removal_date: i64,          // 8    The global fee applies since this date, zero when not removed
+}
+
+impl CollectionFee {
+    /// Collection fee in effect at the timestamp, None once the override is removed
+    pub fn market_fee(&self, by_token: bool, timestamp: i64) -> Option<u64> {
+        if self.removal_date > 0 && timestamp >= self.removal_date {
+            return None;
+        }
+        let (current, pending) = if by_token {
+            (self.market_fee_token, self.pending_fee_token)
+        } else {
+            (self.market_fee_sol, self.pending_fee_sol)
+        };
+        Some(effective_fee(
+            current,
+            pending,
+            self.fee_activation_date,
+            timestamp,
+        ))
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct CollectionInfo {
+    // 8 + 75
+    pub collection: Pubkey,         // 32   Verified collection mint
+    pub name: [u8; 32],             // 32
+    pub symbol: [u8; 10],           // 10
+    pub bump: u8,                   // 1
+}
+
+#[account]
+#[derive(Default)]
+pub struct DenyEntry {
+    // 8 + 57
+    pub address: Pubkey,            // 32   Denied mint or wallet
+    pub kind: u64,                  // 8    0: Mint, 1: Wallet
+    pub reason: u64,                // 8    Reason code, f.e. stolen NFT or sanctioned wallet
+    pub denied_date: i64,           // 8
+    pub bump: u8,                   // 1
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TeamTreasury {
+    // 88
+    pub address: Pubkey,            // 32
+    pub rate: u64,                  // 8    Permyriad
+    pub claimable_sol: u64,         // 8    Accrued fees
+    pub claimable_token: u64,       // 8
+    pub label: [u8; 32],            // 32   Name for reporting
 }
 
 #[account]
 #[derive(Default)]
 pub struct SellData {
-    // 8 + 128
+    // 8 + 217
     pub mint: Pubkey,           // 32
     pub seller: Pubkey,         // 32
     pub collection: Pubkey,     // 32
-    pub price_sol: u64,         // 8
-    pub price_token: u64,       // 8
+    pub price_sol: u64,         // 8    Unit price
+    pub price_token: u64,       // 8    Unit price
     pub listed_date: i64,       // 8
     pub active: u64,            // 8
+    pub payer: Pubkey,          // 32   Rent payer
+    pub bump: u8,               // 1
+    pub buyer_pays_fee: u64,    // 8    0: Seller, 1: Buyer pays the fee on top of the price
+    pub collection_method: u64, // 8    1: Verified collection, 2: First verified creator
+    pub quantity: u64,          // 8    Units left in the listing, prices are per unit
+    pub reserved_buyer: Pubkey, // 32   Only buyer of a private listing, default for public listings
+}
+
+#[account]
+#[derive(Default)]
+pub struct BundleData {
+    // 8 + 337
+    pub seller: Pubkey,             // 32
+    pub collection: Pubkey,         // 32   Collection of every item setting the fee of the bundle
+    pub mints: [Pubkey; 5],         // 5 * 32   First item_count mints are listed
+    pub item_count: u64,            // 8
+    pub price_sol: u64,             // 8
+    pub price_token: u64,           // 8
+    pub listed_date: i64,           // 8
+    pub active: u64,                // 8
+    pub payer: Pubkey,              // 32   Rent payer
+    pub bump: u8,                   // 1
+    pub buyer_pays_fee: u64,        // 8    0: Seller, 1: Buyer pays the fee on top of the price
+    pub reserved_buyer: Pubkey,     // 32   Only buyer of a private bundle, default for public bundles
 }
 
 #[account]
 #[derive(Default)]
 pub struct OfferData {
-    // 8 + 96
+    // 8 + 169
     pub mint: Pubkey,               // 32
     pub buyer: Pubkey,              // 32
     pub offer_price: u64,           // 8
     pub offer_listing_date: i64,    // 8
     pub by_token: u64,              // 8
     pub active: u64,                // 8
+    pub payer: Pubkey,              // 32   Rent payer
+    pub bump: u8,                   // 1
+    pub fee_rate: u64,              // 8    Fee rate escrowed with the offer, the most the buyer is charged
+    pub seller: Pubkey,             // 32   Seller of the offered listing
+}
+
+#[account]
+#[derive(Default)]
+pub struct SwapOfferData {
+    // 8 + 337
+    pub target_mint: Pubkey,        // 32
+    pub buyer: Pubkey,              // 32
+    pub offered_mints: [Pubkey; 5], // 5 * 32   First item_count mints are escrowed
+    pub item_count: u64,            // 8
+    pub price: u64,                 // 8    SOL or abb paid with the offered NFTs
+    pub by_token: u64,              // 8
+    pub active: u64,                // 8
+    pub payer: Pubkey,              // 32   Rent payer
+    pub bump: u8,                   // 1
+    pub collection: Pubkey,         // 32   Collection of the target NFT, default when not resolvable
+    pub fee_rate: u64,              // 8    Fee rate escrowed with the price, the most the buyer is charged
+    pub deposit: u64,               // 8    Price and fee locked in the escrow vault
 }
 
 #[account]
 #[derive(Default)]
 pub struct AuctionData {
-    // 8 + 152
+    // 8 + 257
     pub mint: Pubkey,               // 32
     pub creator: Pubkey,            // 32
     pub start_price: u64,           // 8
@@ -50,16 +280,52 @@ pub struct AuctionData {
     pub last_bid_date: i64,         // 8
     pub last_bidder: Pubkey,        // 32
     pub highest_bid: u64,           // 8
-    pub status: u64,                // 8
+    pub bid_by_escrow: u64,         // 8    1: Highest bid is funded from the bidder's escrow balance
+    pub status: u64,                // 8    1: Live, 2: Claimed, 3: Canceled
+    pub cancel_reason: u64,         // 8    Non zero when canceled by super_admin
+    pub payer: Pubkey,              // 32   Rent payer
+    pub bump: u8,                   // 1
+    pub buyer_pays_fee: u64,        // 8    0: Seller, 1: Buyer pays the fee on top of the bid
+    pub bid_fee_deposit: u64,       // 8    Fee escrowed with the highest bid
+    pub bid_fee_rate: u64,          // 8    Fee rate escrowed with the highest bid, the most the bidder is charged
+    pub collection: Pubkey,         // 32   Default when the NFT has no resolvable collection
 }
 
 #[account]
 #[derive(Default)]
 pub struct UserData {
-    // 8 + 64
+    // 8 + 65
     pub address: Pubkey,            // 32
     pub traded_volume: u64,         // 8
     pub traded_token_volume: u64,   // 8
     pub escrow_sol_balance: u64,    // 8
     pub escrow_token_balance: u64,  // 8
+    pub bump: u8,                   // 1
+}
+
+impl UserData {
+    /**
+     * Credit SOL or abb held in the escrow vault to the escrow balance
+     */
+    pub fn credit_escrow(&mut self, by_token: bool, amount: u64) -> Result<()> {
+        if by_token {
+            self.escrow_token_balance = self.escrow_token_balance.safe_add(amount)?;
+        } else {
+            self.escrow_sol_balance = self.escrow_sol_balance.safe_add(amount)?;
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct ReferralData {
+    // 8 + 73
+    pub referrer: Pubkey,           // 32
+    pub earned_sol: u64,            // 8    Total referral earnings
+    pub earned_token: u64,          // 8
+    pub claimable_sol: u64,         // 8
+    pub claimable_token: u64,       // 8
+    pub bump: u8,                   // 1
+    pub approved: u64,              // 8    1: Registered by super_admin to earn referral fees
 }
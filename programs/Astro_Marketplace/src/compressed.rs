@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::keccak;
+use solana_program::program::invoke_signed;
+
+use crate::account::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::settlement::Royalty;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    // In percentages
+    pub share: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedUses {
+    pub use_method: u8,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+/**
+ * Bubblegum MetadataArgs of the compressed NFT
+ *
+ * Enums are serialized by their variant index
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<u8>,
+    pub collection: Option<CompressedCollection>,
+    pub uses: Option<CompressedUses>,
+    pub token_program_version: u8,
+    pub creators: Vec<CompressedCreator>,
+}
+
+impl CompressedMetadata {
+    /// Leaf data hash of the metadata and the royalty basis points
+    pub fn data_hash(&self) -> Result<[u8; 32]> {
+        let metadata_hash = keccak::hashv(&[&self.try_to_vec()?]);
+        Ok(keccak::hashv(&[
+            &metadata_hash.to_bytes(),
+            &self.seller_fee_basis_points.to_le_bytes(),
+        ])
+        .to_bytes())
+    }
+
+    /// Leaf creator hash of the creator addresses, verified flags and shares
+    pub fn creator_hash(&self) -> [u8; 32] {
+        let mut data = vec![];
+        for creator in self.creators.iter() {
+            data.extend_from_slice(creator.address.as_ref());
+            data.push(creator.verified as u8);
+            data.push(creator.share);
+        }
+        keccak::hashv(&[&data]).to_bytes()
+    }
+
+    /**
+     * Resolve the collection of the compressed NFT
+     *
+     * Returns the collection address and the method used to resolve it like resolve_collection
+     */
+    pub fn resolve_collection(&self, creator_fallback: u64) -> Result<(Pubkey, u64)> {
+        if let Some(collection) = &self.collection {
+            if collection.verified {
+                return Ok((collection.key, 1));
+            }
+        }
+        require_eq!(creator_fallback, 1, MarketplaceError::NoVerifiedCollection);
+        let collection = self
+            .creators
+            .iter()
+            .find(|creator| creator.verified)
+            .map(|creator| creator.address)
+            .unwrap_or_default();
+        Ok((collection, 2))
+    }
+
+    pub fn royalty(&self) -> Royalty {
+        Royalty {
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            creators: self
+                .creators
+                .iter()
+                .map(|creator| (creator.address, creator.share))
+                .collect(),
+        }
+    }
+}
+
+/**
+ * Merkle leaf of the compressed NFT in the current tree root
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedLeaf {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+/**
+ * Get the asset id of the compressed NFT
+ */
+pub fn get_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    let (asset_id, _) = Pubkey::find_program_address(
+        &[
+            ASSET_SEED.as_bytes(),
+            merkle_tree.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &BUBBLEGUM_PROGRAM_ID.parse::<Pubkey>().unwrap(),
+    );
+    asset_id
+}
+
+/**
+ * Assert the leaf belongs to the asset id and carries the hashes of the metadata
+ *
+ * Bubblegum verifies the leaf itself against the tree root in the transfer
+ */
+pub fn assert_compressed_leaf(
+    asset_id: &Pubkey,
+    merkle_tree: &Pubkey,
+    leaf: &CompressedLeaf,
+    metadata: &CompressedMetadata,
+) -> Result<()> {
+    require_keys_eq!(
+        *asset_id,
+        get_asset_id(merkle_tree, leaf.nonce),
+        MarketplaceError::InvalidCompressedNftAccounts
+    );
+    require!(
+        leaf.data_hash == metadata.data_hash()? && leaf.creator_hash == metadata.creator_hash(),
+        MarketplaceError::InvalidCompressedMetadata
+    );
+    Ok(())
+}
+
+/**
+ * Bubblegum accounts transferring a compressed NFT from leaf_owner to new_leaf_owner
+ *
+ * leaf_owner is the seller wallet or the global authority for escrowed compressed NFTs
+ */
+pub struct CompressedTransfer<'info> {
+    pub tree_authority: AccountInfo<'info>,
+    pub leaf_owner: AccountInfo<'info>,
+    pub leaf_delegate: AccountInfo<'info>,
+    pub new_leaf_owner: AccountInfo<'info>,
+    pub merkle_tree: AccountInfo<'info>,
+    pub log_wrapper: AccountInfo<'info>,
+    pub compression_program: AccountInfo<'info>,
+    pub bubblegum_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CompressedTransfer<'info> {
+    /**
+     * Transfer the compressed NFT by Bubblegum
+     *
+     * Bubblegum verifies the leaf with the Merkle proof nodes passed as proof
+     * signer_seeds is empty for the wallet and the global authority seeds otherwise
+     */
+    pub fn execute(
+        &self,
+        leaf: &CompressedLeaf,
+        proof: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        require_keys_eq!(
+            self.bubblegum_program.key(),
+            BUBBLEGUM_PROGRAM_ID.parse::<Pubkey>().unwrap(),
+            MarketplaceError::InvalidCompressedNftAccounts
+        );
+
+        let mut data = BUBBLEGUM_TRANSFER_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&leaf.root);
+        data.extend_from_slice(&leaf.data_hash);
+        data.extend_from_slice(&leaf.creator_hash);
+        data.extend_from_slice(&leaf.nonce.to_le_bytes());
+        data.extend_from_slice(&leaf.index.to_le_bytes());
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.tree_authority.key(), false),
+            AccountMeta::new_readonly(self.leaf_owner.key(), true),
+            AccountMeta::new_readonly(self.leaf_delegate.key(), false),
+            AccountMeta::new_readonly(self.new_leaf_owner.key(), false),
+            AccountMeta::new(self.merkle_tree.key(), false),
+            AccountMeta::new_readonly(self.log_wrapper.key(), false),
+            AccountMeta::new_readonly(self.compression_program.key(), false),
+            AccountMeta::new_readonly(self.system_program.key(), false),
+        ];
+        let mut account_infos = vec![
+            self.tree_authority.clone(),
+            self.leaf_owner.clone(),
+            self.leaf_delegate.clone(),
+            self.new_leaf_owner.clone(),
+            self.merkle_tree.clone(),
+            self.log_wrapper.clone(),
+            self.compression_program.clone(),
+            self.system_program.clone(),
+        ];
+        for node in proof.iter() {
+            accounts.push(AccountMeta::new_readonly(node.key(), false));
+            account_infos.push(node.clone());
+        }
+        account_infos.push(self.bubblegum_program.clone());
+
+        invoke_signed(
+            &Instruction {
+                program_id: self.bubblegum_program.key(),
+                accounts,
+                data,
+            },
+            &account_infos,
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
+
+/**
+ * Transfer the compressed NFT escrowed by the global authority
+ */
+pub fn release_compressed_nft<'info>(
+    global_authority: &Account<'info, GlobalPool>,
+    compressed_transfer: CompressedTransfer<'info>,
+    leaf: &CompressedLeaf,
+    proof: &[AccountInfo<'info>],
+) -> Result<()> {
+    let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_authority.bump]];
+    compressed_transfer.execute(leaf, proof, &[&seeds[..]])
+}
@@ -1,11 +1,39 @@
-pub const GLOBAL_AUTHORITY_SEED: &str = "global-authority-v1";
-pub const SELL_DATA_SEED: &str = "sell-info-v1";
-pub const OFFER_DATA_SEED: &str = "offer-info-v1";
-pub const USER_DATA_SEED: &str = "user-info-v1";
-pub const AUCTION_DATA_SEED: &str = "auction-info-v1";
+pub const GLOBAL_AUTHORITY_SEED: &str = "global-authority-v2";
+pub const SELL_DATA_SEED: &str = "sell-info-v2";
+pub const OFFER_DATA_SEED: &str = "offer-info-v2";
+pub const USER_DATA_SEED: &str = "user-info-v2";
+pub const AUCTION_DATA_SEED: &str = "auction-info-v2";
 pub const ESCROW_VAULT_SEED: &str = "escrow-vault";
+pub const FEE_VAULT_SEED: &str = "fee-vault";
+pub const TREASURY_CONFIG_SEED: &str = "treasury-config-v1";
+pub const COLLECTION_FEE_SEED: &str = "collection-fee-v1";
+pub const REFERRAL_DATA_SEED: &str = "referral-info-v1";
+pub const COLLECTION_INFO_SEED: &str = "collection-info-v1";
+pub const DENY_ENTRY_SEED: &str = "deny-entry-v1";
+pub const BUNDLE_DATA_SEED: &str = "bundle-info-v1";
+pub const SWAP_OFFER_SEED: &str = "swap-offer-info-v1";
 
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+pub const BUBBLEGUM_PROGRAM_ID: &str = "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfxbNRSqbg4Z";
+pub const ASSET_SEED: &str = "asset";   // Bubblegum asset id seed
 pub const REWARD_TOKEN_MINT_PUBKEY: &str = "8EoML7gaBJsgJtepm25wq3GuUCqLYHBoqd3HP1JxtyBx";
 pub const TOKEN_DECIMAL: u64 = 1_000_000_000;   // ABB Token Decimal
 
-pub const PERMYRIAD: u64 = 10_000;      // Permyriad Measure Unit
\ No newline at end of file
+pub const PERMYRIAD: u64 = 10_000;      // Permyriad Measure Unit
+pub const MAX_MARKET_FEE: u64 = 1_000;    // 10% Marketplace fee cap by Permyriad
+pub const FEE_TIMELOCK: i64 = 172_800;    // 2 days delay of fee increases
+pub const MAX_FEE_TIER_COUNT: usize = 4;
+pub const MAX_BUNDLE_SIZE: usize = 5;
+pub const BUNDLE_LIST_ACCOUNT_COUNT: usize = 6;     // Remaining accounts of a bundle item listing
+pub const BUNDLE_DELIST_ACCOUNT_COUNT: usize = 3;   // Remaining accounts of a bundle item delisting
+pub const BUNDLE_PURCHASE_ACCOUNT_COUNT: usize = 5; // Remaining accounts of a bundle item purchase
+pub const MAX_SWAP_ITEM_COUNT: usize = 5;
+pub const SWAP_OFFER_ACCOUNT_COUNT: usize = 4;      // Remaining accounts of an offered swap item
+pub const SWAP_RELEASE_ACCOUNT_COUNT: usize = 3;    // Remaining accounts of a released swap item
+pub const TEAM_TREASURY_SIZE: usize = 88;   // Serialized TeamTreasury size
+pub const PNFT_ACCOUNT_COUNT: usize = 9;    // Remaining accounts of a pNFT transfer
+pub const TOKEN_METADATA_TRANSFER_IX: u8 = 49;  // Token Metadata Transfer instruction index
+pub const TOKEN_TRANSFER_CHECKED_IX: u8 = 12;   // Token TransferChecked instruction index
+pub const TOKEN_CLOSE_ACCOUNT_IX: u8 = 9;       // Token CloseAccount instruction index
+pub const TRANSFER_FEE_CONFIG_EXTENSION: u16 = 1;   // Token-2022 TransferFeeConfig extension type
+pub const BUBBLEGUM_TRANSFER_DISCRIMINATOR: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];   // Bubblegum transfer instruction
@@ -132,4 +132,115 @@ pub enum MarketplaceError {
     // 0x1798
     #[msg("Account Pubkey Does not Matched")]
     MismatchAccountPubkey,
+    // 0x1799
+    #[msg("Bidder Escrow Sol Balance is Less than Bid Price")]
+    InsufficientBidderSolBalance,
+    // 0x179a
+    #[msg("Bidder Escrow Token Balance is Less than Bid Price")]
+    InsufficientBidderTokenBalance,
+    // 0x179b
+    #[msg("Invalid User PDA Account")]
+    InvalidUserPoolAccount,
+    // 0x179c
+    #[msg("Payer Mismatch with Data Account Rent Payer")]
+    RentPayerMismatch,
+    // 0x179d
+    #[msg("Closing Data Account which is Still Active")]
+    ActiveDataAccount,
+    // 0x179e
+    #[msg("The NFT Is Already Listed")]
+    AlreadyListedNFT,
+    // 0x179f
+    #[msg("The NFT Is Already In Live Auction")]
+    AlreadyLiveAuction,
+    // 0x17a0
+    #[msg("Math Operation Overflow")]
+    MathOverflow,
+    // 0x17a1
+    #[msg("Math Operation Underflow")]
+    MathUnderflow,
+    // 0x17a2
+    #[msg("Math Operation Division By Zero")]
+    MathDivisionByZero,
+    // 0x17a3
+    #[msg("Royalty Creator Accounts Count Mismatch with Metadata")]
+    RoyaltyCreatorCountMismatch,
+    // 0x17a4
+    #[msg("Royalty Creator Account Mismatch with Metadata")]
+    RoyaltyCreatorAddressMismatch,
+    // 0x17a5
+    #[msg("Payout Breakdown Does Not Sum To The Price")]
+    PayoutMismatch,
+    // 0x17a6
+    #[msg("Treasury Has Unclaimed Fees")]
+    UnclaimedTreasuryFees,
+    // 0x17a7
+    #[msg("Invalid Collection Fee Account")]
+    InvalidCollectionFeeAccount,
+    // 0x17a8
+    #[msg("Max Fee Tier Count is 4")]
+    MaxFeeTierCountExceed,
+    // 0x17a9
+    #[msg("Fee Tiers Should Be In Ascending Order")]
+    InvalidFeeTierOrder,
+    // 0x17aa
+    #[msg("Invalid Referral Data Account")]
+    InvalidReferralAccount,
+    // 0x17ab
+    #[msg("Trader Can't Refer The Own Trade")]
+    SelfReferral,
+    // 0x17ac
+    #[msg("Referrer Is Not Approved")]
+    ReferralNotApproved,
+    // 0x17ad
+    #[msg("NFT Has No Verified Collection")]
+    NoVerifiedCollection,
+    // 0x17ae
+    #[msg("Invalid Collection Info Account")]
+    InvalidCollectionInfoAccount,
+    // 0x17af
+    #[msg("Collection Is Not Registered")]
+    CollectionNotRegistered,
+    // 0x17b0
+    #[msg("Invalid Deny Entry Account")]
+    InvalidDenyEntryAccount,
+    // 0x17b1
+    #[msg("NFT Or Wallet Is Denylisted")]
+    DeniedAddress,
+    // 0x17b2
+    #[msg("Invalid Programmable NFT Transfer Accounts")]
+    InvalidPnftAccounts,
+    // 0x17b3
+    #[msg("Invalid Token Program")]
+    InvalidTokenProgram,
+    // 0x17b4
+    #[msg("Invalid NFT Token Account")]
+    InvalidNftTokenAccount,
+    // 0x17b5
+    #[msg("NFT Mint Has Transfer Fee")]
+    NftTransferFee,
+    // 0x17b6
+    #[msg("Invalid Compressed NFT Accounts")]
+    InvalidCompressedNftAccounts,
+    // 0x17b7
+    #[msg("Compressed NFT Metadata Mismatch With The Leaf")]
+    InvalidCompressedMetadata,
+    // 0x17b8
+    #[msg("Invalid Bundle Size")]
+    InvalidBundleSize,
+    // 0x17b9
+    #[msg("Invalid Bundle Item Accounts")]
+    InvalidBundleAccounts,
+    // 0x17ba
+    #[msg("Bundle Items Are Of Different Collections")]
+    MixedBundleCollections,
+    // 0x17bb
+    #[msg("Invalid Swap Offer Item Count")]
+    InvalidSwapItemCount,
+    // 0x17bc
+    #[msg("Invalid Swap Offer Item Accounts")]
+    InvalidSwapItemAccounts,
+    // 0x17bd
+    #[msg("Listing Is Reserved For Another Buyer")]
+    ReservedListing,
 }
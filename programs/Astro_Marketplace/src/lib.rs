@@ -1,18 +1,32 @@
+// Anchor's generated entrypoints return the large anchor_lang::error::Error and check cfgs
+// that this crate does not declare
+#![allow(clippy::result_large_err, unexpected_cfgs)]
+
 use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
 // use solana_program::borsh::try_from_slice_unchecked;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use metaplex_token_metadata::state::Metadata;
 use solana_program::program::{invoke, invoke_signed};
 use solana_program::system_instruction;
-use spl_associated_token_account;
 
 pub mod account;
+pub mod compressed;
 pub mod constants;
 pub mod error;
+pub mod math;
+pub mod pnft;
+pub mod settlement;
+pub mod token_interface;
+pub mod utils;
 
 use account::*;
+use compressed::*;
 use constants::*;
 use error::*;
+use math::*;
+use pnft::*;
+use settlement::*;
+use token_interface::*;
+use utils::*;
 
 declare_id!("3TfzeR3fQsoHzaXBkr84WWoYcqseJgUtqzppbu5wafMS");
 
@@ -24,56 +38,49 @@ pub mod astro_marketplace {
      * Initialize global PDA
      *
      * This will save the payer address as the program's super_admin
-     * Initialize the escrow Vault PDA
+     * Initialize the escrow Vault PDA and the fee Vault PDA
+     * Initialize the empty Treasury Config PDA
      *
-     * Params: global pda bump, escrow pda bump
+     * The canonical bumps of global pda and vaults are saved in the Global PDA
      */
-    pub fn initialize(ctx: Context<Initialize>, _global_bump: u8, _escrow_bump: u8) -> Result<()> {
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         let global_authority = &mut ctx.accounts.global_authority;
         global_authority.super_admin = ctx.accounts.admin.key();
-
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(global_authority.key(), expected_address);
-        require_eq!(expected_bump, _global_bump, MarketplaceError::InvalidBump);
-        let (expected_escrow_address, expected_escrow_bump) =
-            Pubkey::find_program_address(&[ESCROW_VAULT_SEED.as_bytes()], &astro_marketplace::ID);
-
-        require_keys_eq!(ctx.accounts.escrow_vault.key(), expected_escrow_address);
-        require_eq!(
-            expected_escrow_bump,
-            _escrow_bump,
-            MarketplaceError::InvalidBump
-        );
+        global_authority.bump = *ctx.bumps.get("global_authority").unwrap();
+        global_authority.escrow_bump = *ctx.bumps.get("escrow_vault").unwrap();
+        global_authority.fee_vault_bump = *ctx.bumps.get("fee_vault").unwrap();
+        ctx.accounts.treasury_config.bump = *ctx.bumps.get("treasury_config").unwrap();
+
+        // Fund the fee vault rent exemption so that small fees can be accrued
+        // The claimable balances never include this rent
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.admin.key,
+                ctx.accounts.fee_vault.key,
+                ctx.accounts.rent.minimum_balance(0),
+            ),
+            &[
+                ctx.accounts.admin.to_account_info().clone(),
+                ctx.accounts.fee_vault.to_account_info().clone(),
+                ctx.accounts.system_program.to_account_info().clone(),
+            ],
+        )?;
         Ok(())
     }
 
     /**
      * Update marketplace fee info in the Global PDA as super_admin
      *
-     * Params:  global pda bump
-     *          sol_fee - marketplace fee as Permyraid for SOL NFT purchasing
+     * Fee decreases apply immediately
+     * Fee increases are pending until the timelock passes so traders can react
+     * Both fees are capped by MAX_MARKET_FEE
+     *
+     * Params:  sol_fee - marketplace fee as Permyraid for SOL NFT purchasing
      *          token_fee - marketplace fee as Permyraid for ABB NFT purchasing
      */
-    pub fn update_fee(
-        ctx: Context<SetThreshold>,
-        _global_bump: u8,
-        sol_fee: u64,
-        token_fee: u64,
-    ) -> Result<()> {
+    pub fn update_fee(ctx: Context<SetThreshold>, sol_fee: u64, token_fee: u64) -> Result<()> {
         let global_authority = &mut ctx.accounts.global_authority;
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(global_authority.key(), expected_address);
-        require_eq!(expected_bump, _global_bump, MarketplaceError::InvalidBump);
-
         // validate sol_fee & token_fee
         require!(sol_fee > 0 && token_fee > 0, MarketplaceError::NonZeroError);
 
@@ -83,98 +90,158 @@ pub mod astro_marketplace {
             MarketplaceError::InvalidSuperOwner
         );
         require!(
-            sol_fee < PERMYRIAD && token_fee < PERMYRIAD,
+            sol_fee <= MAX_MARKET_FEE && token_fee <= MAX_MARKET_FEE,
             MarketplaceError::InvalidFeePercent
         );
 
-        global_authority.market_fee_sol = sol_fee;
-        global_authority.market_fee_token = token_fee;
+        let timestamp = Clock::get()?.unix_timestamp;
+        // Settle the fee change which is already activated
+        global_authority.market_fee_sol = global_authority.market_fee(false, timestamp);
+        global_authority.market_fee_token = global_authority.market_fee(true, timestamp);
+        global_authority.pending_fee_sol = 0;
+        global_authority.pending_fee_token = 0;
+        global_authority.fee_activation_date = 0;
+
+        if sol_fee <= global_authority.market_fee_sol {
+            global_authority.market_fee_sol = sol_fee;
+        } else {
+            global_authority.pending_fee_sol = sol_fee;
+        }
+        if token_fee <= global_authority.market_fee_token {
+            global_authority.market_fee_token = token_fee;
+        } else {
+            global_authority.pending_fee_token = token_fee;
+        }
+        if global_authority.pending_fee_sol > 0 || global_authority.pending_fee_token > 0 {
+            global_authority.fee_activation_date = timestamp
+                .checked_add(FEE_TIMELOCK)
+                .ok_or_else(|| error!(MarketplaceError::MathOverflow))?;
+        }
+        msg!(
+            "Sol Fee: {}, Token Fee: {}, Pending Sol Fee: {}, Pending Token Fee: {}, Activation Date: {}",
+            global_authority.market_fee_sol,
+            global_authority.market_fee_token,
+            global_authority.pending_fee_sol,
+            global_authority.pending_fee_token,
+            global_authority.fee_activation_date
+        );
         Ok(())
     }
 
     /**
-     * Add team treasury account
+     * Update the traded volume fee tiers as super_admin
      *
-     * Params: global pda bump
-     *          treasury address
-     *          distribution rate by permyriad
+     * The fee of a trade is discounted by the highest tier reached by the buyer or the seller traded volume
+     * Tiers lowering the discount of some traded volume or switching the tier volume owner
+     * are activated after the fee timelock, other tiers apply immediately
+     *
+     * Params:  tiers - volume thresholds and fee discounts by permyriad in ascending order, maximum 4
+     *          by_seller - 0: buyer volume, 1: seller volume
      */
-    pub fn add_team_treasury(
-        ctx: Context<AddTreasury>,
-        _global_bump: u8,
-        address: Pubkey,
-        rate: u64,
+    pub fn update_fee_tiers(
+        ctx: Context<SetThreshold>,
+        tiers: Vec<FeeTier>,
+        by_seller: u64,
     ) -> Result<()> {
         let global_authority = &mut ctx.accounts.global_authority;
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(global_authority.key(), expected_address);
-        require_eq!(expected_bump, _global_bump, MarketplaceError::InvalidBump);
-
         // Assert payer is the superadmin
         require_keys_eq!(
             global_authority.super_admin,
             ctx.accounts.admin.key(),
             MarketplaceError::InvalidSuperOwner
         );
-        // Max Team Treasury Count is 8
-        require!(
-            global_authority.team_count < 8,
-            MarketplaceError::MaxTeamCountExceed
-        );
-        // Distribution rate by Permyriad
+        // By Seller should be zero or one
+        require!(by_seller < 2, MarketplaceError::InvalidParamInput);
         require!(
-            rate <= PERMYRIAD && rate > 0,
-            MarketplaceError::InvalidFeePercent
+            tiers.len() <= MAX_FEE_TIER_COUNT,
+            MarketplaceError::MaxFeeTierCountExceed
         );
 
-        let mut exist: u8 = 0;
-        let mut sum: u64 = rate;
-        for i in 0..global_authority.team_count {
-            let index = i as usize;
-            if global_authority.team_treasury[index].eq(&address) {
-                exist = 1;
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                tier.discount > 0 && tier.discount <= PERMYRIAD,
+                MarketplaceError::InvalidFeePercent
+            );
+            // Higher tier needs more volume and gives more discount
+            if i > 0 {
+                let prev = &tiers[i - 1];
+                require!(
+                    tier.sol_volume > prev.sol_volume
+                        && tier.token_volume > prev.token_volume
+                        && tier.discount > prev.discount,
+                    MarketplaceError::InvalidFeeTierOrder
+                );
             }
-            // CHECK: This operation won't overflow because each rate is less than 10000 permyraid
-            // and the team_count is maximum 8
-            sum += global_authority.treasury_rate[index];
         }
-        require_eq!(exist, 0, MarketplaceError::TreasuryAddressAlreadyAdded);
-        // Total sum of treasury rates less than full permyriad
-        require!(sum <= PERMYRIAD, MarketplaceError::MaxTreasuryRateSumExceed);
 
-        let index: usize = global_authority.team_count as usize;
-        global_authority.team_treasury[index] = address;
-        global_authority.treasury_rate[index] = rate;
-        global_authority.team_count += 1;
+        let timestamp = Clock::get()?.unix_timestamp;
+        // Settle the tier change which is already activated
+        let (current_tiers, current_by_seller) = global_authority.fee_tiers(timestamp);
+        let mut current = [FeeTier::default(); MAX_FEE_TIER_COUNT];
+        let current_count = current_tiers.len();
+        current[..current_count].copy_from_slice(current_tiers);
+        global_authority.fee_tiers = current;
+        global_authority.fee_tier_count = current_count as u64;
+        global_authority.fee_tier_by_seller = current_by_seller;
+        global_authority.pending_fee_tiers = [FeeTier::default(); MAX_FEE_TIER_COUNT];
+        global_authority.pending_fee_tier_count = 0;
+        global_authority.pending_fee_tier_by_seller = 0;
+        global_authority.fee_tier_activation_date = 0;
+
+        let mut new = [FeeTier::default(); MAX_FEE_TIER_COUNT];
+        new[..tiers.len()].copy_from_slice(&tiers);
+        if by_seller == current_by_seller
+            && !FeeTier::lowers_discount(&current[..current_count], &tiers)
+        {
+            global_authority.fee_tiers = new;
+            global_authority.fee_tier_count = tiers.len() as u64;
+        } else {
+            global_authority.pending_fee_tiers = new;
+            global_authority.pending_fee_tier_count = tiers.len() as u64;
+            global_authority.pending_fee_tier_by_seller = by_seller;
+            global_authority.fee_tier_activation_date = timestamp
+                .checked_add(FEE_TIMELOCK)
+                .ok_or_else(|| error!(MarketplaceError::MathOverflow))?;
+        }
+        msg!(
+            "Fee Tier Count: {}, Pending Fee Tier Count: {}, Activation Date: {}",
+            global_authority.fee_tier_count,
+            global_authority.pending_fee_tier_count,
+            global_authority.fee_tier_activation_date
+        );
         Ok(())
     }
 
     /**
-     * Remove team treasury account
+     * Update the referrer share of the marketplace fee as super_admin
      *
-     * Params: global pda bump
-     *          treasury address
+     * Params: share - referrer share of the fee by permyriad
      */
-    pub fn remove_team_treasury(
-        ctx: Context<RemoveTreasury>,
-        _global_bump: u8,
-        address: Pubkey,
-    ) -> Result<()> {
+    pub fn update_referral_share(ctx: Context<SetThreshold>, share: u64) -> Result<()> {
         let global_authority = &mut ctx.accounts.global_authority;
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
         );
-        require_keys_eq!(global_authority.key(), expected_address);
+        require!(share <= PERMYRIAD, MarketplaceError::InvalidFeePercent);
 
-        require_eq!(expected_bump, _global_bump, MarketplaceError::InvalidBump);
+        global_authority.referral_share = share;
+        Ok(())
+    }
+
+    /**
+     * Update the default marketplace fee payer as super_admin
+     *
+     * Listings and auctions created with the default fee mode use this payer
+     *
+     * Params: buyer_pays_fee - 0: seller pays from the price, 1: buyer pays on top of the price
+     */
+    pub fn update_fee_payer(ctx: Context<SetThreshold>, buyer_pays_fee: u64) -> Result<()> {
+        let global_authority = &mut ctx.accounts.global_authority;
 
         // Assert payer is the superadmin
         require_keys_eq!(
@@ -182,692 +249,980 @@ pub mod astro_marketplace {
             ctx.accounts.admin.key(),
             MarketplaceError::InvalidSuperOwner
         );
-        // Assert no treasury exist
-        require!(
-            global_authority.team_count > 0,
-            MarketplaceError::NoTeamTreasuryYet
-        );
+        // Fee payer should be zero or one
+        require!(buyer_pays_fee < 2, MarketplaceError::InvalidParamInput);
 
-        let mut exist: u8 = 0;
-        for i in 0..global_authority.team_count {
-            let index = i as usize;
-            if global_authority.team_treasury[index].eq(&address) {
-                if i < global_authority.team_count - 1 {
-                    let last_idx = (global_authority.team_count - 1) as usize;
-                    global_authority.team_treasury[index] =
-                        global_authority.team_treasury[last_idx];
-                    global_authority.treasury_rate[index] =
-                        global_authority.treasury_rate[last_idx];
-
-                    global_authority.team_treasury[last_idx] = Pubkey::default();
-                    global_authority.treasury_rate[last_idx] = 0;
-                }
-                global_authority.team_count -= 1;
-                exist = 1;
-            }
-        }
-        require_eq!(exist, 1, MarketplaceError::TreasuryAddressNotFound);
+        global_authority.buyer_pays_fee = buyer_pays_fee;
         Ok(())
     }
 
     /**
-     * Initialize User PDA for Escrow & Traded Volume
+     * Enable the first verified creator as the collection of NFTs without verified collection
+     *
+     * Params: creator_fallback - 0: verified collection only, 1: creator fallback enabled
      */
-    pub fn init_user_pool(ctx: Context<InitUserPool>, _bump: u8) -> Result<()> {
-        let user_pool = &mut ctx.accounts.user_pool;
-        user_pool.address = ctx.accounts.owner.key();
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[USER_DATA_SEED.as_bytes(), ctx.accounts.owner.key().as_ref()],
-            &astro_marketplace::ID,
+    pub fn update_collection_fallback(
+        ctx: Context<SetThreshold>,
+        creator_fallback: u64,
+    ) -> Result<()> {
+        let global_authority = &mut ctx.accounts.global_authority;
+
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
         );
-        require_keys_eq!(user_pool.key(), expected_address);
-        require_eq!(expected_bump, _bump, MarketplaceError::InvalidBump);
+        // Creator fallback should be zero or one
+        require!(creator_fallback < 2, MarketplaceError::InvalidParamInput);
+
+        global_authority.creator_fallback = creator_fallback;
         Ok(())
     }
 
     /**
-     * Init NFT listed info - Sell Data PDA
+     * Restrict listings and auctions to the registered collections as super_admin
+     *
+     * Params: restricted - 0: any NFT, 1: registered collections only
      */
-    pub fn init_sell_data(ctx: Context<InitSellData>, nft: Pubkey, _bump: u8) -> Result<()> {
-        let sell_data_info = &mut ctx.accounts.sell_data_info;
-        sell_data_info.mint = nft;
+    pub fn update_listing_mode(ctx: Context<SetThreshold>, restricted: u64) -> Result<()> {
+        let global_authority = &mut ctx.accounts.global_authority;
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[SELL_DATA_SEED.as_bytes(), nft.as_ref()],
-            &astro_marketplace::ID,
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
         );
-        require_keys_eq!(ctx.accounts.sell_data_info.key(), expected_address);
-        require_eq!(expected_bump, _bump, MarketplaceError::InvalidBump);
+        // Restricted should be zero or one
+        require!(restricted < 2, MarketplaceError::InvalidParamInput);
 
+        global_authority.restricted_listing = restricted;
         Ok(())
     }
 
     /**
-     * List NFT for sale
+     * Update the receiver of the fee dust
      *
-     * Params:  global pda bump
-     *          nft sell data pda bump
-     *          price_sol is the selling price in sol
-     *          price_token is the selling price in abb
+     * The fee dust is the part of the marketplace fee not covered by the treasury rates or lost by rounding
+     *
+     * Params:  policy - 0: primary treasury, 1: seller
+     *          primary_treasury - team treasury receiving the dust for policy 0
      */
-    pub fn list_nft_for_sale(
-        ctx: Context<ListNftForSale>,
-        _global_bump: u8,
-        _sell_bump: u8,
-        price_sol: u64,
-        price_token: u64,
+    pub fn update_dust_policy(
+        ctx: Context<UpdateDustPolicy>,
+        policy: u64,
+        primary_treasury: Pubkey,
     ) -> Result<()> {
-        let sell_data_info = &mut ctx.accounts.sell_data_info;
-        msg!("Mint: {:?}", sell_data_info.mint);
+        let global_authority = &mut ctx.accounts.global_authority;
 
-        require!(
-            price_sol > 0 && price_token > 0,
-            MarketplaceError::NonZeroError
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
         );
+        // Policy should be zero or one
+        require!(policy < 2, MarketplaceError::InvalidParamInput);
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(ctx.accounts.global_authority.key(), expected_address);
-        require_eq!(expected_bump, _global_bump, MarketplaceError::InvalidBump);
-        // Validate PDA bump and seed
-        let (expected_selldata_address, expected_selldata_bump) = Pubkey::find_program_address(
-            &[
-                SELL_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
+        if policy == 0 {
+            // Assert Primary Treasury is one of the Team Treasuries
+            require!(
+                ctx.accounts
+                    .treasury_config
+                    .position(&primary_treasury)
+                    .is_some(),
+                MarketplaceError::TreasuryAddressNotFound
+            );
+            global_authority.primary_treasury = primary_treasury;
+        }
+        global_authority.dust_policy = policy;
+        Ok(())
+    }
+
+    /**
+     * Add team treasury account
+     *
+     * The Treasury Config PDA grows by one entry and the admin pays the extra rent
+     *
+     * Params:  treasury address
+     *          distribution rate by permyriad
+     *          label - treasury name for reporting
+     */
+    pub fn add_team_treasury(
+        ctx: Context<AddTreasury>,
+        address: Pubkey,
+        rate: u64,
+        label: [u8; 32],
+    ) -> Result<()> {
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
         );
-        require_keys_eq!(sell_data_info.key(), expected_selldata_address);
-        require_eq!(
-            expected_selldata_bump,
-            _sell_bump,
-            MarketplaceError::InvalidBump
+        // Distribution rate by Permyriad
+        require!(
+            rate <= PERMYRIAD && rate > 0,
+            MarketplaceError::InvalidFeePercent
         );
 
-        // Assert NFT Pubkey with Sell Data PDA Mint
+        let treasury_config = &mut ctx.accounts.treasury_config;
         require!(
-            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
+            treasury_config.position(&address).is_none(),
+            MarketplaceError::TreasuryAddressAlreadyAdded
         );
+        let mut sum: u64 = rate;
+        for treasury in treasury_config.treasuries.iter() {
+            sum = sum.safe_add(treasury.rate)?;
+        }
+        // Total sum of treasury rates less than full permyriad
+        require!(sum <= PERMYRIAD, MarketplaceError::MaxTreasuryRateSumExceed);
 
-        // Get Collection address from Metadata
-        let mint_metadata = &mut &ctx.accounts.mint_metadata;
-        msg!("Metadata Account: {:?}", ctx.accounts.mint_metadata.key());
-        let (metadata, _) = Pubkey::find_program_address(
-            &[
-                metaplex_token_metadata::state::PREFIX.as_bytes(),
-                metaplex_token_metadata::id().as_ref(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &metaplex_token_metadata::id(),
-        );
+        resize_account(
+            &treasury_config.to_account_info(),
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            TreasuryConfig::space(treasury_config.treasuries.len() + 1),
+        )?;
+        treasury_config.treasuries.push(TeamTreasury {
+            address,
+            rate,
+            claimable_sol: 0,
+            claimable_token: 0,
+            label,
+        });
+        Ok(())
+    }
+
+    /**
+     * Update the distribution rate and label of a team treasury in place
+     *
+     * Params:  treasury address
+     *          distribution rate by permyriad
+     *          label - treasury name for reporting
+     */
+    pub fn update_team_treasury(
+        ctx: Context<UpdateTreasury>,
+        address: Pubkey,
+        rate: u64,
+        label: [u8; 32],
+    ) -> Result<()> {
+        // Assert payer is the superadmin
         require_keys_eq!(
-            metadata,
-            mint_metadata.key(),
-            MarketplaceError::InvaliedMetadata
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        // Distribution rate by Permyriad
+        require!(
+            rate <= PERMYRIAD && rate > 0,
+            MarketplaceError::InvalidFeePercent
         );
 
-        // verify metadata is legit
-        let nft_metadata = Metadata::from_account_info(mint_metadata)?;
-
-        if let Some(creators) = nft_metadata.data.creators {
-            let mut collection: Pubkey = Pubkey::default();
-            for creator in creators {
-                if creator.verified == true {
-                    collection = creator.address;
-                    break;
-                }
+        let treasury_config = &mut ctx.accounts.treasury_config;
+        let index = treasury_config
+            .position(&address)
+            .ok_or_else(|| error!(MarketplaceError::TreasuryAddressNotFound))?;
+        let mut sum: u64 = rate;
+        for (i, treasury) in treasury_config.treasuries.iter().enumerate() {
+            if i != index {
+                sum = sum.safe_add(treasury.rate)?;
             }
-            sell_data_info.collection = collection;
-            msg!("Collection= {:?}", collection);
-        } else {
-            return Err(error!(MarketplaceError::MetadataCreatorParseError));
-        };
-
-        let timestamp = Clock::get()?.unix_timestamp;
-        msg!("Listed Date: {}", timestamp);
-
-        sell_data_info.seller = ctx.accounts.owner.key();
-        sell_data_info.price_sol = price_sol;
-        sell_data_info.price_token = price_token;
-        sell_data_info.listed_date = timestamp;
-        sell_data_info.active = 1;
-
-        let token_account_info = &mut &ctx.accounts.user_token_account;
-        let dest_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
-
-        let cpi_accounts = Transfer {
-            from: token_account_info.to_account_info().clone(),
-            to: dest_token_account_info.to_account_info().clone(),
-            authority: ctx.accounts.owner.to_account_info().clone(),
-        };
-        token::transfer(
-            CpiContext::new(token_program.clone().to_account_info(), cpi_accounts),
-            1,
-        )?;
+        }
+        // Total sum of treasury rates less than full permyriad
+        require!(sum <= PERMYRIAD, MarketplaceError::MaxTreasuryRateSumExceed);
 
+        treasury_config.treasuries[index].rate = rate;
+        treasury_config.treasuries[index].label = label;
         Ok(())
     }
 
     /**
-     * Cancel NFT lising
+     * Remove team treasury account
      *
-     * Params:  global pda bump
-     *          nft sell data pda bump
+     * The accrued fees should be claimed before removal
+     * The released rent of the Treasury Config PDA is returned to the admin
+     *
+     * Params: treasury address
      */
-    pub fn delist_nft(ctx: Context<DelistNft>, global_bump: u8, _sell_bump: u8) -> Result<()> {
-        let sell_data_info = &mut ctx.accounts.sell_data_info;
-        msg!("Mint: {:?}", sell_data_info.mint);
-
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(ctx.accounts.global_authority.key(), expected_address);
-        require_eq!(expected_bump, global_bump, MarketplaceError::InvalidBump);
-        // Validate PDA bump and seed
-        let (expected_selldata_address, expected_selldata_bump) = Pubkey::find_program_address(
-            &[
-                SELL_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
+    pub fn remove_team_treasury(ctx: Context<RemoveTreasury>, address: Pubkey) -> Result<()> {
+        let global_authority = &mut ctx.accounts.global_authority;
 
-        require_keys_eq!(sell_data_info.key(), expected_selldata_address);
-        require_eq!(
-            expected_selldata_bump,
-            _sell_bump,
-            MarketplaceError::InvalidBump
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
         );
 
-        // Assert NFT Pubkey with Sell Data PDA Mint
+        let treasury_config = &mut ctx.accounts.treasury_config;
+        // Assert no treasury exist
         require!(
-            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
+            !treasury_config.treasuries.is_empty(),
+            MarketplaceError::NoTeamTreasuryYet
         );
-        // Assert NFT seller is payer
+        let index = treasury_config
+            .position(&address)
+            .ok_or_else(|| error!(MarketplaceError::TreasuryAddressNotFound))?;
+        // Assert the accrued fees are claimed before removal
         require!(
-            ctx.accounts.owner.key().eq(&sell_data_info.seller),
-            MarketplaceError::SellerMismatch
+            treasury_config.treasuries[index].claimable_sol == 0
+                && treasury_config.treasuries[index].claimable_token == 0,
+            MarketplaceError::UnclaimedTreasuryFees
         );
-        // Assert Already Delisted NFT
-        require_eq!(sell_data_info.active, 1, MarketplaceError::NotListedNFT);
-
-        sell_data_info.active = 0;
-
-        let token_account_info = &mut &ctx.accounts.user_token_account;
-        let dest_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
-        let signer = &[&seeds[..]];
-
-        let cpi_accounts = Transfer {
-            from: dest_token_account_info.to_account_info().clone(),
-            to: token_account_info.to_account_info().clone(),
-            authority: ctx.accounts.global_authority.to_account_info(),
-        };
-        token::transfer(
-            CpiContext::new_with_signer(
-                token_program.clone().to_account_info(),
-                cpi_accounts,
-                signer,
-            ),
-            1,
-        )?;
 
-        invoke_signed(
-            &spl_token::instruction::close_account(
-                token_program.key,
-                &dest_token_account_info.key(),
-                ctx.accounts.owner.key,
-                &ctx.accounts.global_authority.key(),
-                &[],
-            )?,
-            &[
-                token_program.clone().to_account_info(),
-                dest_token_account_info.to_account_info().clone(),
-                ctx.accounts.owner.to_account_info().clone(),
-                ctx.accounts.global_authority.to_account_info().clone(),
-            ],
-            signer,
+        // Keep the order so that the first treasury stays the dust fallback
+        treasury_config.treasuries.remove(index);
+        resize_account(
+            &treasury_config.to_account_info(),
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            TreasuryConfig::space(treasury_config.treasuries.len()),
         )?;
 
+        // Fee dust falls back to the first treasury until a new primary treasury is set
+        if global_authority.primary_treasury.eq(&address) {
+            global_authority.primary_treasury = Pubkey::default();
+        }
         Ok(())
     }
 
     /**
-     * Purchase listed NFT in "Buy Now" price
+     * Claim the fees accrued for a team treasury from the fee vault
      *
-     * Params:  global pda bump
-     *          nft sell data pda bump
-     *          seller user pda bump
-     *          buyer user pda bump
-     *          by_token is true when purchasing with abb tokens
+     * Anyone can crank the claim, the fees are always paid to the treasury
+     *
+     * Params: by_token - claim abb fees
      */
-    pub fn purchase<'info>(
-        ctx: Context<'_, '_, '_, 'info, PurchaseNft<'info>>,
-        global_bump: u8,
-        _nft_bump: u8,
-        _buyer_bump: u8,
-        _seller_bump: u8,
-        by_token: u8,
-    ) -> Result<()> {
+    pub fn claim_treasury_fees(ctx: Context<ClaimTreasuryFees>, by_token: u8) -> Result<()> {
         // By Token should be zero or one
         require!(by_token < 2, MarketplaceError::InvalidParamInput);
-        let sell_data_info = &mut ctx.accounts.sell_data_info;
-        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
-        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+        let treasury = ctx.accounts.treasury.key();
+        msg!("Treasury: {:?}, By Token: {}", treasury, by_token == 1);
+
+        let treasury_config = &mut ctx.accounts.treasury_config;
+        let index = treasury_config
+            .position(&treasury)
+            .ok_or_else(|| error!(MarketplaceError::TreasuryAddressNotFound))?;
+
+        let seeds = &[
+            FEE_VAULT_SEED.as_bytes(),
+            &[ctx.accounts.global_authority.fee_vault_bump],
+        ];
+        let signer = &[&seeds[..]];
 
-        msg!(
-            "Purchase Mint: {:?}, By Token: {}",
-            sell_data_info.mint,
-            by_token == 1
-        );
+        if by_token == 0 {
+            let amount = treasury_config.treasuries[index].claimable_sol;
+            require!(amount > 0, MarketplaceError::NonZeroError);
+            treasury_config.treasuries[index].claimable_sol = 0;
 
-        // Assert NFT Pubkey with Sell Data PDA Mint
-        require!(
-            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
-        );
-        require_eq!(sell_data_info.active, 1, MarketplaceError::NotListedNFT);
-        // Assert Seller Sell Data Address
-        require!(
-            ctx.accounts.seller.key().eq(&sell_data_info.seller),
-            MarketplaceError::SellerAccountMismatch
-        );
-        // Assert Seller User PDA Address
-        require!(
-            ctx.accounts.seller.key().eq(&seller_user_pool.address),
-            MarketplaceError::InvalidOwner
-        );
-        // Assert Buyer User PDA Address
-        require!(
-            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
-            MarketplaceError::InvalidOwner
-        );
-
-        sell_data_info.active = 0;
-
-        let nft_token_account_info = &mut &ctx.accounts.user_nft_token_account;
-        let dest_nft_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let buyer_token_account_info = &mut &ctx.accounts.user_token_account;
-        let seller_token_account_info = &mut &ctx.accounts.seller_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
-        let signer = &[&seeds[..]];
-
-        let global_authority = &mut ctx.accounts.global_authority;
-        let remaining_accounts: Vec<AccountInfo> = ctx.remaining_accounts.to_vec();
-        // At least one treasury should exist to trade NFT
-        require!(
-            global_authority.team_count > 0,
-            MarketplaceError::NoTeamTreasuryYet
-        );
-        require_eq!(
-            global_authority.team_count,
-            remaining_accounts.len() as u64,
-            MarketplaceError::TeamTreasuryCountMismatch
-        );
-
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(global_authority.key(), expected_address);
-        require_eq!(expected_bump, global_bump, MarketplaceError::InvalidBump);
-        // Validate PDA bump and seed
-        let (expected_selldata_address, expected_selldata_bump) = Pubkey::find_program_address(
-            &[
-                SELL_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(sell_data_info.key(), expected_selldata_address);
-        require_eq!(
-            expected_selldata_bump,
-            _nft_bump,
-            MarketplaceError::InvalidBump
-        );
-        // Validate PDA bump and seed
-        let (expected_buyer_address, expected_buyer_bump) = Pubkey::find_program_address(
-            &[USER_DATA_SEED.as_bytes(), ctx.accounts.buyer.key().as_ref()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(buyer_user_pool.key(), expected_buyer_address);
-        require_eq!(
-            expected_buyer_bump,
-            _buyer_bump,
-            MarketplaceError::InvalidBump
-        );
-        // Validate PDA bump and seed
-        let (expected_seller_address, expected_seller_bump) = Pubkey::find_program_address(
-            &[
-                USER_DATA_SEED.as_bytes(),
-                ctx.accounts.seller.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(seller_user_pool.key(), expected_seller_address);
-        require_eq!(
-            expected_seller_bump,
-            _seller_bump,
-            MarketplaceError::InvalidBump
-        );
-
-        if by_token == 0 {
-            let fee_amount: u64 = sell_data_info
-                .price_sol
-                .checked_mul(global_authority.market_fee_sol)
-                .unwrap_or_else(|| panic!("purchase: overflow in fee_amount calculation"))
-                / PERMYRIAD;
-
-            invoke(
-                &system_instruction::transfer(
-                    ctx.accounts.buyer.key,
-                    ctx.accounts.seller.key,
-                    sell_data_info
-                        .price_sol
-                        .checked_sub(fee_amount)
-                        .unwrap_or_else(|| {
-                            panic!(
-                                "purchase: undererflow in seller sol transfer amount calculation"
-                            )
-                        }),
-                ),
+            invoke_signed(
+                &system_instruction::transfer(ctx.accounts.fee_vault.key, &treasury, amount),
                 &[
-                    ctx.accounts.buyer.to_account_info().clone(),
-                    ctx.accounts.seller.to_account_info().clone(),
+                    ctx.accounts.fee_vault.to_account_info().clone(),
+                    ctx.accounts.treasury.to_account_info().clone(),
                     ctx.accounts.system_program.to_account_info().clone(),
                 ],
+                signer,
             )?;
-
-            let mut i = 0;
-            // This is not expensive cuz the max count is 8
-            for team_account in remaining_accounts {
-                require!(
-                    team_account.key().eq(&global_authority.team_treasury[i]),
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-                invoke(
-                    &system_instruction::transfer(
-                        ctx.accounts.buyer.key,
-                        &global_authority.team_treasury[i],
-                        // This won't overflow because the fee_amount always less than MAX::u64 / PERMYRIAD
-                        // and the treasury_rate always less than PERMYRIAD
-                        fee_amount
-                            .checked_mul(global_authority.treasury_rate[i])
-                            .unwrap_or_else(|| {
-                                panic!("purchase: overflow in team sol transfer amount calculation")
-                            })
-                            / PERMYRIAD,
-                    ),
-                    &[
-                        ctx.accounts.buyer.to_account_info().clone(),
-                        team_account.clone(),
-                        ctx.accounts.system_program.to_account_info().clone(),
-                    ],
-                )?;
-                i += 1;
-            }
-            buyer_user_pool.traded_volume = buyer_user_pool
-                .traded_volume
-                .checked_add(sell_data_info.price_sol)
-                .unwrap_or_else(|| panic!("purchase: overflow in buyer traded_volume calculation"));
-            seller_user_pool.traded_volume = seller_user_pool
-                .traded_volume
-                .checked_add(sell_data_info.price_sol)
-                .unwrap_or_else(|| {
-                    panic!("purchase: overflow in seller traded_volume calculation")
-                });
         } else {
-            let fee_amount: u64 = sell_data_info
-                .price_token
-                .checked_mul(global_authority.market_fee_token)
-                .unwrap_or_else(|| panic!("purchase: overflow in fee_amount calculation"))
-                / PERMYRIAD;
-
-            let cpi_accounts = Transfer {
-                from: buyer_token_account_info.to_account_info().clone(),
-                to: seller_token_account_info.to_account_info().clone(),
-                authority: ctx.accounts.buyer.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new(token_program.clone().to_account_info(), cpi_accounts),
-                sell_data_info
-                    .price_token
-                    .checked_sub(fee_amount)
-                    .unwrap_or_else(|| {
-                        panic!("purchase: undererflow in seller token transfer amount calculation")
-                    }),
+            let amount = treasury_config.treasuries[index].claimable_token;
+            require!(amount > 0, MarketplaceError::NonZeroError);
+            treasury_config.treasuries[index].claimable_token = 0;
+
+            // Get ATA of Treasury Account
+            let treasury_ata = payment_ata(&treasury, ctx.accounts.token_program.key);
+            // Assert Provided Token Account is Treasury ATA
+            require_keys_eq!(
+                ctx.accounts.treasury_token_account.key(),
+                treasury_ata,
+                MarketplaceError::TeamTreasuryAddressMismatch
+            );
+            // Assert Treasury ATA is Initialized
+            require_keys_eq!(
+                *ctx.accounts.treasury_token_account.owner,
+                *ctx.accounts.token_program.key,
+                MarketplaceError::TeamTreasuryAddressMismatch
+            );
+
+            transfer_checked(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.fee_vault_token_account.to_account_info(),
+                &ctx.accounts.payment_mint,
+                &ctx.accounts.treasury_token_account.to_account_info(),
+                &ctx.accounts.fee_vault.to_account_info(),
+                amount,
+                signer,
             )?;
+        }
 
-            let mut i = 0;
-            // This is not expensive cuz the max count is 8
-            // remaining_accounts should be tokenAccount for token purchasing
-            for team_token_account in remaining_accounts {
-                // Get ATA of Treasury Account
-                let team_ata = spl_associated_token_account::get_associated_token_address(
-                    &global_authority.team_treasury[i],
-                    &REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-                );
-                // Assert Provied Remaining Account is Treasury ATA
-                require!(
-                    team_token_account.key().eq(&team_ata),
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-                // Assert Treasury ATA is Initialized
+        Ok(())
+    }
 
-                require_eq!(
-                    team_token_account.owner,
-                    &token::ID,
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
+    /**
+     * Set the marketplace fee of a partnered collection as super_admin
+     *
+     * Collection Fee PDA is created for the first override of the collection
+     * The override is used instead of the global fee in all settlements
+     *
+     * Params:  sol_fee - marketplace fee as Permyraid for SOL NFT purchasing
+     *          token_fee - marketplace fee as Permyraid for ABB NFT purchasing
+     */
+    pub fn set_collection_fee(
+        ctx: Context<SetCollectionFee>,
+        sol_fee: u64,
+        token_fee: u64,
+    ) -> Result<()> {
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        require!(
+            sol_fee <= MAX_MARKET_FEE && token_fee <= MAX_MARKET_FEE,
+            MarketplaceError::InvalidFeePercent
+        );
+        // The default key stands for NFTs without resolvable collection paying the global fee
+        require_keys_neq!(
+            ctx.accounts.collection.key(),
+            Pubkey::default(),
+            MarketplaceError::InvalidParamInput
+        );
 
-                let cpi_accounts = Transfer {
-                    from: buyer_token_account_info.to_account_info().clone(),
-                    to: team_token_account.clone(),
-                    authority: ctx.accounts.buyer.to_account_info(),
-                };
-                token::transfer(
-                    CpiContext::new(token_program.clone().to_account_info(), cpi_accounts),
-                    // This operation won't overflow because the fee_amount always less than MAX::u64 / PERMYRIAD
-                    fee_amount * global_authority.treasury_rate[i] / PERMYRIAD,
-                )?;
+        let global_authority = &ctx.accounts.global_authority;
+        let collection_fee = &mut ctx.accounts.collection_fee;
+        let timestamp = Clock::get()?.unix_timestamp;
+        // Settle the fee in effect which is the global fee for a new or removed override
+        let (current_sol, current_token) = if collection_fee.collection.eq(&Pubkey::default()) {
+            (
+                global_authority.market_fee(false, timestamp),
+                global_authority.market_fee(true, timestamp),
+            )
+        } else {
+            (
+                collection_fee
+                    .market_fee(false, timestamp)
+                    .unwrap_or_else(|| global_authority.market_fee(false, timestamp)),
+                collection_fee
+                    .market_fee(true, timestamp)
+                    .unwrap_or_else(|| global_authority.market_fee(true, timestamp)),
+            )
+        };
+        collection_fee.collection = ctx.accounts.collection.key();
+        collection_fee.bump = *ctx.bumps.get("collection_fee").unwrap();
+        collection_fee.market_fee_sol = current_sol;
+        collection_fee.market_fee_token = current_token;
+        collection_fee.pending_fee_sol = 0;
+        collection_fee.pending_fee_token = 0;
+        collection_fee.fee_activation_date = 0;
+        collection_fee.removal_date = 0;
+
+        // Fee decreases apply immediately and increases after the fee timelock
+        if sol_fee <= current_sol {
+            collection_fee.market_fee_sol = sol_fee;
+        } else {
+            collection_fee.pending_fee_sol = sol_fee;
+        }
+        if token_fee <= current_token {
+            collection_fee.market_fee_token = token_fee;
+        } else {
+            collection_fee.pending_fee_token = token_fee;
+        }
+        if collection_fee.pending_fee_sol > 0 || collection_fee.pending_fee_token > 0 {
+            collection_fee.fee_activation_date = timestamp
+                .checked_add(FEE_TIMELOCK)
+                .ok_or_else(|| error!(MarketplaceError::MathOverflow))?;
+        }
+        msg!(
+            "Collection: {:?}, Sol Fee: {}, Token Fee: {}, Pending Sol Fee: {}, Pending Token Fee: {}, Activation Date: {}",
+            collection_fee.collection,
+            collection_fee.market_fee_sol,
+            collection_fee.market_fee_token,
+            collection_fee.pending_fee_sol,
+            collection_fee.pending_fee_token,
+            collection_fee.fee_activation_date
+        );
+        Ok(())
+    }
+
+    /**
+     * Remove the fee override of a collection as super_admin
+     *
+     * The collection pays the global fee again
+     * Removing an override below the global fee is scheduled after the fee timelock
+     * and the Collection Fee PDA is closed by calling again once the removal is activated
+     */
+    pub fn remove_collection_fee(ctx: Context<RemoveCollectionFee>) -> Result<()> {
+        let global_authority = &ctx.accounts.global_authority;
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        let collection_fee = &mut ctx.accounts.collection_fee;
+        msg!("Collection: {:?}", collection_fee.collection);
 
-                i += 1;
+        let timestamp = Clock::get()?.unix_timestamp;
+        let raises_fee = [false, true].iter().any(|&by_token| {
+            matches!(
+                collection_fee.market_fee(by_token, timestamp),
+                Some(fee) if fee < global_authority.market_fee(by_token, timestamp)
+            )
+        });
+        if raises_fee {
+            // Keep the earlier schedule of a repeated removal
+            if collection_fee.removal_date == 0 {
+                collection_fee.removal_date = timestamp
+                    .checked_add(FEE_TIMELOCK)
+                    .ok_or_else(|| error!(MarketplaceError::MathOverflow))?;
             }
-            buyer_user_pool.traded_token_volume = buyer_user_pool
-                .traded_token_volume
-                .checked_add(sell_data_info.price_token)
-                .unwrap_or_else(|| {
-                    panic!("purchase: overflow in buyer traded_token_volume calculation")
-                });
-            seller_user_pool.traded_token_volume = seller_user_pool
-                .traded_token_volume
-                .checked_add(sell_data_info.price_token)
-                .unwrap_or_else(|| {
-                    panic!("purchase: overflow in seller traded_token_volume calculation")
-                });
+            msg!("Removal Date: {}", collection_fee.removal_date);
+            return Ok(());
         }
 
-        let cpi_accounts = Transfer {
-            from: dest_nft_token_account_info.to_account_info().clone(),
-            to: nft_token_account_info.to_account_info().clone(),
-            authority: ctx.accounts.global_authority.to_account_info(),
-        };
-        token::transfer(
-            CpiContext::new_with_signer(
-                token_program.clone().to_account_info(),
-                cpi_accounts,
-                signer,
-            ),
-            1,
-        )?;
+        collection_fee.close(ctx.accounts.admin.to_account_info())
+    }
 
-        invoke_signed(
-            &spl_token::instruction::close_account(
-                token_program.key,
-                &dest_nft_token_account_info.key(),
-                ctx.accounts.buyer.key,
-                &ctx.accounts.global_authority.key(),
-                &[],
-            )?,
-            &[
-                token_program.clone().to_account_info(),
-                dest_nft_token_account_info.to_account_info().clone(),
-                ctx.accounts.buyer.to_account_info().clone(),
-                ctx.accounts.global_authority.to_account_info().clone(),
-            ],
-            signer,
-        )?;
+    /**
+     * Register a verified collection in the collection registry as super_admin
+     *
+     * Collection Info PDA is created for the first registration of the collection
+     *
+     * Params:  name - collection name for display
+     *          symbol - collection symbol for display
+     */
+    pub fn register_collection(
+        ctx: Context<RegisterCollection>,
+        name: [u8; 32],
+        symbol: [u8; 10],
+    ) -> Result<()> {
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
 
+        let collection_info = &mut ctx.accounts.collection_info;
+        msg!("Collection: {:?}", ctx.accounts.collection.key());
+        collection_info.collection = ctx.accounts.collection.key();
+        collection_info.name = name;
+        collection_info.symbol = symbol;
+        collection_info.bump = *ctx.bumps.get("collection_info").unwrap();
         Ok(())
     }
 
     /**
-     * Deposit funds to escrow balance
+     * Remove a collection from the collection registry as super_admin
      *
-     * Params:  user pda bump
-     *          escrow vault bump
-     *          sol is depositing amount
-     *          token is depositing abb amount
+     * Live listings and auctions of the collection are not affected
      */
-    pub fn deposit_to_escrow(
-        ctx: Context<Deposit>,
-        _user_bump: u8,
-        _escrow_bump: u8,
-        sol: u64,
-        token: u64,
-    ) -> Result<()> {
-        require!(sol > 0 || token > 0, MarketplaceError::InvalidParamInput);
+    pub fn unregister_collection(ctx: Context<UnregisterCollection>) -> Result<()> {
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        msg!("Collection: {:?}", ctx.accounts.collection_info.collection);
+        Ok(())
+    }
 
-        let user_pool = &mut ctx.accounts.user_pool;
+    /**
+     * Add a stolen NFT mint or a sanctioned wallet to the denylist as super_admin
+     *
+     * Denylisted mints and wallets can't list, offer, bid or settle trades
+     * Auctions live before the denylisting of a trader can still be claimed
+     *
+     * Params:  kind - 0: mint, 1: wallet
+     *          reason - reason code of the entry
+     */
+    pub fn add_deny_entry(ctx: Context<AddDenyEntry>, kind: u64, reason: u64) -> Result<()> {
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        // Kind should be zero or one
+        require!(kind < 2, MarketplaceError::InvalidParamInput);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let deny_entry = &mut ctx.accounts.deny_entry;
         msg!(
-            "User: {:?}, Sol Deposit: {}, Token Deposit: {}",
-            user_pool.address,
-            sol,
-            token
+            "Denied: {:?}, Kind: {}, Reason: {}",
+            ctx.accounts.address.key(),
+            kind,
+            reason
         );
+        deny_entry.address = ctx.accounts.address.key();
+        deny_entry.kind = kind;
+        deny_entry.reason = reason;
+        deny_entry.denied_date = timestamp;
+        deny_entry.bump = *ctx.bumps.get("deny_entry").unwrap();
+        Ok(())
+    }
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[USER_DATA_SEED.as_bytes(), ctx.accounts.owner.key().as_ref()],
-            &astro_marketplace::ID,
+    /**
+     * Remove a mint or a wallet from the denylist as super_admin
+     */
+    pub fn remove_deny_entry(ctx: Context<RemoveDenyEntry>) -> Result<()> {
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
         );
-        require_keys_eq!(user_pool.key(), expected_address);
-        require_eq!(expected_bump, _user_bump, MarketplaceError::InvalidBump);
-        // Validate PDA bump and seed
-        let (expected_escrow_address, expected_escrow_bump) =
-            Pubkey::find_program_address(&[ESCROW_VAULT_SEED.as_bytes()], &astro_marketplace::ID);
-        require_keys_eq!(ctx.accounts.escrow_vault.key(), expected_escrow_address);
-        require_eq!(
-            expected_escrow_bump,
-            _escrow_bump,
-            MarketplaceError::InvalidBump
+        msg!("Allowed: {:?}", ctx.accounts.deny_entry.address);
+        Ok(())
+    }
+
+    /**
+     * Approve or revoke a referrer as super_admin
+     *
+     * Only approved referrers earn the referral share of the fee
+     *
+     * Params:  approved - 0: revoked, 1: approved
+     */
+    pub fn approve_referral(ctx: Context<ApproveReferral>, approved: u64) -> Result<()> {
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            ctx.accounts.global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        // Approved should be zero or one
+        require!(approved < 2, MarketplaceError::InvalidParamInput);
+        let referral_data = &mut ctx.accounts.referral_data;
+        msg!(
+            "Referrer: {:?}, Approved: {}",
+            referral_data.referrer,
+            approved
         );
+        referral_data.approved = approved;
+        Ok(())
+    }
 
-        // Assert User Pubkey with User Data PDA Address
-        require!(
-            ctx.accounts.owner.key().eq(&user_pool.address),
-            MarketplaceError::InvalidOwner
+    /**
+     * Initialize User PDA for Escrow & Traded Volume
+     */
+    pub fn init_user_pool(ctx: Context<InitUserPool>) -> Result<()> {
+        let user_pool = &mut ctx.accounts.user_pool;
+        user_pool.address = ctx.accounts.owner.key();
+        user_pool.bump = *ctx.bumps.get("user_pool").unwrap();
+        Ok(())
+    }
+
+    /**
+     * Initialize Referral PDA to earn the referral share of the marketplace fee
+     */
+    pub fn init_referral(ctx: Context<InitReferral>) -> Result<()> {
+        let referral_data = &mut ctx.accounts.referral_data;
+        referral_data.referrer = ctx.accounts.referrer.key();
+        referral_data.bump = *ctx.bumps.get("referral_data").unwrap();
+        Ok(())
+    }
+
+    /**
+     * Claim the referral fees accrued in the fee vault
+     *
+     * Params: by_token - claim abb fees
+     */
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>, by_token: u8) -> Result<()> {
+        // By Token should be zero or one
+        require!(by_token < 2, MarketplaceError::InvalidParamInput);
+        let referral_data = &mut ctx.accounts.referral_data;
+        msg!(
+            "Referrer: {:?}, By Token: {}",
+            referral_data.referrer,
+            by_token == 1
         );
 
-        let user_token_account_info = &mut &ctx.accounts.user_token_account;
-        let vault_token_account_info = &mut &ctx.accounts.escrow_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
+        let seeds = &[
+            FEE_VAULT_SEED.as_bytes(),
+            &[ctx.accounts.global_authority.fee_vault_bump],
+        ];
+        let signer = &[&seeds[..]];
 
-        if sol > 0 {
-            invoke(
+        if by_token == 0 {
+            let amount = referral_data.claimable_sol;
+            require!(amount > 0, MarketplaceError::NonZeroError);
+            referral_data.claimable_sol = 0;
+
+            invoke_signed(
                 &system_instruction::transfer(
-                    ctx.accounts.owner.key,
-                    ctx.accounts.escrow_vault.key,
-                    sol,
+                    ctx.accounts.fee_vault.key,
+                    ctx.accounts.referrer.key,
+                    amount,
                 ),
                 &[
-                    ctx.accounts.owner.to_account_info().clone(),
-                    ctx.accounts.escrow_vault.to_account_info().clone(),
+                    ctx.accounts.fee_vault.to_account_info().clone(),
+                    ctx.accounts.referrer.to_account_info().clone(),
                     ctx.accounts.system_program.to_account_info().clone(),
                 ],
+                signer,
             )?;
-            user_pool.escrow_sol_balance = user_pool
-                .escrow_sol_balance
-                .checked_add(sol)
-                .unwrap_or_else(|| panic!("deposit: overflow in escrow_sol_balance calculation"));
-        }
-
-        if token > 0 {
-            let cpi_accounts = Transfer {
-                from: user_token_account_info.to_account_info().clone(),
-                to: vault_token_account_info.to_account_info().clone(),
-                authority: ctx.accounts.owner.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new(token_program.clone().to_account_info(), cpi_accounts),
-                token,
+        } else {
+            let amount = referral_data.claimable_token;
+            require!(amount > 0, MarketplaceError::NonZeroError);
+            referral_data.claimable_token = 0;
+
+            transfer_checked(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.fee_vault_token_account.to_account_info(),
+                &ctx.accounts.payment_mint,
+                &ctx.accounts.referrer_token_account.to_account_info(),
+                &ctx.accounts.fee_vault.to_account_info(),
+                amount,
+                signer,
             )?;
-            user_pool.escrow_token_balance = user_pool
-                .escrow_token_balance
-                .checked_add(token)
-                .unwrap_or_else(|| panic!("deposit: overflow in escrow_token_balance calculation"));
         }
 
         Ok(())
     }
 
     /**
-     * Withdraw funds from escrow balance
+     * List NFT for sale
      *
-     * Params:  user pda bump
-     *          escrow vault bump
-     *          sol - withdrawing amount
-     *          token - withdrawing abb amount
+     * Sell Data PDA is keyed by the NFT mint and the seller and created for the first listing of the seller
+     * Sellers of the same semi-fungible token list their units in separate listings
+     * Semi-fungible tokens are listed by quantity and sold by partial fills
+     *
+     * Params:  price_sol is the selling unit price in sol
+     *          price_token is the selling unit price in abb
+     *          fee_mode - marketplace fee payer, 0: global default, 1: seller, 2: buyer
+     *          quantity - listed units, 1 for NFTs
+     *          reserved_buyer - the only buyer of a private listing, Pubkey::default() for public listings
      */
-    pub fn withdraw_from_escrow(
-        ctx: Context<Withdraw>,
-        _user_bump: u8,
-        escrow_bump: u8,
-        sol: u64,
-        token: u64,
+    pub fn list_nft_for_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListNftForSale<'info>>,
+        price_sol: u64,
+        price_token: u64,
+        fee_mode: u8,
+        quantity: u64,
+        reserved_buyer: Pubkey,
     ) -> Result<()> {
-        require!(sol > 0 || token > 0, MarketplaceError::InvalidParamInput);
+        // Assert the NFT and the trader is not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.nft_mint.key(), &ctx.accounts.mint_deny_entry),
+            (ctx.accounts.owner.key(), &ctx.accounts.owner_deny_entry),
+        ])?;
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        msg!("Mint: {:?}", ctx.accounts.nft_mint.key());
 
-        let user_pool = &mut ctx.accounts.user_pool;
-        msg!(
-            "User: {:?}, Sol Withdraw: {}, Token Withdraw: {}",
-            user_pool.address,
-            sol,
-            token
+        require!(
+            price_sol > 0 && price_token > 0 && quantity > 0,
+            MarketplaceError::NonZeroError
+        );
+        // Assert the seller holds the listed units
+        require!(
+            token_amount(&ctx.accounts.user_token_account)? >= quantity,
+            MarketplaceError::InvalidNftTokenAccount
         );
 
-        // Validate PDA bump and seed
-        let (expected_escrow_address, expected_escrow_bump) =
-            Pubkey::find_program_address(&[ESCROW_VAULT_SEED.as_bytes()], &astro_marketplace::ID);
-        require_keys_eq!(ctx.accounts.escrow_vault.key(), expected_escrow_address);
-        require_eq!(
-            expected_escrow_bump,
-            escrow_bump,
-            MarketplaceError::InvalidBump
+        // Initialize newly created Sell Data PDA
+        if sell_data_info.mint.eq(&Pubkey::default()) {
+            sell_data_info.mint = ctx.accounts.nft_mint.key();
+            sell_data_info.payer = ctx.accounts.owner.key();
+            sell_data_info.bump = *ctx.bumps.get("sell_data_info").unwrap();
+        }
+        // Assert NFT Pubkey with Sell Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert Already Listed NFT
+        require_eq!(sell_data_info.active, 0, MarketplaceError::AlreadyListedNFT);
+
+        // Get Collection address from Metadata
+        let nft_metadata =
+            load_metadata(&ctx.accounts.nft_mint.key(), &ctx.accounts.mint_metadata)?;
+
+        let (collection, collection_method) = resolve_collection(
+            &nft_metadata,
+            &ctx.accounts.mint_metadata,
+            ctx.accounts.global_authority.creator_fallback,
+        )?;
+        if ctx.accounts.global_authority.restricted_listing == 1 {
+            assert_registered_collection(
+                &collection,
+                collection_method,
+                &ctx.accounts.collection_info,
+            )?;
+        }
+        sell_data_info.collection = collection;
+        sell_data_info.collection_method = collection_method;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Listed Date: {}", timestamp);
+
+        sell_data_info.seller = ctx.accounts.owner.key();
+        sell_data_info.price_sol = price_sol;
+        sell_data_info.price_token = price_token;
+        sell_data_info.listed_date = timestamp;
+        sell_data_info.active = 1;
+        sell_data_info.buyer_pays_fee =
+            resolve_buyer_pays_fee(&ctx.accounts.global_authority, fee_mode)?;
+        sell_data_info.quantity = quantity;
+        sell_data_info.reserved_buyer = reserved_buyer;
+
+        // Assert the NFT has no Token-2022 transfer fee
+        assert_no_transfer_fee(&ctx.accounts.nft_mint)?;
+        let pnft = PnftAccounts::load(ctx.remaining_accounts)?;
+        // pNFTs are single units
+        require!(
+            pnft.is_none() || quantity == 1,
+            MarketplaceError::InvalidParamInput
+        );
+        NftTransfer {
+            token: ctx.accounts.user_token_account.to_account_info(),
+            token_owner: ctx.accounts.owner.to_account_info(),
+            destination: ctx.accounts.dest_nft_token_account.to_account_info(),
+            destination_owner: ctx.accounts.global_authority.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            amount: quantity,
+            payer: ctx.accounts.owner.to_account_info(),
+            token_program: ctx.accounts.nft_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        }
+        .execute(pnft.as_ref(), &[])?;
+
+        Ok(())
+    }
+
+    /**
+     * Cancel NFT lising
+     */
+    pub fn delist_nft<'info>(ctx: Context<'_, '_, '_, 'info, DelistNft<'info>>) -> Result<()> {
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        msg!("Mint: {:?}", sell_data_info.mint);
+
+        // Assert NFT Pubkey with Sell Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert NFT seller is payer
+        require!(
+            ctx.accounts.owner.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerMismatch
+        );
+        // Assert Already Delisted NFT
+        require_eq!(sell_data_info.active, 1, MarketplaceError::NotListedNFT);
+
+        // Return the units left in the listing
+        let quantity = sell_data_info.quantity;
+        sell_data_info.active = 0;
+        sell_data_info.quantity = 0;
+
+        let pnft = PnftAccounts::load(ctx.remaining_accounts)?;
+        release_nft(
+            &ctx.accounts.global_authority,
+            NftTransfer {
+                token: ctx.accounts.dest_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.global_authority.to_account_info(),
+                destination: ctx.accounts.user_token_account.to_account_info(),
+                destination_owner: ctx.accounts.owner.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                amount: quantity,
+                payer: ctx.accounts.owner.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            ctx.accounts.owner.to_account_info(),
+            pnft.as_ref(),
+        )?;
+
+        Ok(())
+    }
+
+    /**
+     * Purchase listed NFT in "Buy Now" price
+     *
+     * The listing stays active until all listed units are sold
+     *
+     * Params: by_token is true when purchasing with abb tokens
+     *         quantity - purchased units, 1 for NFTs
+     */
+    pub fn purchase<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseNft<'info>>,
+        by_token: u8,
+        quantity: u64,
+    ) -> Result<()> {
+        // Assert the NFT and the traders are not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.nft_mint.key(), &ctx.accounts.mint_deny_entry),
+            (ctx.accounts.buyer.key(), &ctx.accounts.buyer_deny_entry),
+            (ctx.accounts.seller.key(), &ctx.accounts.seller_deny_entry),
+        ])?;
+        // By Token should be zero or one
+        require!(by_token < 2, MarketplaceError::InvalidParamInput);
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+
+        msg!(
+            "Purchase Mint: {:?}, Quantity: {}, By Token: {}",
+            sell_data_info.mint,
+            quantity,
+            by_token == 1
+        );
+
+        // Assert NFT Pubkey with Sell Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        require_eq!(sell_data_info.active, 1, MarketplaceError::NotListedNFT);
+        // Assert Buyer is the reserved buyer of a private listing
+        assert_reserved_buyer(&sell_data_info.reserved_buyer, &ctx.accounts.buyer.key())?;
+        // Assert Seller Sell Data Address
+        require!(
+            ctx.accounts.seller.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerAccountMismatch
+        );
+        // Assert Seller User PDA Address
+        require!(
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Buyer User PDA Address
+        require!(
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert the purchased units are left in the listing
+        require!(
+            quantity > 0 && quantity <= sell_data_info.quantity,
+            MarketplaceError::InvalidParamInput
         );
-        // Validate PDA bump and seed
-        let (expected_user_address, expected_user_bump) = Pubkey::find_program_address(
-            &[USER_DATA_SEED.as_bytes(), ctx.accounts.owner.key().as_ref()],
-            &astro_marketplace::ID,
+
+        sell_data_info.quantity = sell_data_info.quantity.safe_sub(quantity)?;
+        if sell_data_info.quantity == 0 {
+            sell_data_info.active = 0;
+        }
+
+        let global_authority = &ctx.accounts.global_authority;
+        let unit_price = if by_token == 1 {
+            sell_data_info.price_token
+        } else {
+            sell_data_info.price_sol
+        };
+        let price = unit_price.safe_mul(quantity)?;
+        let fee_rate = market_fee_rate(
+            global_authority,
+            &sell_data_info.collection,
+            &ctx.accounts.collection_fee,
+            buyer_user_pool,
+            seller_user_pool,
+            by_token == 1,
+        )?;
+        let nft_metadata =
+            load_metadata(&ctx.accounts.nft_mint.key(), &ctx.accounts.mint_metadata)?;
+        let mut referral = load_referral(
+            &ctx.accounts.referral_data,
+            &ctx.accounts.buyer.key(),
+            &ctx.accounts.seller.key(),
+        )?;
+        let payout = Payout::compute(
+            price,
+            fee_rate,
+            global_authority,
+            &ctx.accounts.treasury_config,
+            &Royalty::from_metadata(&nft_metadata),
+            referral.is_some(),
+            sell_data_info.buyer_pays_fee == 1,
+            (by_token == 1).then_some(&ctx.accounts.payment_mint),
+        )?;
+
+        // Remaining accounts are the royalty creators followed by the pNFT transfer accounts
+        let creator_count = payout.royalties.len().min(ctx.remaining_accounts.len());
+        let (creators, pnft_accounts) = ctx.remaining_accounts.split_at(creator_count);
+        let pnft = PnftAccounts::load(pnft_accounts)?;
+
+        settlement!(
+            ctx.accounts,
+            by_token == 1,
+            buyer,
+            user_token_account,
+            seller,
+            seller_token_account,
+            &[]
+        )
+        .execute(
+            &payout,
+            &mut ctx.accounts.treasury_config,
+            referral.as_mut(),
+            creators,
+        )?;
+
+        record_volume(buyer_user_pool, seller_user_pool, by_token == 1, price)?;
+
+        release_nft(
+            &ctx.accounts.global_authority,
+            NftTransfer {
+                token: ctx.accounts.dest_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.global_authority.to_account_info(),
+                destination: ctx.accounts.user_nft_token_account.to_account_info(),
+                destination_owner: ctx.accounts.buyer.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                amount: quantity,
+                payer: ctx.accounts.buyer.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            ctx.accounts.buyer.to_account_info(),
+            pnft.as_ref(),
+        )?;
+
+        Ok(())
+    }
+
+    /**
+     * Close delisted or sold NFT Sell Data PDA to reclaim rent
+     *
+     * The rent is returned to the payer of the Sell Data PDA
+     */
+    pub fn close_sell_data(ctx: Context<CloseSellData>) -> Result<()> {
+        let sell_data_info = &ctx.accounts.sell_data_info;
+        msg!("Mint: {:?}", sell_data_info.mint);
+
+        // Assert Payer is the Sell Data Rent Payer
+        require_keys_eq!(
+            ctx.accounts.payer.key(),
+            sell_data_info.payer,
+            MarketplaceError::RentPayerMismatch
         );
-        require_keys_eq!(user_pool.key(), expected_user_address);
+        // Assert the NFT is not escrowed by the listing
         require_eq!(
-            expected_user_bump,
-            _user_bump,
-            MarketplaceError::InvalidBump
+            sell_data_info.active,
+            0,
+            MarketplaceError::ActiveDataAccount
+        );
+
+        Ok(())
+    }
+
+    /**
+     * Deposit funds to escrow balance
+     *
+     * The abb deposit is credited after the Token-2022 transfer fee of the abb mint
+     *
+     * Params:  sol is depositing amount
+     *          token is depositing abb amount
+     */
+    pub fn deposit_to_escrow(ctx: Context<Deposit>, sol: u64, token: u64) -> Result<()> {
+        require!(sol > 0 || token > 0, MarketplaceError::InvalidParamInput);
+
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "User: {:?}, Sol Deposit: {}, Token Deposit: {}",
+            user_pool.address,
+            sol,
+            token
         );
 
         // Assert User Pubkey with User Data PDA Address
@@ -879,14 +1234,12 @@ pub mod astro_marketplace {
         let user_token_account_info = &mut &ctx.accounts.user_token_account;
         let vault_token_account_info = &mut &ctx.accounts.escrow_token_account;
         let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
-        let signer = &[&seeds[..]];
 
         if sol > 0 {
-            invoke_signed(
+            invoke(
                 &system_instruction::transfer(
-                    ctx.accounts.escrow_vault.key,
                     ctx.accounts.owner.key,
+                    ctx.accounts.escrow_vault.key,
                     sol,
                 ),
                 &[
@@ -894,61 +1247,89 @@ pub mod astro_marketplace {
                     ctx.accounts.escrow_vault.to_account_info().clone(),
                     ctx.accounts.system_program.to_account_info().clone(),
                 ],
-                signer,
             )?;
-            user_pool.escrow_sol_balance = user_pool
-                .escrow_sol_balance
-                .checked_sub(sol)
-                .unwrap_or_else(|| panic!("withdraw: underflow in escrow_sol_balance calculation"));
+            user_pool.escrow_sol_balance = user_pool.escrow_sol_balance.safe_add(sol)?;
         }
 
         if token > 0 {
-            let cpi_accounts = Transfer {
-                from: vault_token_account_info.to_account_info().clone(),
-                to: user_token_account_info.to_account_info().clone(),
-                authority: ctx.accounts.escrow_vault.to_account_info().clone(),
-            };
-            token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.clone().to_account_info(),
-                    cpi_accounts,
-                    signer,
-                ),
+            transfer_checked(
+                &token_program.to_account_info(),
+                &user_token_account_info.to_account_info(),
+                &ctx.accounts.payment_mint,
+                &vault_token_account_info.to_account_info(),
+                &ctx.accounts.owner.to_account_info(),
                 token,
+                &[],
             )?;
-            user_pool.escrow_token_balance = user_pool
-                .escrow_token_balance
-                .checked_sub(token)
-                .unwrap_or_else(|| {
-                    panic!("withdraw: underflow in escrow_token_balance calculation")
-                });
+            // Credit the amount received after the Token-2022 transfer fee
+            let received = token.safe_sub(transfer_fee(&ctx.accounts.payment_mint, token)?)?;
+            user_pool.escrow_token_balance = user_pool.escrow_token_balance.safe_add(received)?;
         }
 
         Ok(())
     }
 
     /**
-     * Init offer data pda
+     * Withdraw funds from escrow balance
      *
-     * Params:  nft - NFT address for offering
-     *          offer data pda bump
+     * Params:  sol - withdrawing amount
+     *          token - withdrawing abb amount
      */
-    pub fn init_offer_data(ctx: Context<InitOfferData>, nft: Pubkey, _bump: u8) -> Result<()> {
-        let offer_data_info = &mut ctx.accounts.offer_data_info;
-        offer_data_info.mint = nft;
-        offer_data_info.buyer = ctx.accounts.payer.key();
+    pub fn withdraw_from_escrow(ctx: Context<Withdraw>, sol: u64, token: u64) -> Result<()> {
+        require!(sol > 0 || token > 0, MarketplaceError::InvalidParamInput);
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[
-                OFFER_DATA_SEED.as_bytes(),
-                nft.as_ref(),
-                ctx.accounts.payer.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "User: {:?}, Sol Withdraw: {}, Token Withdraw: {}",
+            user_pool.address,
+            sol,
+            token
+        );
+
+        // Assert User Pubkey with User Data PDA Address
+        require!(
+            ctx.accounts.owner.key().eq(&user_pool.address),
+            MarketplaceError::InvalidOwner
         );
-        require_keys_eq!(offer_data_info.key(), expected_address);
-        require_eq!(expected_bump, _bump, MarketplaceError::InvalidBump);
+
+        let user_token_account_info = &mut &ctx.accounts.user_token_account;
+        let vault_token_account_info = &mut &ctx.accounts.escrow_token_account;
+        let token_program = &mut &ctx.accounts.token_program;
+        let seeds = &[
+            ESCROW_VAULT_SEED.as_bytes(),
+            &[ctx.accounts.global_authority.escrow_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        if sol > 0 {
+            invoke_signed(
+                &system_instruction::transfer(
+                    ctx.accounts.escrow_vault.key,
+                    ctx.accounts.owner.key,
+                    sol,
+                ),
+                &[
+                    ctx.accounts.owner.to_account_info().clone(),
+                    ctx.accounts.escrow_vault.to_account_info().clone(),
+                    ctx.accounts.system_program.to_account_info().clone(),
+                ],
+                signer,
+            )?;
+            user_pool.escrow_sol_balance = user_pool.escrow_sol_balance.safe_sub(sol)?;
+        }
+
+        if token > 0 {
+            transfer_checked(
+                &token_program.to_account_info(),
+                &vault_token_account_info.to_account_info(),
+                &ctx.accounts.payment_mint,
+                &user_token_account_info.to_account_info(),
+                &ctx.accounts.escrow_vault.to_account_info(),
+                token,
+                signer,
+            )?;
+            user_pool.escrow_token_balance = user_pool.escrow_token_balance.safe_sub(token)?;
+        }
 
         Ok(())
     }
@@ -956,22 +1337,17 @@ pub mod astro_marketplace {
     /**
      * Make an offer for a particular NFT
      *
-     * Params:  nft sell data pda bump
-     *          offer data pda bump
-     *          user pda bump
-     *          escrow vault bump
-     *          price - offer price
+     * Offer Data PDA is created for the first offer of the buyer for the listing of the seller
+     *
+     * Params:  price - offer price
      *          by_token - offering by abb
      */
-    pub fn make_offer(
-        ctx: Context<MakeOffer>,
-        _nft_bump: u8,
-        _offer_bump: u8,
-        _user_bump: u8,
-        _escrow_bump: u8,
-        price: u64,
-        by_token: u64,
-    ) -> Result<()> {
+    pub fn make_offer(ctx: Context<MakeOffer>, price: u64, by_token: u64) -> Result<()> {
+        // Assert the NFT and the trader is not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.nft_mint.key(), &ctx.accounts.mint_deny_entry),
+            (ctx.accounts.owner.key(), &ctx.accounts.owner_deny_entry),
+        ])?;
         // By Token Param should be zero or one
         require!(by_token < 2, MarketplaceError::InvalidParamInput);
         let sell_data_info = &mut ctx.accounts.sell_data_info;
@@ -988,6 +1364,14 @@ pub mod astro_marketplace {
         );
 
         let offer_data_info = &mut ctx.accounts.offer_data_info;
+        // Initialize newly created Offer Data PDA
+        if offer_data_info.mint.eq(&Pubkey::default()) {
+            offer_data_info.mint = ctx.accounts.nft_mint.key();
+            offer_data_info.buyer = ctx.accounts.owner.key();
+            offer_data_info.seller = ctx.accounts.seller.key();
+            offer_data_info.payer = ctx.accounts.owner.key();
+            offer_data_info.bump = *ctx.bumps.get("offer_data_info").unwrap();
+        }
         require!(
             ctx.accounts.nft_mint.key().eq(&offer_data_info.mint),
             MarketplaceError::InvalidOfferDataMint
@@ -1003,6 +1387,8 @@ pub mod astro_marketplace {
             1,
             MarketplaceError::OfferForNotListedNFT
         );
+        // Assert Buyer is the reserved buyer of a private listing
+        assert_reserved_buyer(&sell_data_info.reserved_buyer, &ctx.accounts.owner.key())?;
         // Offer price range is from x1 to x0.5
         if by_token == 1 {
             require!(
@@ -1016,16 +1402,30 @@ pub mod astro_marketplace {
             );
         }
 
+        // Escrow the fee at the current rate with the offer when the buyer pays the fee
+        let fee_rate = if sell_data_info.buyer_pays_fee == 1 {
+            base_fee_rate(
+                &ctx.accounts.global_authority,
+                &sell_data_info.collection,
+                &ctx.accounts.collection_fee,
+                by_token == 1,
+            )?
+        } else {
+            0
+        };
+        let deposit = price.safe_add(price.permyriad(fee_rate)?)?;
+
         offer_data_info.offer_listing_date = sell_data_info.listed_date;
         offer_data_info.offer_price = price;
         offer_data_info.by_token = by_token;
         offer_data_info.active = 1;
+        offer_data_info.fee_rate = fee_rate;
 
         let user_pool = &mut ctx.accounts.user_pool;
         msg!(
             "User: {:?}, Deposit: {}, By Token: {}",
             user_pool.address,
-            price,
+            deposit,
             by_token
         );
 
@@ -1039,60 +1439,12 @@ pub mod astro_marketplace {
         let vault_token_account_info = &mut &ctx.accounts.escrow_token_account;
         let token_program = &mut &ctx.accounts.token_program;
 
-        // Validate PDA bump and seed
-        let (expected_nft_address, expected_nft_bump) = Pubkey::find_program_address(
-            &[
-                SELL_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(sell_data_info.key(), expected_nft_address);
-        require_eq!(expected_nft_bump, _nft_bump, MarketplaceError::InvalidBump);
-
-        // Validate PDA bump and seed
-        let (expected_offer_address, expected_offer_bump) = Pubkey::find_program_address(
-            &[
-                OFFER_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-                ctx.accounts.owner.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(offer_data_info.key(), expected_offer_address);
-        require_eq!(
-            expected_offer_bump,
-            _offer_bump,
-            MarketplaceError::InvalidBump
-        );
-        // Validate PDA bump and seed
-        let (expected_user_address, expected_user_bump) = Pubkey::find_program_address(
-            &[USER_DATA_SEED.as_bytes(), ctx.accounts.owner.key().as_ref()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(user_pool.key(), expected_user_address);
-        require_eq!(
-            expected_user_bump,
-            _user_bump,
-            MarketplaceError::InvalidBump
-        );
-
-        // Validate PDA bump and seed
-        let (expected_escrow_address, expected_escrow_bump) =
-            Pubkey::find_program_address(&[ESCROW_VAULT_SEED.as_bytes()], &astro_marketplace::ID);
-        require_keys_eq!(ctx.accounts.escrow_vault.key(), expected_escrow_address);
-        require_eq!(
-            expected_escrow_bump,
-            _escrow_bump,
-            MarketplaceError::InvalidBump
-        );
-
         if by_token == 0 {
             invoke(
                 &system_instruction::transfer(
                     ctx.accounts.owner.key,
                     ctx.accounts.escrow_vault.key,
-                    price,
+                    deposit,
                 ),
                 &[
                     ctx.accounts.owner.to_account_info().clone(),
@@ -1100,40 +1452,29 @@ pub mod astro_marketplace {
                     ctx.accounts.system_program.to_account_info().clone(),
                 ],
             )?;
-            user_pool.escrow_sol_balance = user_pool
-                .escrow_sol_balance
-                .checked_add(price)
-                .unwrap_or_else(|| {
-                    panic!("make_offer: overflow in escrow_sol_balance calculation")
-                });
+            user_pool.escrow_sol_balance = user_pool.escrow_sol_balance.safe_add(deposit)?;
         }
 
         if by_token == 1 {
-            let cpi_accounts = Transfer {
-                from: user_token_account_info.to_account_info().clone(),
-                to: vault_token_account_info.to_account_info().clone(),
-                authority: ctx.accounts.owner.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new(token_program.clone().to_account_info(), cpi_accounts),
-                price,
+            // Deposit with the Token-2022 transfer fee on top
+            transfer_checked(
+                &token_program.to_account_info(),
+                &user_token_account_info.to_account_info(),
+                &ctx.accounts.payment_mint,
+                &vault_token_account_info.to_account_info(),
+                &ctx.accounts.owner.to_account_info(),
+                gross_transfer_amount(&ctx.accounts.payment_mint, deposit)?,
+                &[],
             )?;
-            user_pool.escrow_token_balance = user_pool
-                .escrow_token_balance
-                .checked_add(price)
-                .unwrap_or_else(|| {
-                    panic!("make_offer: overflow in escrow_token_balance calculation")
-                });
+            user_pool.escrow_token_balance = user_pool.escrow_token_balance.safe_add(deposit)?;
         }
         Ok(())
     }
 
     /**
      * Cancel offer
-     *
-     * Params: offer data pda bump
      */
-    pub fn cancel_offer(ctx: Context<CancelOffer>, _offer_bump: u8) -> Result<()> {
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
         let offer_data_info = &mut ctx.accounts.offer_data_info;
         msg!(
             "Mint: {:?}, buyer: {:?}",
@@ -1141,18 +1482,6 @@ pub mod astro_marketplace {
             ctx.accounts.owner.key()
         );
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[
-                OFFER_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-                ctx.accounts.owner.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(offer_data_info.key(), expected_address);
-        require_eq!(expected_bump, _offer_bump, MarketplaceError::InvalidBump);
-
         // Assert NFT Pubkey with Offer Data PDA Mint
         require!(
             ctx.accounts.nft_mint.key().eq(&offer_data_info.mint),
@@ -1173,22 +1502,15 @@ pub mod astro_marketplace {
     /**
      * Sell NFT in offer price
      *
-     * Params:  global pda bump
-     *          nft sell data pda bump
-     *          offer data bump
-     *          buyer user pda bump
-     *          seller user pda bump
-     *          escrow vault bump
+     * Offers buy one unit of the listing at the offer price
      */
-    pub fn accept_offer<'info>(
-        ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>,
-        global_bump: u8,
-        _nft_bump: u8,
-        _offer_bump: u8,
-        _buyer_bump: u8,
-        _seller_bump: u8,
-        escrow_bump: u8,
-    ) -> Result<()> {
+    pub fn accept_offer<'info>(ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>) -> Result<()> {
+        // Assert the NFT and the traders are not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.nft_mint.key(), &ctx.accounts.mint_deny_entry),
+            (ctx.accounts.buyer.key(), &ctx.accounts.buyer_deny_entry),
+            (ctx.accounts.seller.key(), &ctx.accounts.seller_deny_entry),
+        ])?;
         let sell_data_info = &mut ctx.accounts.sell_data_info;
 
         let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
@@ -1230,6 +1552,8 @@ pub mod astro_marketplace {
         );
         // Assert Already Disabled Offer
         require_eq!(offer_data_info.active, 1, MarketplaceError::DisabledOffer);
+        // Assert Buyer is the reserved buyer of a private listing
+        assert_reserved_buyer(&sell_data_info.reserved_buyer, &ctx.accounts.buyer.key())?;
         // Assert Offer provided date with the NFT Listed Date
         require_eq!(
             offer_data_info.offer_listing_date,
@@ -1247,1290 +1571,3583 @@ pub mod astro_marketplace {
         );
 
         offer_data_info.active = 0;
-        sell_data_info.active = 0;
+        sell_data_info.quantity = sell_data_info.quantity.safe_sub(1)?;
+        if sell_data_info.quantity == 0 {
+            sell_data_info.active = 0;
+        }
 
-        if offer_data_info.by_token == 1 {
+        let price = offer_data_info.offer_price;
+        let by_token = offer_data_info.by_token == 1;
+
+        let global_authority = &ctx.accounts.global_authority;
+        let fee_rate = market_fee_rate(
+            global_authority,
+            &sell_data_info.collection,
+            &ctx.accounts.collection_fee,
+            buyer_user_pool,
+            seller_user_pool,
+            by_token,
+        )?;
+        // The buyer paying the fee is never charged more than the fee escrowed with the offer
+        let fee_rate = if sell_data_info.buyer_pays_fee == 1 {
+            fee_rate.min(offer_data_info.fee_rate)
+        } else {
+            fee_rate
+        };
+        let nft_metadata =
+            load_metadata(&ctx.accounts.nft_mint.key(), &ctx.accounts.mint_metadata)?;
+        let mut referral = load_referral(
+            &ctx.accounts.referral_data,
+            &ctx.accounts.buyer.key(),
+            &ctx.accounts.seller.key(),
+        )?;
+        let payout = Payout::compute(
+            price,
+            fee_rate,
+            global_authority,
+            &ctx.accounts.treasury_config,
+            &Royalty::from_metadata(&nft_metadata),
+            referral.is_some(),
+            sell_data_info.buyer_pays_fee == 1,
+            by_token.then_some(&ctx.accounts.payment_mint),
+        )?;
+
+        // Buyer pays the price and the fee on top of it from the escrow balance
+        if by_token {
             require!(
-                offer_data_info.offer_price <= buyer_user_pool.escrow_token_balance,
+                payout.total <= buyer_user_pool.escrow_token_balance,
                 MarketplaceError::InsufficientBuyerTokenBalance
             );
             buyer_user_pool.escrow_token_balance = buyer_user_pool
                 .escrow_token_balance
-                .checked_sub(offer_data_info.offer_price)
-                .unwrap_or_else(|| {
-                    panic!("accept_offer: underflow in buyer escrow_token_balance calculation")
-                });
-            buyer_user_pool.traded_token_volume = buyer_user_pool
-                .traded_token_volume
-                .checked_add(offer_data_info.offer_price)
-                .unwrap_or_else(|| {
-                    panic!("accept_offer: overflow in buyer escrow_token_balance calculation")
-                });
-            seller_user_pool.traded_token_volume = seller_user_pool
-                .traded_token_volume
-                .checked_add(offer_data_info.offer_price)
-                .unwrap_or_else(|| {
-                    panic!("accept_offer: overflow in seller escrow_token_balance calculation")
-                });
+                .safe_sub(payout.total)?;
         } else {
             require!(
-                offer_data_info.offer_price <= buyer_user_pool.escrow_sol_balance,
+                payout.total <= buyer_user_pool.escrow_sol_balance,
                 MarketplaceError::InsufficientBuyerSolBalance
             );
-            buyer_user_pool.escrow_sol_balance = buyer_user_pool
-                .escrow_sol_balance
-                .checked_sub(offer_data_info.offer_price)
-                .unwrap_or_else(|| {
-                    panic!("accept_offer: underflow in buyer escrow_sol_balance calculation")
-                });
-            buyer_user_pool.traded_volume = buyer_user_pool
-                .traded_volume
-                .checked_add(offer_data_info.offer_price)
-                .unwrap_or_else(|| {
-                    panic!("accept_offer: overflow in buyer escrow_sol_balance calculation")
-                });
-            seller_user_pool.traded_volume = seller_user_pool
-                .traded_volume
-                .checked_add(offer_data_info.offer_price)
-                .unwrap_or_else(|| {
-                    panic!("accept_offer: overflow in seller escrow_sol_balance calculation")
-                });
+            buyer_user_pool.escrow_sol_balance =
+                buyer_user_pool.escrow_sol_balance.safe_sub(payout.total)?;
         }
 
-        let user_token_account_info = &mut &ctx.accounts.user_token_account;
-        let vault_token_account_info = &mut &ctx.accounts.escrow_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
-        let signer = &[&seeds[..]];
+        // Remaining accounts are the royalty creators followed by the pNFT transfer accounts
+        let creator_count = payout.royalties.len().min(ctx.remaining_accounts.len());
+        let (creators, pnft_accounts) = ctx.remaining_accounts.split_at(creator_count);
+        let pnft = PnftAccounts::load(pnft_accounts)?;
+
+        let seeds = &[
+            ESCROW_VAULT_SEED.as_bytes(),
+            &[global_authority.escrow_bump],
+        ];
+        settlement!(
+            ctx.accounts,
+            by_token,
+            escrow_vault,
+            escrow_token_account,
+            seller,
+            user_token_account,
+            &[&seeds[..]]
+        )
+        .execute(
+            &payout,
+            &mut ctx.accounts.treasury_config,
+            referral.as_mut(),
+            creators,
+        )?;
 
-        let global_authority = &mut ctx.accounts.global_authority;
-        let remaining_accounts: Vec<AccountInfo> = ctx.remaining_accounts.to_vec();
-        require!(
-            global_authority.team_count > 0,
-            MarketplaceError::NoTeamTreasuryYet
-        );
-        require_eq!(
-            global_authority.team_count,
-            remaining_accounts.len() as u64,
-            MarketplaceError::TeamTreasuryCountMismatch
-        );
+        record_volume(buyer_user_pool, seller_user_pool, by_token, price)?;
+
+        release_nft(
+            &ctx.accounts.global_authority,
+            NftTransfer {
+                token: ctx.accounts.dest_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.global_authority.to_account_info(),
+                destination: ctx.accounts.user_nft_token_account.to_account_info(),
+                destination_owner: ctx.accounts.buyer.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                amount: 1,
+                payer: ctx.accounts.seller.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            ctx.accounts.seller.to_account_info(),
+            pnft.as_ref(),
+        )?;
 
-        if offer_data_info.by_token == 0 {
-            let fee_amount: u64 = offer_data_info
-                .offer_price
-                .checked_mul(global_authority.market_fee_sol)
-                .unwrap_or_else(|| panic!("accept_offer: overflow in sol fee_amount calculation"))
-                / PERMYRIAD;
+        Ok(())
+    }
 
-            invoke_signed(
-                &system_instruction::transfer(
-                    ctx.accounts.escrow_vault.key,
-                    ctx.accounts.seller.key,
-                    offer_data_info
-                        .offer_price
-                        .checked_sub(fee_amount)
-                        .unwrap_or_else(|| {
-                            panic!(
-                                "accept_offer: underflow in seller sol transfer amount calculation"
-                            )
-                        }),
-                ),
-                &[
-                    ctx.accounts.seller.to_account_info().clone(),
-                    ctx.accounts.escrow_vault.to_account_info().clone(),
-                    ctx.accounts.system_program.to_account_info().clone(),
-                ],
-                signer,
-            )?;
-
-            let mut i = 0;
-            // This is not expensive cuz the max count is 8
-            for team_account in remaining_accounts {
-                // Assert Provided Remaining Account is Treasury
-                require!(
-                    team_account.key().eq(&global_authority.team_treasury[i]),
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-                invoke_signed(
-                    &system_instruction::transfer(
-                        ctx.accounts.escrow_vault.key,
-                        &global_authority.team_treasury[i],
-                        fee_amount
-                            .checked_mul(global_authority.treasury_rate[i])
-                            .unwrap_or_else(|| {
-                                panic!(
-                                    "accept_offer: overflow in team sol transfer amount calculation"
-                                )
-                            })
-                            / PERMYRIAD,
-                    ),
-                    &[
-                        ctx.accounts.escrow_vault.to_account_info().clone(),
-                        team_account.clone(),
-                        ctx.accounts.system_program.to_account_info().clone(),
-                    ],
-                    signer,
-                )?;
-                i += 1;
-            }
-        } else {
-            let fee_amount: u64 = offer_data_info
-                .offer_price
-                .checked_mul(global_authority.market_fee_token)
-                .unwrap_or_else(|| {
-                    panic!("accept_offer: overflow in token fee_amount calculation")
-                })
-                / PERMYRIAD;
-
-            let cpi_accounts = Transfer {
-                from: vault_token_account_info.to_account_info().clone(),
-                to: user_token_account_info.to_account_info().clone(),
-                authority: ctx.accounts.escrow_vault.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.clone().to_account_info(),
-                    cpi_accounts,
-                    signer,
-                ),
-                offer_data_info
-                    .offer_price
-                    .checked_sub(fee_amount)
-                    .unwrap_or_else(|| {
-                        panic!(
-                            "accept_offer: underflow in seller token transfer amount calculation"
-                        )
-                    }),
-            )?;
-
-            let mut i = 0;
-            // This is not expensive cuz the max count is 8
-            for team_account in remaining_accounts {
-                // Get ATA of Treasury Account
-                let team_ata = spl_associated_token_account::get_associated_token_address(
-                    &global_authority.team_treasury[i],
-                    &REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-                );
-                // Assert Provied Remaining Account is Treasury ATA
-                require!(
-                    team_account.key().eq(&team_ata),
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-                // Assert Treasury ATA is Initialized
-                require_eq!(
-                    team_account.owner,
-                    &token::ID,
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-
-                let cpi_accounts = Transfer {
-                    from: vault_token_account_info.to_account_info().clone(),
-                    to: team_account.to_account_info().clone(),
-                    authority: ctx.accounts.escrow_vault.to_account_info(),
-                };
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        token_program.clone().to_account_info(),
-                        cpi_accounts,
-                        signer,
-                    ),
-                    fee_amount
-                        .checked_mul(global_authority.treasury_rate[i])
-                        .unwrap_or_else(|| {
-                            panic!(
-                                "accept_offer: overflow in team token transfer amount calculation"
-                            )
-                        })
-                        / PERMYRIAD,
-                )?;
-                i += 1;
-            }
-        }
-
-        let nft_token_account_info = &mut &ctx.accounts.user_nft_token_account;
-        let dest_nft_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
-        let signer = &[&seeds[..]];
-
-        let cpi_accounts = Transfer {
-            from: dest_nft_token_account_info.to_account_info().clone(),
-            to: nft_token_account_info.to_account_info().clone(),
-            authority: global_authority.to_account_info(),
-        };
-        token::transfer(
-            CpiContext::new_with_signer(
-                token_program.clone().to_account_info(),
-                cpi_accounts,
-                signer,
-            ),
-            1,
-        )?;
-
-        invoke_signed(
-            &spl_token::instruction::close_account(
-                token_program.key,
-                &dest_nft_token_account_info.key(),
-                ctx.accounts.seller.key,
-                &global_authority.key(),
-                &[],
-            )?,
-            &[
-                token_program.clone().to_account_info(),
-                dest_nft_token_account_info.to_account_info().clone(),
-                ctx.accounts.seller.to_account_info().clone(),
-                global_authority.to_account_info().clone(),
-            ],
-            signer,
-        )?;
+    /**
+     * Close canceled or accepted Offer Data PDA to reclaim rent
+     *
+     * The rent is returned to the payer of the Offer Data PDA
+     */
+    pub fn close_offer_data(ctx: Context<CloseOfferData>) -> Result<()> {
+        let offer_data_info = &ctx.accounts.offer_data_info;
+        msg!(
+            "Mint: {:?}, buyer: {:?}",
+            offer_data_info.mint,
+            offer_data_info.buyer
+        );
 
-        // Validate PDA bump and seed
-        let (expected_global_address, expected_global_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
+        // Assert Payer is the Offer Data Rent Payer
+        require_keys_eq!(
+            ctx.accounts.payer.key(),
+            offer_data_info.payer,
+            MarketplaceError::RentPayerMismatch
         );
-        require_keys_eq!(global_authority.key(), expected_global_address);
+        // Assert the Offer is not live
         require_eq!(
-            expected_global_bump,
-            global_bump,
-            MarketplaceError::InvalidBump
+            offer_data_info.active,
+            0,
+            MarketplaceError::ActiveDataAccount
         );
 
-        // Validate PDA bump and seed
-        let (expected_nft_address, expected_nft_bump) = Pubkey::find_program_address(
-            &[
-                SELL_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(sell_data_info.key(), expected_nft_address);
-        require!(
-            expected_nft_bump == _nft_bump,
-            MarketplaceError::InvalidBump
-        );
+        Ok(())
+    }
 
-        // Validate PDA bump and seed
-        let (expected_offer_address, expected_offer_bump) = Pubkey::find_program_address(
-            &[
-                OFFER_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-                ctx.accounts.buyer.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(offer_data_info.key(), expected_offer_address);
-        require_eq!(
-            expected_offer_bump,
-            _offer_bump,
-            MarketplaceError::InvalidBump
-        );
+    /**
+     * List compressed NFT for sale
+     *
+     * Sell Data PDA is keyed by the asset id and the seller and created for the first listing of the seller
+     * The Merkle proof nodes of the leaf are passed as remaining accounts
+     *
+     * Params:  price_sol is the selling price in sol
+     *          price_token is the selling price in abb
+     *          fee_mode - marketplace fee payer, 0: global default, 1: seller, 2: buyer
+     *          reserved_buyer - the only buyer of a private listing, Pubkey::default() for public listings
+     *          leaf - the leaf of the compressed NFT in the current tree root
+     *          metadata - the metadata of the compressed NFT hashed in the leaf
+     */
+    pub fn list_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListCompressedNft<'info>>,
+        price_sol: u64,
+        price_token: u64,
+        fee_mode: u8,
+        reserved_buyer: Pubkey,
+        leaf: CompressedLeaf,
+        metadata: CompressedMetadata,
+    ) -> Result<()> {
+        // Assert the NFT and the trader is not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.asset_id.key(), &ctx.accounts.asset_deny_entry),
+            (ctx.accounts.owner.key(), &ctx.accounts.owner_deny_entry),
+        ])?;
+        assert_compressed_leaf(
+            &ctx.accounts.asset_id.key(),
+            &ctx.accounts.merkle_tree.key(),
+            &leaf,
+            &metadata,
+        )?;
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        msg!("Asset: {:?}", ctx.accounts.asset_id.key());
 
-        // Validate PDA bump and seed
-        let (expected_buyer_address, expected_buyer_bump) = Pubkey::find_program_address(
-            &[USER_DATA_SEED.as_bytes(), ctx.accounts.buyer.key().as_ref()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(buyer_user_pool.key(), expected_buyer_address);
-        require_eq!(
-            expected_buyer_bump,
-            _buyer_bump,
-            MarketplaceError::InvalidBump
+        require!(
+            price_sol > 0 && price_token > 0,
+            MarketplaceError::NonZeroError
         );
 
-        // Validate PDA bump and seed
-        let (expected_seller_address, expected_seller_bump) = Pubkey::find_program_address(
-            &[
-                USER_DATA_SEED.as_bytes(),
-                ctx.accounts.seller.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(seller_user_pool.key(), expected_seller_address);
-        require_eq!(
-            expected_seller_bump,
-            _seller_bump,
-            MarketplaceError::InvalidBump
+        // Initialize newly created Sell Data PDA
+        if sell_data_info.mint.eq(&Pubkey::default()) {
+            sell_data_info.mint = ctx.accounts.asset_id.key();
+            sell_data_info.payer = ctx.accounts.owner.key();
+            sell_data_info.bump = *ctx.bumps.get("sell_data_info").unwrap();
+        }
+        // Assert Asset Id with Sell Data PDA Mint
+        require!(
+            ctx.accounts.asset_id.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
         );
+        // Assert Already Listed NFT
+        require_eq!(sell_data_info.active, 0, MarketplaceError::AlreadyListedNFT);
+
+        let (collection, collection_method) =
+            metadata.resolve_collection(ctx.accounts.global_authority.creator_fallback)?;
+        if ctx.accounts.global_authority.restricted_listing == 1 {
+            assert_registered_collection(
+                &collection,
+                collection_method,
+                &ctx.accounts.collection_info,
+            )?;
+        }
+        sell_data_info.collection = collection;
+        sell_data_info.collection_method = collection_method;
 
-        // Validate PDA bump and seed
-        let (expected_escrow_address, expected_escrow_bump) =
-            Pubkey::find_program_address(&[ESCROW_VAULT_SEED.as_bytes()], &astro_marketplace::ID);
-        require_keys_eq!(ctx.accounts.escrow_vault.key(), expected_escrow_address);
-        require_eq!(
-            expected_escrow_bump,
-            escrow_bump,
-            MarketplaceError::InvalidBump
-        );
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Listed Date: {}", timestamp);
+
+        sell_data_info.seller = ctx.accounts.owner.key();
+        sell_data_info.price_sol = price_sol;
+        sell_data_info.price_token = price_token;
+        sell_data_info.listed_date = timestamp;
+        sell_data_info.active = 1;
+        sell_data_info.buyer_pays_fee =
+            resolve_buyer_pays_fee(&ctx.accounts.global_authority, fee_mode)?;
+        sell_data_info.quantity = 1;
+        sell_data_info.reserved_buyer = reserved_buyer;
+
+        CompressedTransfer {
+            tree_authority: ctx.accounts.tree_authority.to_account_info(),
+            leaf_owner: ctx.accounts.owner.to_account_info(),
+            leaf_delegate: ctx.accounts.leaf_delegate.to_account_info(),
+            new_leaf_owner: ctx.accounts.global_authority.to_account_info(),
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: ctx.accounts.compression_program.to_account_info(),
+            bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        }
+        .execute(&leaf, ctx.remaining_accounts, &[])?;
 
         Ok(())
     }
 
     /**
-     * Init auction data pda
+     * Cancel compressed NFT listing
      *
-     * Params:  nft - NFT address to start auction
-     *          auctiond data pda bump
+     * The Merkle proof nodes of the leaf are passed as remaining accounts
+     *
+     * Params:  leaf - the leaf of the escrowed compressed NFT in the current tree root
      */
-    pub fn init_auction_data(ctx: Context<InitAuctionData>, nft: Pubkey, _bump: u8) -> Result<()> {
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
-        auction_data_info.mint = nft;
+    pub fn delist_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelistCompressedNft<'info>>,
+        leaf: CompressedLeaf,
+    ) -> Result<()> {
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        msg!("Asset: {:?}", sell_data_info.mint);
 
-        // Validate PDA bump and seed
-        let (expected_address, expected_bump) = Pubkey::find_program_address(
-            &[AUCTION_DATA_SEED.as_bytes(), nft.key().as_ref()],
-            &astro_marketplace::ID,
+        // Assert Asset Id with Sell Data PDA Mint
+        require!(
+            ctx.accounts.asset_id.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
         );
+        // Assert the leaf belongs to the asset
+        require_keys_eq!(
+            ctx.accounts.asset_id.key(),
+            get_asset_id(&ctx.accounts.merkle_tree.key(), leaf.nonce),
+            MarketplaceError::InvalidCompressedNftAccounts
+        );
+        // Assert NFT seller is payer
+        require!(
+            ctx.accounts.owner.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerMismatch
+        );
+        // Assert Already Delisted NFT
+        require_eq!(sell_data_info.active, 1, MarketplaceError::NotListedNFT);
 
-        require_keys_eq!(auction_data_info.key(), expected_address);
-        require_eq!(expected_bump, _bump, MarketplaceError::InvalidBump);
+        sell_data_info.active = 0;
+        sell_data_info.quantity = 0;
+
+        release_compressed_nft(
+            &ctx.accounts.global_authority,
+            CompressedTransfer {
+                tree_authority: ctx.accounts.tree_authority.to_account_info(),
+                leaf_owner: ctx.accounts.global_authority.to_account_info(),
+                leaf_delegate: ctx.accounts.global_authority.to_account_info(),
+                new_leaf_owner: ctx.accounts.owner.to_account_info(),
+                merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+                log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: ctx.accounts.compression_program.to_account_info(),
+                bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &leaf,
+            ctx.remaining_accounts,
+        )?;
 
         Ok(())
     }
 
     /**
-     * Start an auction for NFT
+     * Purchase listed compressed NFT in "Buy Now" price
      *
-     * Params:  global pda bump
-     *          auction pda bump
-     *          start_price - Bidding start price
-     *          min_increase - Minimum inscrease amount of next bidding price
-     *          by_token - auction selling by abb
-     *          end_date - auction end date
+     * Remaining accounts are the royalty creators followed by the Merkle proof nodes of the leaf
+     *
+     * Params:  by_token is true when purchasing with abb tokens
+     *          leaf - the leaf of the escrowed compressed NFT in the current tree root
+     *          metadata - the metadata of the compressed NFT hashed in the leaf
      */
-    pub fn create_auction(
-        ctx: Context<CreateAuction>,
-        _global_bump: u8,
-        _auction_bump: u8,
-        start_price: u64,
-        min_increase: u64,
-        by_token: u64,
-        end_date: i64,
+    pub fn purchase_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseCompressedNft<'info>>,
+        by_token: u8,
+        leaf: CompressedLeaf,
+        metadata: CompressedMetadata,
     ) -> Result<()> {
-        // validate min_increase, end_date
+        // Assert the NFT and the traders are not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.asset_id.key(), &ctx.accounts.asset_deny_entry),
+            (ctx.accounts.buyer.key(), &ctx.accounts.buyer_deny_entry),
+            (ctx.accounts.seller.key(), &ctx.accounts.seller_deny_entry),
+        ])?;
+        assert_compressed_leaf(
+            &ctx.accounts.asset_id.key(),
+            &ctx.accounts.merkle_tree.key(),
+            &leaf,
+            &metadata,
+        )?;
+        // By Token should be zero or one
+        require!(by_token < 2, MarketplaceError::InvalidParamInput);
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+
+        msg!(
+            "Purchase Asset: {:?}, By Token: {}",
+            sell_data_info.mint,
+            by_token == 1
+        );
+
+        // Assert Asset Id with Sell Data PDA Mint
         require!(
-            min_increase > 0,
-            MarketplaceError::InvalidMinimumIncreaseValue
+            ctx.accounts.asset_id.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
         );
+        require_eq!(sell_data_info.active, 1, MarketplaceError::NotListedNFT);
+        // Assert Buyer is the reserved buyer of a private listing
+        assert_reserved_buyer(&sell_data_info.reserved_buyer, &ctx.accounts.buyer.key())?;
+        // Assert Seller Sell Data Address
         require!(
-            end_date > Clock::get()?.unix_timestamp,
-            MarketplaceError::InvalidEndDate
+            ctx.accounts.seller.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerAccountMismatch
         );
-
-        // By Token Param should be zero or one
-        require!(by_token < 2, MarketplaceError::InvalidParamInput);
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
-        msg!("Mint: {:?}", auction_data_info.mint);
-
-        // Assert NFT Pubkey with Auction Data PDA Mint
+        // Assert Seller User PDA Address
         require!(
-            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Buyer User PDA Address
+        require!(
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
         );
 
-        let timestamp = Clock::get()?.unix_timestamp;
-        msg!("Created Date: {}", timestamp);
-
-        auction_data_info.creator = ctx.accounts.owner.key();
-        auction_data_info.start_price = start_price;
-        auction_data_info.min_increase_amount = min_increase;
-        auction_data_info.by_token = by_token;
-        auction_data_info.end_date = end_date;
-        auction_data_info.last_bidder = Pubkey::default();
-        auction_data_info.highest_bid = start_price;
-        auction_data_info.status = 1;
-
-        let token_account_info = &mut &ctx.accounts.user_token_account;
-        let dest_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
+        sell_data_info.active = 0;
+        sell_data_info.quantity = 0;
 
-        let cpi_accounts = Transfer {
-            from: token_account_info.to_account_info().clone(),
-            to: dest_token_account_info.to_account_info().clone(),
-            authority: ctx.accounts.owner.to_account_info().clone(),
+        let global_authority = &ctx.accounts.global_authority;
+        let price = if by_token == 1 {
+            sell_data_info.price_token
+        } else {
+            sell_data_info.price_sol
         };
-        token::transfer(
-            CpiContext::new(token_program.clone().to_account_info(), cpi_accounts),
-            1,
+        let fee_rate = market_fee_rate(
+            global_authority,
+            &sell_data_info.collection,
+            &ctx.accounts.collection_fee,
+            buyer_user_pool,
+            seller_user_pool,
+            by_token == 1,
+        )?;
+        let mut referral = load_referral(
+            &ctx.accounts.referral_data,
+            &ctx.accounts.buyer.key(),
+            &ctx.accounts.seller.key(),
+        )?;
+        let payout = Payout::compute(
+            price,
+            fee_rate,
+            global_authority,
+            &ctx.accounts.treasury_config,
+            &metadata.royalty(),
+            referral.is_some(),
+            sell_data_info.buyer_pays_fee == 1,
+            (by_token == 1).then_some(&ctx.accounts.payment_mint),
         )?;
 
-        // Validate PDA bump and seed
-        let (expected_global_address, expected_global_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(ctx.accounts.global_authority.key(), expected_global_address);
-        require_eq!(
-            expected_global_bump,
-            _global_bump,
-            MarketplaceError::InvalidBump
-        );
-        // Validate PDA bump and seed
-        let (expected_auction_address, expected_auction_bump) = Pubkey::find_program_address(
-            &[
-                AUCTION_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(auction_data_info.key(), expected_auction_address);
-        require_eq!(
-            expected_auction_bump,
-            _auction_bump,
-            MarketplaceError::InvalidBump
-        );
+        let creator_count = payout.royalties.len().min(ctx.remaining_accounts.len());
+        let (creators, proof) = ctx.remaining_accounts.split_at(creator_count);
+
+        settlement!(
+            ctx.accounts,
+            by_token == 1,
+            buyer,
+            user_token_account,
+            seller,
+            seller_token_account,
+            &[]
+        )
+        .execute(
+            &payout,
+            &mut ctx.accounts.treasury_config,
+            referral.as_mut(),
+            creators,
+        )?;
+
+        record_volume(buyer_user_pool, seller_user_pool, by_token == 1, price)?;
+
+        release_compressed_nft(
+            &ctx.accounts.global_authority,
+            CompressedTransfer {
+                tree_authority: ctx.accounts.tree_authority.to_account_info(),
+                leaf_owner: ctx.accounts.global_authority.to_account_info(),
+                leaf_delegate: ctx.accounts.global_authority.to_account_info(),
+                new_leaf_owner: ctx.accounts.buyer.to_account_info(),
+                merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+                log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: ctx.accounts.compression_program.to_account_info(),
+                bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &leaf,
+            proof,
+        )?;
 
         Ok(())
     }
 
     /**
-     * Place a bid for an auction
+     * Sell compressed NFT in offer price
+     *
+     * Offers for compressed NFTs are made and canceled by make_offer and cancel_offer with the asset id as the NFT mint
+     * Remaining accounts are the royalty creators followed by the Merkle proof nodes of the leaf
      *
-     * Params:  auction pda bump
-     *          escrow vault pda bump
-     *          price - bidding price
+     * Params:  leaf - the leaf of the escrowed compressed NFT in the current tree root
+     *          metadata - the metadata of the compressed NFT hashed in the leaf
      */
-    pub fn place_bid(
-        ctx: Context<PlaceBid>,
-        _auction_bump: u8,
-        escrow_bump: u8,
-        price: u64,
+    pub fn accept_compressed_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptCompressedOffer<'info>>,
+        leaf: CompressedLeaf,
+        metadata: CompressedMetadata,
     ) -> Result<()> {
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        // Assert the NFT and the traders are not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.asset_id.key(), &ctx.accounts.asset_deny_entry),
+            (ctx.accounts.buyer.key(), &ctx.accounts.buyer_deny_entry),
+            (ctx.accounts.seller.key(), &ctx.accounts.seller_deny_entry),
+        ])?;
+        assert_compressed_leaf(
+            &ctx.accounts.asset_id.key(),
+            &ctx.accounts.merkle_tree.key(),
+            &leaf,
+            &metadata,
+        )?;
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
 
-        let timestamp = Clock::get()?.unix_timestamp;
-        msg!("Place Date: {}", timestamp);
-        // Assert NFT Pubkey with Auction Data PDA Mint
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+        // Assert Buyer User PDA Address
         require!(
-            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
         );
-        // Assert Auction Already Ended
+        // Assert Seller User PDA Address
         require!(
-            auction_data_info.end_date > timestamp,
-            MarketplaceError::EndedAuction
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
         );
-        // Assert Already Disabled Auction
-        require_eq!(auction_data_info.status, 1, MarketplaceError::NotListedNFT);
-        // New Bid should be increased more than min_increase_amount
+
+        // Assert Asset Id with Sell Data PDA Mint
         require!(
-            auction_data_info
-                .highest_bid
-                .checked_add(auction_data_info.min_increase_amount)
-                .unwrap_or_else(|| { panic!("place_bid: overflow in new bid amount calculation") })
-                <= price,
-            MarketplaceError::InvalidBidPrice
+            ctx.accounts.asset_id.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
         );
-        // Assert OutBidder Address with the Last Bidder
+        // Assert Already Delisted NFT
+        require_eq!(sell_data_info.active, 1, MarketplaceError::NotListedNFT);
+        // Assert Seller Pubkey with Sell Data PDA Seller Address
         require!(
-            Pubkey::default().eq(&auction_data_info.last_bidder)
-                || ctx
-                    .accounts
-                    .out_bidder
-                    .key()
-                    .eq(&auction_data_info.last_bidder),
-            MarketplaceError::OutBidderMismatch
+            ctx.accounts.seller.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerAccountMismatch
         );
-        // Assert New Bidder is same with the Last Bidder
+
+        let offer_data_info = &mut ctx.accounts.offer_data_info;
+        // Assert Asset Id with Offer Data PDA Mint
         require!(
-            !ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
-            MarketplaceError::DoubleBidFromOneBidder
+            ctx.accounts.asset_id.key().eq(&offer_data_info.mint),
+            MarketplaceError::InvalidOfferDataMint
         );
-        // Assert Bid from Auction Creator
+        // Assert Buyer Pubkey with Offer Data PDA Buyer Address
         require!(
-            !ctx.accounts.bidder.key().eq(&auction_data_info.creator),
-            MarketplaceError::BidFromAuctionCreator
+            ctx.accounts.buyer.key().eq(&offer_data_info.buyer),
+            MarketplaceError::InvalidOfferDataBuyer
+        );
+        // Assert Already Disabled Offer
+        require_eq!(offer_data_info.active, 1, MarketplaceError::DisabledOffer);
+        // Assert Buyer is the reserved buyer of a private listing
+        assert_reserved_buyer(&sell_data_info.reserved_buyer, &ctx.accounts.buyer.key())?;
+        // Assert Offer provided date with the NFT Listed Date
+        require_eq!(
+            offer_data_info.offer_listing_date,
+            sell_data_info.listed_date,
+            MarketplaceError::OfferForExpiredListingNFT
         );
 
         msg!(
-            "Mint: {:?}, Bidder: {:?}",
-            auction_data_info.mint,
-            ctx.accounts.bidder.key()
+            "Offer Asset: {:?}, Seller: {:?}, Buyer: {:?}, Price: {}, ByToken: {}",
+            offer_data_info.mint,
+            sell_data_info.seller,
+            offer_data_info.buyer,
+            offer_data_info.offer_price,
+            offer_data_info.by_token,
         );
 
-        let user_token_account_info = &mut &ctx.accounts.bidder_token_account;
-        let vault_token_account_info = &mut &ctx.accounts.escrow_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
-        let signer = &[&seeds[..]];
+        offer_data_info.active = 0;
+        sell_data_info.active = 0;
+        sell_data_info.quantity = 0;
+
+        let price = offer_data_info.offer_price;
+        let by_token = offer_data_info.by_token == 1;
+
+        let global_authority = &ctx.accounts.global_authority;
+        let fee_rate = market_fee_rate(
+            global_authority,
+            &sell_data_info.collection,
+            &ctx.accounts.collection_fee,
+            buyer_user_pool,
+            seller_user_pool,
+            by_token,
+        )?;
+        // The buyer paying the fee is never charged more than the fee escrowed with the offer
+        let fee_rate = if sell_data_info.buyer_pays_fee == 1 {
+            fee_rate.min(offer_data_info.fee_rate)
+        } else {
+            fee_rate
+        };
+        let mut referral = load_referral(
+            &ctx.accounts.referral_data,
+            &ctx.accounts.buyer.key(),
+            &ctx.accounts.seller.key(),
+        )?;
+        let payout = Payout::compute(
+            price,
+            fee_rate,
+            global_authority,
+            &ctx.accounts.treasury_config,
+            &metadata.royalty(),
+            referral.is_some(),
+            sell_data_info.buyer_pays_fee == 1,
+            by_token.then_some(&ctx.accounts.payment_mint),
+        )?;
 
-        if auction_data_info.by_token == 0 {
-            // Refund Last Bidder Escrow
-            if !Pubkey::default().eq(&auction_data_info.last_bidder) {
-                invoke_signed(
-                    &system_instruction::transfer(
-                        ctx.accounts.escrow_vault.key,
-                        ctx.accounts.out_bidder.key,
-                        auction_data_info.highest_bid,
-                    ),
-                    &[
-                        ctx.accounts.out_bidder.to_account_info().clone(),
-                        ctx.accounts.escrow_vault.to_account_info().clone(),
-                        ctx.accounts.system_program.to_account_info().clone(),
-                    ],
-                    signer,
-                )?;
-            }
-            // Escrow New Bidder funds
-            invoke(
-                &system_instruction::transfer(
-                    ctx.accounts.bidder.key,
-                    ctx.accounts.escrow_vault.key,
-                    price,
-                ),
-                &[
-                    ctx.accounts.bidder.to_account_info().clone(),
-                    ctx.accounts.escrow_vault.to_account_info().clone(),
-                    ctx.accounts.system_program.to_account_info().clone(),
-                ],
-            )?;
+        // Buyer pays the price and the fee on top of it from the escrow balance
+        if by_token {
+            require!(
+                payout.total <= buyer_user_pool.escrow_token_balance,
+                MarketplaceError::InsufficientBuyerTokenBalance
+            );
+            buyer_user_pool.escrow_token_balance = buyer_user_pool
+                .escrow_token_balance
+                .safe_sub(payout.total)?;
         } else {
-            // Refund Last Bidder Escrow
-            if !Pubkey::default().eq(&auction_data_info.last_bidder) {
-                let cpi_accounts = Transfer {
-                    from: vault_token_account_info.to_account_info().clone(),
-                    to: ctx
-                        .accounts
-                        .out_bidder_token_account
-                        .to_account_info()
-                        .clone(),
-                    authority: ctx.accounts.escrow_vault.to_account_info(),
-                };
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        token_program.clone().to_account_info(),
-                        cpi_accounts,
-                        signer,
-                    ),
-                    auction_data_info.highest_bid,
-                )?;
-            }
-            // Escrow New Bidder funds
-            let cpi_accounts = Transfer {
-                from: user_token_account_info.to_account_info().clone(),
-                to: vault_token_account_info.to_account_info().clone(),
-                authority: ctx.accounts.bidder.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new(token_program.clone().to_account_info(), cpi_accounts),
-                price,
-            )?;
+            require!(
+                payout.total <= buyer_user_pool.escrow_sol_balance,
+                MarketplaceError::InsufficientBuyerSolBalance
+            );
+            buyer_user_pool.escrow_sol_balance =
+                buyer_user_pool.escrow_sol_balance.safe_sub(payout.total)?;
         }
 
-        auction_data_info.last_bid_date = timestamp;
-        auction_data_info.last_bidder = ctx.accounts.bidder.key();
-        auction_data_info.highest_bid = price;
+        let creator_count = payout.royalties.len().min(ctx.remaining_accounts.len());
+        let (creators, proof) = ctx.remaining_accounts.split_at(creator_count);
+
+        let seeds = &[
+            ESCROW_VAULT_SEED.as_bytes(),
+            &[global_authority.escrow_bump],
+        ];
+        settlement!(
+            ctx.accounts,
+            by_token,
+            escrow_vault,
+            escrow_token_account,
+            seller,
+            user_token_account,
+            &[&seeds[..]]
+        )
+        .execute(
+            &payout,
+            &mut ctx.accounts.treasury_config,
+            referral.as_mut(),
+            creators,
+        )?;
 
-        // Validate PDA bump and seed
-        let (expected_auction_address, expected_auction_bump) = Pubkey::find_program_address(
-            &[
-                AUCTION_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
-        );
-        require!(
-            auction_data_info.key() == expected_auction_address
-                && expected_auction_bump == _auction_bump,
-            MarketplaceError::InvalidBump
-        );
+        record_volume(buyer_user_pool, seller_user_pool, by_token, price)?;
+
+        release_compressed_nft(
+            &ctx.accounts.global_authority,
+            CompressedTransfer {
+                tree_authority: ctx.accounts.tree_authority.to_account_info(),
+                leaf_owner: ctx.accounts.global_authority.to_account_info(),
+                leaf_delegate: ctx.accounts.global_authority.to_account_info(),
+                new_leaf_owner: ctx.accounts.buyer.to_account_info(),
+                merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+                log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: ctx.accounts.compression_program.to_account_info(),
+                bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &leaf,
+            proof,
+        )?;
 
         Ok(())
     }
 
     /**
-     * Claim NFT for won auction
+     * List a bundle of NFTs for sale at one price
      *
-     * Params: global pda bump
-     *          escrow vault bump
+     * Bundle Data PDA is keyed by the first NFT mint of the bundle and the seller and created for the first listing
+     * Remaining accounts are the items in order, each of the NFT mint, the mint metadata,
+     * the seller NFT token account, the escrow NFT token account of the global authority,
+     * the mint Deny Entry PDA and the Collection Info PDA
+     * Every item is of the same collection which sets the fee of the bundle
+     * Programmable and compressed NFTs can't be bundled
+     *
+     * Params:  price_sol is the bundle price in sol
+     *          price_token is the bundle price in abb
+     *          fee_mode - marketplace fee payer, 0: global default, 1: seller, 2: buyer
+     *          reserved_buyer - the only buyer of a private listing, Pubkey::default() for public listings
      */
-    pub fn claim_auction<'info>(
-        ctx: Context<'_, '_, '_, 'info, ClaimAuction<'info>>,
-        global_bump: u8,
-        _auction_bump: u8,
-        escrow_bump: u8,
+    pub fn list_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListBundle<'info>>,
+        price_sol: u64,
+        price_token: u64,
+        fee_mode: u8,
+        reserved_buyer: Pubkey,
     ) -> Result<()> {
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
-        msg!("Mint: {:?}", auction_data_info.mint);
-
-        let timestamp = Clock::get()?.unix_timestamp;
-        msg!("Claim Date: {}", timestamp);
-        // Assert NFT Pubkey with Auction Data PDA Mint
-        require!(
-            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
-        );
-        // Assert Auction End Date is Passed
-        require!(
-            auction_data_info.end_date <= timestamp,
-            MarketplaceError::NotEndedAuction
-        );
-        // Assert Already Ended or Not Started Auction
-        require_eq!(auction_data_info.status, 1, MarketplaceError::NotListedNFT);
-        // Assert Creator Pubkey with Auction Data Creator Address
-        require!(
-            ctx.accounts.creator.key().eq(&auction_data_info.creator),
-            MarketplaceError::CreatorAccountMismatch
-        );
-        // Assert Bidder Pubkey with Auction Data Last Bidder Address
-        require!(
-            ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
-            MarketplaceError::BidderAccountMismatch
-        );
+        // Assert the trader is not denylisted
+        assert_not_denied(&ctx.accounts.owner.key(), &ctx.accounts.owner_deny_entry)?;
+        let bundle_data_info = &mut ctx.accounts.bundle_data_info;
+        msg!("Bundle: {:?}", ctx.accounts.bundle_mint.key());
 
-        let bidder_user_pool = &mut ctx.accounts.bidder_user_pool;
-        let creator_user_pool = &mut ctx.accounts.creator_user_pool;
-        // Assert Bidder User PDA Address
         require!(
-            ctx.accounts.bidder.key().eq(&bidder_user_pool.address),
-            MarketplaceError::BidderAccountMismatch
+            price_sol > 0 && price_token > 0,
+            MarketplaceError::NonZeroError
         );
-        // Assert Creator User PDA Address
+        let item_count = ctx.remaining_accounts.len() / BUNDLE_LIST_ACCOUNT_COUNT;
         require!(
-            ctx.accounts.creator.key().eq(&creator_user_pool.address),
-            MarketplaceError::CreatorAccountMismatch
+            ctx.remaining_accounts.len() == item_count * BUNDLE_LIST_ACCOUNT_COUNT
+                && (2..=MAX_BUNDLE_SIZE).contains(&item_count),
+            MarketplaceError::InvalidBundleSize
         );
 
-        // Set Flag as Claimed Auction
-        auction_data_info.status = 2;
-        bidder_user_pool.traded_volume = bidder_user_pool
-            .traded_volume
-            .checked_add(auction_data_info.highest_bid)
-            .unwrap_or_else(|| {
-                panic!("claimed_auction: overflow in bidder traded_volume calculation")
-            });
-        creator_user_pool.traded_volume = creator_user_pool
-            .traded_volume
-            .checked_add(auction_data_info.highest_bid)
-            .unwrap_or_else(|| {
-                panic!("claimed_auction: overflow in creator traded_volume calculation")
-            });
-
-        let token_account_info = &mut &ctx.accounts.user_token_account;
-        let dest_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
-        let signer = &[&seeds[..]];
-
-        let global_authority = &mut ctx.accounts.global_authority;
-        let remaining_accounts: Vec<AccountInfo> = ctx.remaining_accounts.to_vec();
-        require!(
-            global_authority.team_count > 0,
-            MarketplaceError::NoTeamTreasuryYet
-        );
+        // Initialize newly created Bundle Data PDA
+        if bundle_data_info.payer.eq(&Pubkey::default()) {
+            bundle_data_info.payer = ctx.accounts.owner.key();
+            bundle_data_info.bump = *ctx.bumps.get("bundle_data_info").unwrap();
+        }
+        // Assert Already Listed Bundle
         require_eq!(
-            global_authority.team_count,
-            remaining_accounts.len() as u64,
-            MarketplaceError::TeamTreasuryCountMismatch
+            bundle_data_info.active,
+            0,
+            MarketplaceError::AlreadyListedNFT
         );
 
-        if auction_data_info.by_token == 0 {
-            let fee_amount: u64 = auction_data_info
-                .highest_bid
-                .checked_mul(global_authority.market_fee_sol)
-                .unwrap_or_else(|| {
-                    panic!("claimed_auction: overflow in sol fee_amount calculation")
-                })
-                / PERMYRIAD;
-
-            invoke_signed(
-                &system_instruction::transfer(
-                    ctx.accounts.escrow_vault.key,
-                    ctx.accounts.creator.key,
-                    auction_data_info
-                        .highest_bid
-                        .checked_sub(fee_amount)
-                        .unwrap_or_else(|| {
-                            panic!(
-                            "claimed_auction: underflow in seller sol transfer amount calculation"
-                        )
-                        }),
+        let global_authority = &ctx.accounts.global_authority;
+        let mut mints = [Pubkey::default(); MAX_BUNDLE_SIZE];
+        let mut collection = Pubkey::default();
+        for (i, item) in ctx
+            .remaining_accounts
+            .chunks(BUNDLE_LIST_ACCOUNT_COUNT)
+            .enumerate()
+        {
+            let (nft_mint, mint_metadata, user_token_account, dest_nft_token_account) =
+                (&item[0], &item[1], &item[2], &item[3]);
+            // Assert the NFT is not denylisted
+            assert_not_denied(&nft_mint.key(), &item[4])?;
+            // Assert the first item is the Bundle Data PDA key and every item is bundled once
+            require!(
+                (i > 0 || nft_mint.key().eq(&ctx.accounts.bundle_mint.key()))
+                    && !mints[..i].contains(&nft_mint.key()),
+                MarketplaceError::InvalidBundleAccounts
+            );
+            require_keys_eq!(
+                *nft_mint.owner,
+                ctx.accounts.nft_token_program.key(),
+                MarketplaceError::InvalidTokenProgram
+            );
+            require!(
+                is_nft_token_account(
+                    user_token_account,
+                    &nft_mint.key(),
+                    ctx.accounts.owner.key,
+                    Some(1)
+                ) && is_nft_token_account(
+                    dest_nft_token_account,
+                    &nft_mint.key(),
+                    &global_authority.key(),
+                    None
                 ),
-                &[
-                    ctx.accounts.creator.to_account_info().clone(),
-                    ctx.accounts.escrow_vault.to_account_info().clone(),
-                    ctx.accounts.system_program.to_account_info().clone(),
-                ],
-                signer,
-            )?;
+                MarketplaceError::InvalidNftTokenAccount
+            );
 
-            let mut i = 0;
-            // This is not expensive cuz the max count is 8
-            for team_account in remaining_accounts {
-                // Assert Provided Remaining Account is Treasury
-                require!(
-                    team_account.key().eq(&global_authority.team_treasury[i]),
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-                invoke_signed(
-                    &system_instruction::transfer(
-                        ctx.accounts.escrow_vault.key,
-                        &global_authority.team_treasury[i],
-                        fee_amount
-                            .checked_mul(global_authority.treasury_rate[i])
-                            .unwrap_or_else(|| {
-                                panic!(
-                                "claimed_auction: overflow in team sol transfer amount calculation"
-                            )
-                            })
-                            / PERMYRIAD,
-                    ),
-                    &[
-                        ctx.accounts.escrow_vault.to_account_info().clone(),
-                        team_account.clone(),
-                        ctx.accounts.system_program.to_account_info().clone(),
-                    ],
-                    signer,
-                )?;
-                i += 1;
-            }
-        } else {
-            let fee_amount: u64 = auction_data_info
-                .highest_bid
-                .checked_mul(global_authority.market_fee_token)
-                .unwrap_or_else(|| {
-                    panic!("claimed_auction: overflow in token fee_amount calculation")
-                })
-                / PERMYRIAD;
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.escrow_token_account.to_account_info().clone(),
-                to: ctx.accounts.creator_token_account.to_account_info().clone(),
-                authority: ctx.accounts.escrow_vault.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.clone().to_account_info(),
-                    cpi_accounts,
-                    signer,
-                ),
-                auction_data_info
-                    .highest_bid
-                    .checked_sub(fee_amount)
-                    .unwrap_or_else(|| {
-                        panic!("claimed_auction: underflow in token transfer amount calculation")
-                    }),
+            let nft_metadata = load_metadata(&nft_mint.key(), mint_metadata)?;
+            let (item_collection, collection_method) = resolve_collection(
+                &nft_metadata,
+                mint_metadata,
+                global_authority.creator_fallback,
             )?;
-
-            let mut i = 0;
-            // This is not expensive cuz the max count is 8
-            // remaining_accounts should be tokenAccount for token purchasing
-            for team_token_account in remaining_accounts {
-                // Get ATA of Treasury Account
-                let team_ata = spl_associated_token_account::get_associated_token_address(
-                    &global_authority.team_treasury[i],
-                    &REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-                );
-                // Assert Provied Remaining Account is Treasury ATA
-                require!(
-                    team_token_account.key().eq(&team_ata),
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-                // Assert Treasury ATA is Initialized
-                require_eq!(
-                    team_token_account.owner,
-                    &token::ID,
-                    MarketplaceError::TeamTreasuryAddressMismatch
-                );
-                let cpi_accounts = Transfer {
-                    from: ctx.accounts.escrow_token_account.to_account_info().clone(),
-                    to: team_token_account.clone(),
-                    authority: ctx.accounts.escrow_vault.to_account_info(),
-                };
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        token_program.clone().to_account_info(),
-                        cpi_accounts,
-                        signer,
-                    ),
-                    fee_amount
-                        .checked_mul(global_authority.treasury_rate[i])
-                        .unwrap_or_else(|| {
-                            panic!(
-                            "claimed_auction: overflow in team token transfer amount calculation"
-                        )
-                        })
-                        / PERMYRIAD,
-                )?;
-
-                i += 1;
+            if global_authority.restricted_listing == 1 {
+                assert_registered_collection(&item_collection, collection_method, &item[5])?;
             }
+            // The bundle collection sets the fee of the bundle
+            if i == 0 {
+                collection = item_collection;
+            }
+            // Assert every item is of the collection of the first item
+            require_keys_eq!(
+                item_collection,
+                collection,
+                MarketplaceError::MixedBundleCollections
+            );
+            mints[i] = nft_mint.key();
+
+            // Assert the NFT has no Token-2022 transfer fee
+            assert_no_transfer_fee(nft_mint)?;
+            NftTransfer {
+                token: user_token_account.clone(),
+                token_owner: ctx.accounts.owner.to_account_info(),
+                destination: dest_nft_token_account.clone(),
+                destination_owner: global_authority.to_account_info(),
+                mint: nft_mint.clone(),
+                amount: 1,
+                payer: ctx.accounts.owner.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            }
+            .execute(None, &[])?;
         }
-        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
-        let signer = &[&seeds[..]];
 
-        let cpi_accounts = Transfer {
-            from: dest_token_account_info.to_account_info().clone(),
-            to: token_account_info.to_account_info().clone(),
-            authority: global_authority.to_account_info().clone(),
-        };
-        token::transfer(
-            CpiContext::new_with_signer(
-                token_program.clone().to_account_info(),
-                cpi_accounts,
-                signer,
-            ),
-            1,
-        )?;
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Items: {}, Listed Date: {}", item_count, timestamp);
+
+        bundle_data_info.seller = ctx.accounts.owner.key();
+        bundle_data_info.collection = collection;
+        bundle_data_info.mints = mints;
+        bundle_data_info.item_count = item_count as u64;
+        bundle_data_info.price_sol = price_sol;
+        bundle_data_info.price_token = price_token;
+        bundle_data_info.reserved_buyer = reserved_buyer;
+        bundle_data_info.listed_date = timestamp;
+        bundle_data_info.active = 1;
+        bundle_data_info.buyer_pays_fee = resolve_buyer_pays_fee(global_authority, fee_mode)?;
 
-        invoke_signed(
-            &spl_token::instruction::close_account(
-                token_program.key,
-                &dest_token_account_info.key(),
-                ctx.accounts.bidder.key,
-                &global_authority.key(),
-                &[],
-            )?,
-            &[
-                token_program.clone().to_account_info(),
-                dest_token_account_info.to_account_info().clone(),
-                ctx.accounts.bidder.to_account_info().clone(),
-                global_authority.to_account_info().clone(),
-            ],
-            signer,
-        )?;
+        Ok(())
+    }
 
-        // Validate PDA bump and seed
-        let (expected_global_address, expected_global_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
-        );
-        require_keys_eq!(global_authority.key(), expected_global_address);
-        require_eq!(
-            expected_global_bump,
-            global_bump,
-            MarketplaceError::InvalidBump
-        );
-        // Validate PDA bump and seed
-        let (expected_auction_address, expected_auction_bump) = Pubkey::find_program_address(
-            &[
-                AUCTION_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
+    /**
+     * Cancel bundle listing
+     *
+     * Remaining accounts are the items in the listed order,
+     * each of the NFT mint, the escrow NFT token account and the seller NFT token account
+     */
+    pub fn delist_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelistBundle<'info>>,
+    ) -> Result<()> {
+        let bundle_data_info = &mut ctx.accounts.bundle_data_info;
+        msg!("Bundle: {:?}", ctx.accounts.bundle_mint.key());
+
+        // Assert NFT seller is payer
+        require!(
+            ctx.accounts.owner.key().eq(&bundle_data_info.seller),
+            MarketplaceError::SellerMismatch
         );
-        require_keys_eq!(auction_data_info.key(), expected_auction_address);
-        require_eq!(
-            expected_auction_bump,
-            _auction_bump,
-            MarketplaceError::InvalidBump
-        );
-        // Validate PDA bump and seed
-        let (expected_escrow_address, expected_escrow_bump) =
-            Pubkey::find_program_address(&[ESCROW_VAULT_SEED.as_bytes()], &astro_marketplace::ID);
-        require_keys_eq!(ctx.accounts.escrow_vault.key(), expected_escrow_address);
+        // Assert Already Delisted Bundle
+        require_eq!(bundle_data_info.active, 1, MarketplaceError::NotListedNFT);
         require_eq!(
-            expected_escrow_bump,
-            escrow_bump,
-            MarketplaceError::InvalidBump
+            ctx.remaining_accounts.len(),
+            bundle_data_info.item_count as usize * BUNDLE_DELIST_ACCOUNT_COUNT,
+            MarketplaceError::InvalidBundleAccounts
         );
 
+        bundle_data_info.active = 0;
+
+        for (i, item) in ctx
+            .remaining_accounts
+            .chunks(BUNDLE_DELIST_ACCOUNT_COUNT)
+            .enumerate()
+        {
+            let (nft_mint, dest_nft_token_account, user_token_account) =
+                (&item[0], &item[1], &item[2]);
+            require_keys_eq!(
+                nft_mint.key(),
+                bundle_data_info.mints[i],
+                MarketplaceError::InvalidBundleAccounts
+            );
+            require!(
+                is_nft_token_account(
+                    dest_nft_token_account,
+                    &nft_mint.key(),
+                    &ctx.accounts.global_authority.key(),
+                    Some(1)
+                ) && is_nft_token_account(
+                    user_token_account,
+                    &nft_mint.key(),
+                    ctx.accounts.owner.key,
+                    None
+                ),
+                MarketplaceError::InvalidNftTokenAccount
+            );
+
+            release_nft(
+                &ctx.accounts.global_authority,
+                NftTransfer {
+                    token: dest_nft_token_account.clone(),
+                    token_owner: ctx.accounts.global_authority.to_account_info(),
+                    destination: user_token_account.clone(),
+                    destination_owner: ctx.accounts.owner.to_account_info(),
+                    mint: nft_mint.clone(),
+                    amount: 1,
+                    payer: ctx.accounts.owner.to_account_info(),
+                    token_program: ctx.accounts.nft_token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                ctx.accounts.owner.to_account_info(),
+                None,
+            )?;
+        }
+
         Ok(())
     }
 
     /**
-     * Cancel auction has no bid until end date
+     * Purchase listed bundle in "Buy Now" price
      *
-     * Params: global pda bump
-     *          auctiond data pda bump
+     * Royalties are paid for every item on an even split of the price
+     * Remaining accounts are the royalty creators of all items in item order followed by the items in the listed order,
+     * each of the NFT mint, the mint metadata, the escrow NFT token account,
+     * the buyer NFT token account and the mint Deny Entry PDA
+     *
+     * Params: by_token is true when purchasing with abb tokens
      */
-    pub fn cancel_auction(
-        ctx: Context<CancelAuction>,
-        global_bump: u8,
-        _auction_bump: u8,
+    pub fn purchase_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseBundle<'info>>,
+        by_token: u8,
     ) -> Result<()> {
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
-        msg!("Mint: {:?}", auction_data_info.mint);
+        // Assert the traders are not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.buyer.key(), &ctx.accounts.buyer_deny_entry),
+            (ctx.accounts.seller.key(), &ctx.accounts.seller_deny_entry),
+        ])?;
+        // By Token should be zero or one
+        require!(by_token < 2, MarketplaceError::InvalidParamInput);
+        let bundle_data_info = &mut ctx.accounts.bundle_data_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
 
-        let timestamp = Clock::get()?.unix_timestamp;
-        msg!("Cancel Date: {}", timestamp);
-        // Assert NFT Pubkey with Auction Data PDA Mint
-        require!(
-            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
+        msg!(
+            "Purchase Bundle: {:?}, By Token: {}",
+            ctx.accounts.bundle_mint.key(),
+            by_token == 1
         );
-        // Assert Auction End Date is passed
+
+        require_eq!(bundle_data_info.active, 1, MarketplaceError::NotListedNFT);
+        // Assert Buyer is the reserved buyer of a private bundle
+        assert_reserved_buyer(&bundle_data_info.reserved_buyer, &ctx.accounts.buyer.key())?;
+        // Assert Seller Bundle Data Address
         require!(
-            auction_data_info.end_date <= timestamp,
-            MarketplaceError::NotEndedAuction
+            ctx.accounts.seller.key().eq(&bundle_data_info.seller),
+            MarketplaceError::SellerAccountMismatch
         );
-        // Assert Already Ended Or Not Started Auction
-        require_eq!(auction_data_info.status, 1, MarketplaceError::NotListedNFT);
-        // Assert Auction Has No Bidder
+        // Assert Seller User PDA Address
         require!(
-            Pubkey::default().eq(&auction_data_info.last_bidder),
-            MarketplaceError::AuctionHasBid
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
         );
-        // Assert Creator Pubkey is same with the Auction Data Creator
+        // Assert Buyer User PDA Address
         require!(
-            ctx.accounts.creator.key().eq(&auction_data_info.creator),
-            MarketplaceError::CreatorAccountMismatch
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
         );
 
-        auction_data_info.status = 0;
+        // Remaining accounts are the royalty creators followed by the bundle items
+        let item_accounts = bundle_data_info.item_count as usize * BUNDLE_PURCHASE_ACCOUNT_COUNT;
+        require!(
+            ctx.remaining_accounts.len() >= item_accounts,
+            MarketplaceError::InvalidBundleAccounts
+        );
+        let (creators, items) = ctx
+            .remaining_accounts
+            .split_at(ctx.remaining_accounts.len() - item_accounts);
+
+        let mut royalties = vec![];
+        for (i, item) in items.chunks(BUNDLE_PURCHASE_ACCOUNT_COUNT).enumerate() {
+            let (nft_mint, mint_metadata) = (&item[0], &item[1]);
+            require_keys_eq!(
+                nft_mint.key(),
+                bundle_data_info.mints[i],
+                MarketplaceError::InvalidBundleAccounts
+            );
+            // Assert the NFT is not denylisted
+            assert_not_denied(&nft_mint.key(), &item[4])?;
+            let nft_metadata = load_metadata(&nft_mint.key(), mint_metadata)?;
+            royalties.push(Royalty::from_metadata(&nft_metadata));
+        }
 
-        let token_account_info = &mut &ctx.accounts.user_token_account;
-        let dest_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
-        let signer = &[&seeds[..]];
+        bundle_data_info.active = 0;
 
-        let cpi_accounts = Transfer {
-            from: dest_token_account_info.to_account_info().clone(),
-            to: token_account_info.to_account_info().clone(),
-            authority: ctx.accounts.global_authority.to_account_info().clone(),
+        let global_authority = &ctx.accounts.global_authority;
+        let price = if by_token == 1 {
+            bundle_data_info.price_token
+        } else {
+            bundle_data_info.price_sol
         };
-        token::transfer(
-            CpiContext::new_with_signer(
-                token_program.clone().to_account_info(),
-                cpi_accounts,
-                signer,
-            ),
-            1,
+        let fee_rate = market_fee_rate(
+            global_authority,
+            &bundle_data_info.collection,
+            &ctx.accounts.collection_fee,
+            buyer_user_pool,
+            seller_user_pool,
+            by_token == 1,
+        )?;
+        let mut referral = load_referral(
+            &ctx.accounts.referral_data,
+            &ctx.accounts.buyer.key(),
+            &ctx.accounts.seller.key(),
+        )?;
+        let payout = Payout::compute_bundle(
+            price,
+            fee_rate,
+            global_authority,
+            &ctx.accounts.treasury_config,
+            &royalties,
+            referral.is_some(),
+            bundle_data_info.buyer_pays_fee == 1,
+            (by_token == 1).then_some(&ctx.accounts.payment_mint),
         )?;
 
-        invoke_signed(
-            &spl_token::instruction::close_account(
-                token_program.key,
-                &dest_token_account_info.key(),
-                ctx.accounts.creator.key,
-                &ctx.accounts.global_authority.key(),
-                &[],
-            )?,
-            &[
-                token_program.clone().to_account_info(),
-                dest_token_account_info.to_account_info().clone(),
-                ctx.accounts.creator.to_account_info().clone(),
-                ctx.accounts.global_authority.to_account_info().clone(),
-            ],
-            signer,
+        settlement!(
+            ctx.accounts,
+            by_token == 1,
+            buyer,
+            user_token_account,
+            seller,
+            seller_token_account,
+            &[]
+        )
+        .execute(
+            &payout,
+            &mut ctx.accounts.treasury_config,
+            referral.as_mut(),
+            creators,
         )?;
 
-        // Validate PDA bump and seed
-        let (expected_global_address, expected_global_bump) = Pubkey::find_program_address(
-            &[GLOBAL_AUTHORITY_SEED.as_bytes()],
-            &astro_marketplace::ID,
+        record_volume(buyer_user_pool, seller_user_pool, by_token == 1, price)?;
+
+        for item in items.chunks(BUNDLE_PURCHASE_ACCOUNT_COUNT) {
+            let (nft_mint, dest_nft_token_account, user_nft_token_account) =
+                (&item[0], &item[2], &item[3]);
+            require!(
+                is_nft_token_account(
+                    dest_nft_token_account,
+                    &nft_mint.key(),
+                    &ctx.accounts.global_authority.key(),
+                    Some(1)
+                ) && is_nft_token_account(
+                    user_nft_token_account,
+                    &nft_mint.key(),
+                    ctx.accounts.buyer.key,
+                    None
+                ),
+                MarketplaceError::InvalidNftTokenAccount
+            );
+
+            release_nft(
+                &ctx.accounts.global_authority,
+                NftTransfer {
+                    token: dest_nft_token_account.clone(),
+                    token_owner: ctx.accounts.global_authority.to_account_info(),
+                    destination: user_nft_token_account.clone(),
+                    destination_owner: ctx.accounts.buyer.to_account_info(),
+                    mint: nft_mint.clone(),
+                    amount: 1,
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    token_program: ctx.accounts.nft_token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                ctx.accounts.buyer.to_account_info(),
+                None,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Close delisted or sold Bundle Data PDA to reclaim rent
+     *
+     * The rent is returned to the payer of the Bundle Data PDA
+     */
+    pub fn close_bundle_data(ctx: Context<CloseBundleData>) -> Result<()> {
+        let bundle_data_info = &ctx.accounts.bundle_data_info;
+        msg!("Bundle: {:?}", ctx.accounts.bundle_mint.key());
+
+        // Assert Payer is the Bundle Data Rent Payer
+        require_keys_eq!(
+            ctx.accounts.payer.key(),
+            bundle_data_info.payer,
+            MarketplaceError::RentPayerMismatch
+        );
+        // Assert the NFTs are not escrowed by the listing
+        require_eq!(
+            bundle_data_info.active,
+            0,
+            MarketplaceError::ActiveDataAccount
         );
+
+        Ok(())
+    }
+
+    /**
+     * Offer NFTs optionally with SOL or abb in exchange for a particular NFT
+     *
+     * Swap Offer Data PDA is created for the first swap offer of the buyer for the NFT
+     * The offered NFTs are escrowed in token accounts of the global authority
+     * The SOL or abb and the fee at the current rate are locked in the escrow vault
+     * as the fee payer is only known when the offer is accepted
+     * Remaining accounts are the offered items, each of the NFT mint, the buyer NFT token account,
+     * the escrow NFT token account of the global authority and the mint Deny Entry PDA
+     * Programmable and compressed NFTs can't be swapped
+     *
+     * Params:  price - SOL or abb paid with the offered NFTs, zero for NFTs only
+     *          by_token - paying by abb
+     */
+    pub fn make_swap_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, MakeSwapOffer<'info>>,
+        price: u64,
+        by_token: u64,
+    ) -> Result<()> {
+        // Assert the NFT and the trader is not denylisted
+        assert_none_denied(&[
+            (
+                ctx.accounts.target_mint.key(),
+                &ctx.accounts.target_deny_entry,
+            ),
+            (ctx.accounts.owner.key(), &ctx.accounts.owner_deny_entry),
+        ])?;
+        // By Token Param should be zero or one
+        require!(by_token < 2, MarketplaceError::InvalidParamInput);
+        let item_count = ctx.remaining_accounts.len() / SWAP_OFFER_ACCOUNT_COUNT;
         require!(
-            ctx.accounts.global_authority.key() == expected_global_address
-                && expected_global_bump == global_bump,
-            MarketplaceError::InvalidBump
+            ctx.remaining_accounts.len() == item_count * SWAP_OFFER_ACCOUNT_COUNT
+                && (1..=MAX_SWAP_ITEM_COUNT).contains(&item_count),
+            MarketplaceError::InvalidSwapItemCount
         );
-        // Validate PDA bump and seed
-        let (expected_auction_address, expected_auction_bump) = Pubkey::find_program_address(
-            &[
-                AUCTION_DATA_SEED.as_bytes(),
-                ctx.accounts.nft_mint.key().as_ref(),
-            ],
-            &astro_marketplace::ID,
+
+        let swap_offer_info = &mut ctx.accounts.swap_offer_info;
+        msg!(
+            "Mint: {:?}, buyer: {:?}",
+            ctx.accounts.target_mint.key(),
+            ctx.accounts.owner.key()
+        );
+        // Initialize newly created Swap Offer Data PDA
+        if swap_offer_info.target_mint.eq(&Pubkey::default()) {
+            swap_offer_info.target_mint = ctx.accounts.target_mint.key();
+            swap_offer_info.buyer = ctx.accounts.owner.key();
+            swap_offer_info.payer = ctx.accounts.owner.key();
+            swap_offer_info.bump = *ctx.bumps.get("swap_offer_info").unwrap();
+        }
+        // Assert the Swap Offer NFTs are not escrowed yet
+        require_eq!(
+            swap_offer_info.active,
+            0,
+            MarketplaceError::ActiveDataAccount
+        );
+
+        let global_authority = &ctx.accounts.global_authority;
+        // Swaps of NFTs without resolvable collection pay the global fee
+        let nft_metadata =
+            load_metadata(&ctx.accounts.target_mint.key(), &ctx.accounts.mint_metadata)?;
+        let collection = resolve_collection(
+            &nft_metadata,
+            &ctx.accounts.mint_metadata,
+            global_authority.creator_fallback,
+        )
+        .map(|(collection, _)| collection)
+        .unwrap_or_default();
+        let mut offered_mints = [Pubkey::default(); MAX_SWAP_ITEM_COUNT];
+        for (i, item) in ctx
+            .remaining_accounts
+            .chunks(SWAP_OFFER_ACCOUNT_COUNT)
+            .enumerate()
+        {
+            let (nft_mint, user_token_account, dest_nft_token_account) =
+                (&item[0], &item[1], &item[2]);
+            // Assert the offered NFT is not denylisted
+            assert_not_denied(&nft_mint.key(), &item[3])?;
+            // Assert every item is offered once and is not the target NFT
+            require!(
+                !nft_mint.key().eq(&ctx.accounts.target_mint.key())
+                    && !offered_mints[..i].contains(&nft_mint.key()),
+                MarketplaceError::InvalidSwapItemAccounts
+            );
+            require_keys_eq!(
+                *nft_mint.owner,
+                ctx.accounts.nft_token_program.key(),
+                MarketplaceError::InvalidTokenProgram
+            );
+            require!(
+                is_nft_token_account(
+                    user_token_account,
+                    &nft_mint.key(),
+                    ctx.accounts.owner.key,
+                    Some(1)
+                ) && is_nft_token_account(
+                    dest_nft_token_account,
+                    &nft_mint.key(),
+                    &global_authority.key(),
+                    None
+                ),
+                MarketplaceError::InvalidNftTokenAccount
+            );
+            offered_mints[i] = nft_mint.key();
+
+            // Assert the NFT has no Token-2022 transfer fee
+            assert_no_transfer_fee(nft_mint)?;
+            NftTransfer {
+                token: user_token_account.clone(),
+                token_owner: ctx.accounts.owner.to_account_info(),
+                destination: dest_nft_token_account.clone(),
+                destination_owner: global_authority.to_account_info(),
+                mint: nft_mint.clone(),
+                amount: 1,
+                payer: ctx.accounts.owner.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            }
+            .execute(None, &[])?;
+        }
+
+        swap_offer_info.offered_mints = offered_mints;
+        swap_offer_info.item_count = item_count as u64;
+        swap_offer_info.price = price;
+        swap_offer_info.by_token = by_token;
+        swap_offer_info.active = 1;
+        swap_offer_info.collection = collection;
+        swap_offer_info.fee_rate = base_fee_rate(
+            global_authority,
+            &collection,
+            &ctx.accounts.collection_fee,
+            by_token == 1,
+        )?;
+        let deposit = price.safe_add(price.permyriad(swap_offer_info.fee_rate)?)?;
+        swap_offer_info.deposit = deposit;
+        if deposit == 0 {
+            return Ok(());
+        }
+
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "User: {:?}, Deposit: {}, By Token: {}",
+            user_pool.address,
+            deposit,
+            by_token
+        );
+        // Assert User Pubkey with User Data PDA Address
+        require!(
+            ctx.accounts.owner.key().eq(&user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+
+        if by_token == 0 {
+            invoke(
+                &system_instruction::transfer(
+                    ctx.accounts.owner.key,
+                    ctx.accounts.escrow_vault.key,
+                    deposit,
+                ),
+                &[
+                    ctx.accounts.owner.to_account_info().clone(),
+                    ctx.accounts.escrow_vault.to_account_info().clone(),
+                    ctx.accounts.system_program.to_account_info().clone(),
+                ],
+            )?;
+        } else {
+            // Deposit with the Token-2022 transfer fee on top
+            transfer_checked(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.user_token_account.to_account_info(),
+                &ctx.accounts.payment_mint,
+                &ctx.accounts.escrow_token_account.to_account_info(),
+                &ctx.accounts.owner.to_account_info(),
+                gross_transfer_amount(&ctx.accounts.payment_mint, deposit)?,
+                &[],
+            )?;
+        }
+        Ok(())
+    }
+
+    /**
+     * Cancel swap offer and return the offered NFTs
+     *
+     * The locked SOL or abb is unlocked to the escrow balance of the buyer to be withdrawn
+     * Remaining accounts are the offered items in the offered order,
+     * each of the NFT mint, the escrow NFT token account and the buyer NFT token account
+     */
+    pub fn cancel_swap_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelSwapOffer<'info>>,
+    ) -> Result<()> {
+        let swap_offer_info = &mut ctx.accounts.swap_offer_info;
+        msg!(
+            "Mint: {:?}, buyer: {:?}",
+            swap_offer_info.target_mint,
+            ctx.accounts.owner.key()
+        );
+
+        // Asser Payer is the Swap Offer Data Buyer
+        require!(
+            ctx.accounts.owner.key().eq(&swap_offer_info.buyer),
+            MarketplaceError::InvalidOfferDataBuyer
+        );
+        require_eq!(swap_offer_info.active, 1, MarketplaceError::DisabledOffer);
+
+        swap_offer_info.active = 0;
+        ctx.accounts
+            .user_pool
+            .credit_escrow(swap_offer_info.by_token == 1, swap_offer_info.deposit)?;
+        swap_offer_info.deposit = 0;
+
+        release_swap_items(
+            &ctx.accounts.global_authority,
+            swap_offer_info,
+            ctx.remaining_accounts,
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.nft_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )
+    }
+
+    /**
+     * Exchange the NFT for the offered NFTs and the offered SOL or abb
+     *
+     * Marketplace fee and the royalty of the target NFT are charged on the SOL or abb portion
+     * The fee payer is the one of the seller's active listing of the NFT, the global default otherwise
+     * The NFT of the active listing is released from the listing escrow
+     * Remaining accounts are the royalty creators of the target NFT, the pNFT transfer accounts of the target NFT
+     * and the offered items in the offered order,
+     * each of the NFT mint, the escrow NFT token account and the seller NFT token account
+     * The royalty creators are omitted for swap offers without SOL or abb
+     * The pNFT transfer accounts are omitted for non-programmable target NFTs
+     */
+    pub fn accept_swap_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptSwapOffer<'info>>,
+    ) -> Result<()> {
+        // Assert the NFT and the traders are not denylisted
+        assert_none_denied(&[
+            (
+                ctx.accounts.target_mint.key(),
+                &ctx.accounts.target_deny_entry,
+            ),
+            (ctx.accounts.buyer.key(), &ctx.accounts.buyer_deny_entry),
+            (ctx.accounts.seller.key(), &ctx.accounts.seller_deny_entry),
+        ])?;
+        let swap_offer_info = &mut ctx.accounts.swap_offer_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+        // Assert Buyer User PDA Address
+        require!(
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Seller User PDA Address
+        require!(
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Buyer Pubkey with Swap Offer Data PDA Buyer Address
+        require!(
+            ctx.accounts.buyer.key().eq(&swap_offer_info.buyer),
+            MarketplaceError::InvalidOfferDataBuyer
+        );
+        // Assert Already Disabled Offer
+        require_eq!(swap_offer_info.active, 1, MarketplaceError::DisabledOffer);
+
+        msg!(
+            "Swap Mint: {:?}, Seller: {:?}, Buyer: {:?}, Items: {}, Price: {}, ByToken: {}",
+            swap_offer_info.target_mint,
+            ctx.accounts.seller.key(),
+            swap_offer_info.buyer,
+            swap_offer_info.item_count,
+            swap_offer_info.price,
+            swap_offer_info.by_token,
+        );
+
+        swap_offer_info.active = 0;
+
+        // The seller's active listing of the NFT sets the fee payer
+        let mut listing = load_sell_data(
+            &ctx.accounts.target_mint.key(),
+            &ctx.accounts.seller.key(),
+            &ctx.accounts.sell_data_info,
+        )?
+        .filter(|listing| listing.active == 1);
+        // Assert Buyer is the reserved buyer of a private listing of the target NFT
+        if let Some(listing) = &listing {
+            assert_reserved_buyer(&listing.reserved_buyer, &ctx.accounts.buyer.key())?;
+        }
+        let buyer_pays_fee = match &listing {
+            Some(listing) => listing.buyer_pays_fee,
+            None => ctx.accounts.global_authority.buyer_pays_fee,
+        };
+
+        let item_accounts = swap_offer_info.item_count as usize * SWAP_RELEASE_ACCOUNT_COUNT;
+        require!(
+            ctx.remaining_accounts.len() >= item_accounts,
+            MarketplaceError::InvalidSwapItemAccounts
+        );
+        let (creators_and_pnft, items) = ctx
+            .remaining_accounts
+            .split_at(ctx.remaining_accounts.len() - item_accounts);
+
+        let price = swap_offer_info.price;
+        let by_token = swap_offer_info.by_token == 1;
+        let mut creator_count = 0;
+        if price > 0 {
+            let global_authority = &ctx.accounts.global_authority;
+            let nft_metadata =
+                load_metadata(&ctx.accounts.target_mint.key(), &ctx.accounts.mint_metadata)?;
+            let fee_rate = market_fee_rate(
+                global_authority,
+                &swap_offer_info.collection,
+                &ctx.accounts.collection_fee,
+                buyer_user_pool,
+                seller_user_pool,
+                by_token,
+            )?;
+            // The buyer paying the fee is never charged more than the fee escrowed with the offer
+            let fee_rate = if buyer_pays_fee == 1 {
+                fee_rate.min(swap_offer_info.fee_rate)
+            } else {
+                fee_rate
+            };
+            let mut referral = load_referral(
+                &ctx.accounts.referral_data,
+                &ctx.accounts.buyer.key(),
+                &ctx.accounts.seller.key(),
+            )?;
+            let payout = Payout::compute(
+                price,
+                fee_rate,
+                global_authority,
+                &ctx.accounts.treasury_config,
+                &Royalty::from_metadata(&nft_metadata),
+                referral.is_some(),
+                buyer_pays_fee == 1,
+                by_token.then_some(&ctx.accounts.payment_mint),
+            )?;
+
+            // Buyer pays the price and the fee on top of it from the offer deposit
+            // and the rest of the deposit is unlocked to the buyer's escrow balance
+            buyer_user_pool
+                .credit_escrow(by_token, swap_offer_info.deposit.safe_sub(payout.total)?)?;
+            creator_count = payout.royalties.len().min(creators_and_pnft.len());
+
+            let seeds = &[
+                ESCROW_VAULT_SEED.as_bytes(),
+                &[global_authority.escrow_bump],
+            ];
+            settlement!(
+                ctx.accounts,
+                by_token,
+                escrow_vault,
+                escrow_token_account,
+                seller,
+                user_token_account,
+                &[&seeds[..]]
+            )
+            .execute(
+                &payout,
+                &mut ctx.accounts.treasury_config,
+                referral.as_mut(),
+                &creators_and_pnft[..creator_count],
+            )?;
+
+            record_volume(buyer_user_pool, seller_user_pool, by_token, price)?;
+        }
+        swap_offer_info.deposit = 0;
+        let pnft = PnftAccounts::load(&creators_and_pnft[creator_count..])?;
+
+        // Assert the target NFT has no Token-2022 transfer fee
+        assert_no_transfer_fee(&ctx.accounts.target_mint)?;
+        if let Some(listing) = listing.as_mut() {
+            // Release one unit of the listing from the escrow
+            require!(
+                is_nft_token_account(
+                    &ctx.accounts.seller_nft_token_account,
+                    &ctx.accounts.target_mint.key(),
+                    &ctx.accounts.global_authority.key(),
+                    Some(1)
+                ),
+                MarketplaceError::InvalidNftTokenAccount
+            );
+            listing.quantity = listing.quantity.safe_sub(1)?;
+            if listing.quantity == 0 {
+                listing.active = 0;
+            }
+            listing.exit(&crate::ID)?;
+            release_nft(
+                &ctx.accounts.global_authority,
+                NftTransfer {
+                    token: ctx.accounts.seller_nft_token_account.to_account_info(),
+                    token_owner: ctx.accounts.global_authority.to_account_info(),
+                    destination: ctx.accounts.buyer_nft_token_account.to_account_info(),
+                    destination_owner: ctx.accounts.buyer.to_account_info(),
+                    mint: ctx.accounts.target_mint.to_account_info(),
+                    amount: 1,
+                    payer: ctx.accounts.seller.to_account_info(),
+                    token_program: ctx.accounts.target_token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                ctx.accounts.seller.to_account_info(),
+                pnft.as_ref(),
+            )?;
+        } else {
+            require!(
+                is_nft_token_account(
+                    &ctx.accounts.seller_nft_token_account,
+                    &ctx.accounts.target_mint.key(),
+                    ctx.accounts.seller.key,
+                    Some(1)
+                ),
+                MarketplaceError::InvalidNftTokenAccount
+            );
+            NftTransfer {
+                token: ctx.accounts.seller_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.seller.to_account_info(),
+                destination: ctx.accounts.buyer_nft_token_account.to_account_info(),
+                destination_owner: ctx.accounts.buyer.to_account_info(),
+                mint: ctx.accounts.target_mint.to_account_info(),
+                amount: 1,
+                payer: ctx.accounts.seller.to_account_info(),
+                token_program: ctx.accounts.target_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            }
+            .execute(pnft.as_ref(), &[])?;
+        }
+
+        release_swap_items(
+            &ctx.accounts.global_authority,
+            swap_offer_info,
+            items,
+            ctx.accounts.seller.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.nft_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )
+    }
+
+    /**
+     * Close canceled or accepted Swap Offer Data PDA to reclaim rent
+     *
+     * The rent is returned to the payer of the Swap Offer Data PDA
+     */
+    pub fn close_swap_offer_data(ctx: Context<CloseSwapOfferData>) -> Result<()> {
+        let swap_offer_info = &ctx.accounts.swap_offer_info;
+        msg!(
+            "Mint: {:?}, buyer: {:?}",
+            swap_offer_info.target_mint,
+            swap_offer_info.buyer
+        );
+
+        // Assert Payer is the Swap Offer Data Rent Payer
+        require_keys_eq!(
+            ctx.accounts.payer.key(),
+            swap_offer_info.payer,
+            MarketplaceError::RentPayerMismatch
+        );
+        // Assert the offered NFTs are not escrowed
+        require_eq!(
+            swap_offer_info.active,
+            0,
+            MarketplaceError::ActiveDataAccount
+        );
+
+        Ok(())
+    }
+
+    /**
+     * Start an auction for NFT
+     *
+     * Auction Data PDA is created for the first auction of the NFT
+     *
+     * Params:  start_price - Bidding start price
+     *          min_increase - Minimum inscrease amount of next bidding price
+     *          by_token - auction selling by abb
+     *          end_date - auction end date
+     *          fee_mode - marketplace fee payer, 0: global default, 1: seller, 2: buyer
+     */
+    pub fn create_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAuction<'info>>,
+        start_price: u64,
+        min_increase: u64,
+        by_token: u64,
+        end_date: i64,
+        fee_mode: u8,
+    ) -> Result<()> {
+        // Assert the NFT and the trader is not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.nft_mint.key(), &ctx.accounts.mint_deny_entry),
+            (ctx.accounts.owner.key(), &ctx.accounts.owner_deny_entry),
+        ])?;
+        // validate min_increase, end_date
+        require!(
+            min_increase > 0,
+            MarketplaceError::InvalidMinimumIncreaseValue
+        );
+        require!(
+            end_date > Clock::get()?.unix_timestamp,
+            MarketplaceError::InvalidEndDate
+        );
+
+        // By Token Param should be zero or one
+        require!(by_token < 2, MarketplaceError::InvalidParamInput);
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        msg!("Mint: {:?}", ctx.accounts.nft_mint.key());
+
+        // Initialize newly created Auction Data PDA
+        if auction_data_info.mint.eq(&Pubkey::default()) {
+            auction_data_info.mint = ctx.accounts.nft_mint.key();
+            auction_data_info.payer = ctx.accounts.owner.key();
+            auction_data_info.bump = *ctx.bumps.get("auction_data_info").unwrap();
+        }
+        // Assert NFT Pubkey with Auction Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert Already Live Auction
+        require!(
+            auction_data_info.status != 1,
+            MarketplaceError::AlreadyLiveAuction
+        );
+
+        let global_authority = &ctx.accounts.global_authority;
+        let nft_metadata =
+            load_metadata(&ctx.accounts.nft_mint.key(), &ctx.accounts.mint_metadata)?;
+        let collection = resolve_collection(
+            &nft_metadata,
+            &ctx.accounts.mint_metadata,
+            global_authority.creator_fallback,
+        );
+        if global_authority.restricted_listing == 1 {
+            let (collection, collection_method) = collection?;
+            assert_registered_collection(
+                &collection,
+                collection_method,
+                &ctx.accounts.collection_info,
+            )?;
+            auction_data_info.collection = collection;
+        } else {
+            // Auctions of NFTs without resolvable collection pay the global fee
+            auction_data_info.collection = collection
+                .map(|(collection, _)| collection)
+                .unwrap_or_default();
+        }
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Created Date: {}", timestamp);
+
+        auction_data_info.creator = ctx.accounts.owner.key();
+        auction_data_info.start_price = start_price;
+        auction_data_info.min_increase_amount = min_increase;
+        auction_data_info.by_token = by_token;
+        auction_data_info.end_date = end_date;
+        auction_data_info.last_bidder = Pubkey::default();
+        auction_data_info.highest_bid = start_price;
+        auction_data_info.bid_by_escrow = 0;
+        auction_data_info.status = 1;
+        auction_data_info.cancel_reason = 0;
+        auction_data_info.buyer_pays_fee =
+            resolve_buyer_pays_fee(&ctx.accounts.global_authority, fee_mode)?;
+        auction_data_info.bid_fee_deposit = 0;
+        auction_data_info.bid_fee_rate = 0;
+
+        // Assert the NFT has no Token-2022 transfer fee
+        assert_no_transfer_fee(&ctx.accounts.nft_mint)?;
+        let pnft = PnftAccounts::load(ctx.remaining_accounts)?;
+        NftTransfer {
+            token: ctx.accounts.user_token_account.to_account_info(),
+            token_owner: ctx.accounts.owner.to_account_info(),
+            destination: ctx.accounts.dest_nft_token_account.to_account_info(),
+            destination_owner: ctx.accounts.global_authority.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            amount: 1,
+            payer: ctx.accounts.owner.to_account_info(),
+            token_program: ctx.accounts.nft_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        }
+        .execute(pnft.as_ref(), &[])?;
+
+        Ok(())
+    }
+
+    /**
+     * Place a bid for an auction
+     *
+     * The out-bidder is refunded the way the out-bid was funded,
+     * credited back to the escrow balance or refunded to the wallet
+     *
+     * Params:  price - bidding price
+     *          by_escrow - bid from the bidder's escrow balance
+     */
+    pub fn place_bid(ctx: Context<PlaceBid>, price: u64, by_escrow: u8) -> Result<()> {
+        // Assert the NFT and the trader is not denylisted
+        assert_none_denied(&[
+            (ctx.accounts.nft_mint.key(), &ctx.accounts.mint_deny_entry),
+            (ctx.accounts.bidder.key(), &ctx.accounts.bidder_deny_entry),
+        ])?;
+        // By Escrow should be zero or one
+        require!(by_escrow < 2, MarketplaceError::InvalidParamInput);
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Place Date: {}", timestamp);
+        // Assert NFT Pubkey with Auction Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
         );
+        // Assert Auction Already Ended
         require!(
-            auction_data_info.key() == expected_auction_address
-                && expected_auction_bump == _auction_bump,
-            MarketplaceError::InvalidBump
+            auction_data_info.end_date > timestamp,
+            MarketplaceError::EndedAuction
         );
+        // Assert Already Disabled Auction
+        require_eq!(auction_data_info.status, 1, MarketplaceError::NotListedNFT);
+        // New Bid should be increased more than min_increase_amount
+        require!(
+            auction_data_info
+                .highest_bid
+                .safe_add(auction_data_info.min_increase_amount)?
+                <= price,
+            MarketplaceError::InvalidBidPrice
+        );
+        // Assert OutBidder Address with the Last Bidder
+        require!(
+            Pubkey::default().eq(&auction_data_info.last_bidder)
+                || ctx
+                    .accounts
+                    .out_bidder
+                    .key()
+                    .eq(&auction_data_info.last_bidder),
+            MarketplaceError::OutBidderMismatch
+        );
+        // Assert New Bidder is same with the Last Bidder
+        require!(
+            !ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
+            MarketplaceError::DoubleBidFromOneBidder
+        );
+        // Assert Bid from Auction Creator
+        require!(
+            !ctx.accounts.bidder.key().eq(&auction_data_info.creator),
+            MarketplaceError::BidFromAuctionCreator
+        );
+
+        // The bidder User PDA is only needed to bid from the escrow balance
+        let mut bidder_user_pool = if by_escrow == 1 {
+            let user_pool =
+                load_user_pool(&ctx.accounts.bidder.key(), &ctx.accounts.bidder_user_pool)?;
+            Some(user_pool.ok_or(MarketplaceError::InvalidUserPoolAccount)?)
+        } else {
+            None
+        };
+
+        msg!(
+            "Mint: {:?}, Bidder: {:?}, By Escrow: {}",
+            auction_data_info.mint,
+            ctx.accounts.bidder.key(),
+            by_escrow == 1
+        );
+
+        let user_token_account_info = &mut &ctx.accounts.bidder_token_account;
+        let vault_token_account_info = &mut &ctx.accounts.escrow_token_account;
+        let token_program = &mut &ctx.accounts.token_program;
+
+        // The first bid has no out-bidder and the out-bidder accounts are not used
+        // Otherwise the out-bidder is the last bidder which can't be the bidder,
+        // so the out-bidder User PDA never aliases the bidder User PDA
+        if !Pubkey::default().eq(&auction_data_info.last_bidder) {
+            let refund = auction_data_info
+                .highest_bid
+                .safe_add(auction_data_info.bid_fee_deposit)?;
+            if auction_data_info.bid_by_escrow == 1 {
+                // Refund Last Bidder to the Escrow Balance
+                // The funds are already in the escrow vault so only the ledger is updated
+                let mut out_bidder_user_pool = load_user_pool(
+                    &ctx.accounts.out_bidder.key(),
+                    &ctx.accounts.out_bidder_user_pool,
+                )?
+                .ok_or(MarketplaceError::InvalidUserPoolAccount)?;
+                out_bidder_user_pool.credit_escrow(auction_data_info.by_token == 1, refund)?;
+                out_bidder_user_pool.exit(&crate::ID)?;
+            } else {
+                // Refund Last Bidder to the wallet
+                let seeds = &[
+                    ESCROW_VAULT_SEED.as_bytes(),
+                    &[ctx.accounts.global_authority.escrow_bump],
+                ];
+                let signer = &[&seeds[..]];
+                if auction_data_info.by_token == 0 {
+                    invoke_signed(
+                        &system_instruction::transfer(
+                            ctx.accounts.escrow_vault.key,
+                            ctx.accounts.out_bidder.key,
+                            refund,
+                        ),
+                        &[
+                            ctx.accounts.escrow_vault.to_account_info().clone(),
+                            ctx.accounts.out_bidder.to_account_info().clone(),
+                            ctx.accounts.system_program.to_account_info().clone(),
+                        ],
+                        signer,
+                    )?;
+                } else {
+                    require!(
+                        is_payment_token_account(
+                            &ctx.accounts.out_bidder_token_account,
+                            ctx.accounts.out_bidder.key
+                        ),
+                        MarketplaceError::InvalidOwner
+                    );
+                    transfer_checked(
+                        &token_program.to_account_info(),
+                        &vault_token_account_info.to_account_info(),
+                        &ctx.accounts.payment_mint,
+                        &ctx.accounts.out_bidder_token_account,
+                        &ctx.accounts.escrow_vault.to_account_info(),
+                        refund,
+                        signer,
+                    )?;
+                }
+            }
+        }
+
+        // Escrow the fee at the current rate with the bid when the buyer pays the fee
+        let fee_rate = if auction_data_info.buyer_pays_fee == 1 {
+            base_fee_rate(
+                &ctx.accounts.global_authority,
+                &auction_data_info.collection,
+                &ctx.accounts.collection_fee,
+                auction_data_info.by_token == 1,
+            )?
+        } else {
+            0
+        };
+        let fee_deposit = price.permyriad(fee_rate)?;
+        let amount = price.safe_add(fee_deposit)?;
+
+        if let Some(bidder_user_pool) = bidder_user_pool.as_mut() {
+            // Escrow New Bidder funds from his Escrow Balance
+            if auction_data_info.by_token == 0 {
+                require!(
+                    amount <= bidder_user_pool.escrow_sol_balance,
+                    MarketplaceError::InsufficientBidderSolBalance
+                );
+                bidder_user_pool.escrow_sol_balance =
+                    bidder_user_pool.escrow_sol_balance.safe_sub(amount)?;
+            } else {
+                require!(
+                    amount <= bidder_user_pool.escrow_token_balance,
+                    MarketplaceError::InsufficientBidderTokenBalance
+                );
+                bidder_user_pool.escrow_token_balance =
+                    bidder_user_pool.escrow_token_balance.safe_sub(amount)?;
+            }
+        } else if auction_data_info.by_token == 0 {
+            // Escrow New Bidder funds
+            invoke(
+                &system_instruction::transfer(
+                    ctx.accounts.bidder.key,
+                    ctx.accounts.escrow_vault.key,
+                    amount,
+                ),
+                &[
+                    ctx.accounts.bidder.to_account_info().clone(),
+                    ctx.accounts.escrow_vault.to_account_info().clone(),
+                    ctx.accounts.system_program.to_account_info().clone(),
+                ],
+            )?;
+        } else {
+            // Escrow New Bidder funds with the Token-2022 transfer fee on top
+            transfer_checked(
+                &token_program.to_account_info(),
+                &user_token_account_info.to_account_info(),
+                &ctx.accounts.payment_mint,
+                &vault_token_account_info.to_account_info(),
+                &ctx.accounts.bidder.to_account_info(),
+                gross_transfer_amount(&ctx.accounts.payment_mint, amount)?,
+                &[],
+            )?;
+        }
+
+        if let Some(bidder_user_pool) = bidder_user_pool {
+            bidder_user_pool.exit(&crate::ID)?;
+        }
+
+        auction_data_info.last_bid_date = timestamp;
+        auction_data_info.last_bidder = ctx.accounts.bidder.key();
+        auction_data_info.highest_bid = price;
+        auction_data_info.bid_by_escrow = by_escrow as u64;
+        auction_data_info.bid_fee_deposit = fee_deposit;
+        auction_data_info.bid_fee_rate = fee_rate;
+
+        Ok(())
+    }
+
+    /**
+     * Claim NFT for won auction
+     */
+    pub fn claim_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAuction<'info>>,
+    ) -> Result<()> {
+        // Assert the NFT and the traders are not denylisted
+        // The super admin force cancels the auctions of denylisted NFTs or traders
+        assert_none_denied(&[
+            (ctx.accounts.nft_mint.key(), &ctx.accounts.mint_deny_entry),
+            (ctx.accounts.bidder.key(), &ctx.accounts.bidder_deny_entry),
+            (ctx.accounts.creator.key(), &ctx.accounts.creator_deny_entry),
+        ])?;
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        msg!("Mint: {:?}", auction_data_info.mint);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Claim Date: {}", timestamp);
+        // Assert NFT Pubkey with Auction Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert Auction End Date is Passed
+        require!(
+            auction_data_info.end_date <= timestamp,
+            MarketplaceError::NotEndedAuction
+        );
+        // Assert Already Ended or Not Started Auction
+        require_eq!(auction_data_info.status, 1, MarketplaceError::NotListedNFT);
+        // Assert Creator Pubkey with Auction Data Creator Address
+        require!(
+            ctx.accounts.creator.key().eq(&auction_data_info.creator),
+            MarketplaceError::CreatorAccountMismatch
+        );
+        // Assert Bidder Pubkey with Auction Data Last Bidder Address
+        require!(
+            ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
+            MarketplaceError::BidderAccountMismatch
+        );
+
+        // The traders may have no User PDA when the bid was funded from the wallet
+        let mut bidder_user_pool =
+            load_user_pool(&ctx.accounts.bidder.key(), &ctx.accounts.bidder_user_pool)?;
+        let mut creator_user_pool =
+            load_user_pool(&ctx.accounts.creator.key(), &ctx.accounts.creator_user_pool)?;
+        // Traders without User PDA have no traded volume to discount or record
+        let mut no_bidder_user_pool = UserData::default();
+        let mut no_creator_user_pool = UserData::default();
+
+        // Set Flag as Claimed Auction
+        auction_data_info.status = 2;
+
+        let price = auction_data_info.highest_bid;
+        let by_token = auction_data_info.by_token == 1;
+
+        let global_authority = &ctx.accounts.global_authority;
+        let nft_metadata =
+            load_metadata(&ctx.accounts.nft_mint.key(), &ctx.accounts.mint_metadata)?;
+        let fee_rate = market_fee_rate(
+            global_authority,
+            &auction_data_info.collection,
+            &ctx.accounts.collection_fee,
+            bidder_user_pool.as_deref().unwrap_or(&no_bidder_user_pool),
+            creator_user_pool.as_deref().unwrap_or(&no_creator_user_pool),
+            by_token,
+        )?;
+        // The bidder paying the fee is never charged more than the fee escrowed with the bid
+        let fee_rate = if auction_data_info.buyer_pays_fee == 1 {
+            fee_rate.min(auction_data_info.bid_fee_rate)
+        } else {
+            fee_rate
+        };
+        let mut referral = load_referral(
+            &ctx.accounts.referral_data,
+            &ctx.accounts.bidder.key(),
+            &ctx.accounts.creator.key(),
+        )?;
+        let payout = Payout::compute(
+            price,
+            fee_rate,
+            global_authority,
+            &ctx.accounts.treasury_config,
+            &Royalty::from_metadata(&nft_metadata),
+            referral.is_some(),
+            auction_data_info.buyer_pays_fee == 1,
+            by_token.then_some(&ctx.accounts.payment_mint),
+        )?;
+
+        let seeds = &[
+            ESCROW_VAULT_SEED.as_bytes(),
+            &[global_authority.escrow_bump],
+        ];
+
+        // Bidder pays from the escrowed bid and fee deposit
+        // and the rest of the fee deposit is refunded the way the bid was funded
+        let escrowed = price.safe_add(auction_data_info.bid_fee_deposit)?;
+        let surplus = escrowed.safe_sub(payout.total)?;
+        if auction_data_info.bid_by_escrow == 1 {
+            bidder_user_pool
+                .as_deref_mut()
+                .ok_or(MarketplaceError::InvalidUserPoolAccount)?
+                .credit_escrow(by_token, surplus)?;
+        } else if surplus > 0 && by_token {
+            require!(
+                is_payment_token_account(
+                    &ctx.accounts.bidder_token_account,
+                    ctx.accounts.bidder.key
+                ),
+                MarketplaceError::InvalidOwner
+            );
+            transfer_checked(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow_token_account,
+                &ctx.accounts.payment_mint,
+                &ctx.accounts.bidder_token_account,
+                &ctx.accounts.escrow_vault,
+                surplus,
+                &[&seeds[..]],
+            )?;
+        } else if surplus > 0 {
+            invoke_signed(
+                &system_instruction::transfer(
+                    ctx.accounts.escrow_vault.key,
+                    ctx.accounts.bidder.key,
+                    surplus,
+                ),
+                &[
+                    ctx.accounts.escrow_vault.to_account_info().clone(),
+                    ctx.accounts.bidder.to_account_info().clone(),
+                    ctx.accounts.system_program.to_account_info().clone(),
+                ],
+                &[&seeds[..]],
+            )?;
+        }
+
+        // Remaining accounts are the royalty creators followed by the pNFT transfer accounts
+        let creator_count = payout.royalties.len().min(ctx.remaining_accounts.len());
+        let (creators, pnft_accounts) = ctx.remaining_accounts.split_at(creator_count);
+        let pnft = PnftAccounts::load(pnft_accounts)?;
+
+        settlement!(
+            ctx.accounts,
+            by_token,
+            escrow_vault,
+            escrow_token_account,
+            creator,
+            creator_token_account,
+            &[&seeds[..]]
+        )
+        .execute(
+            &payout,
+            &mut ctx.accounts.treasury_config,
+            referral.as_mut(),
+            creators,
+        )?;
+
+        record_volume(
+            bidder_user_pool
+                .as_deref_mut()
+                .unwrap_or(&mut no_bidder_user_pool),
+            creator_user_pool
+                .as_deref_mut()
+                .unwrap_or(&mut no_creator_user_pool),
+            by_token,
+            price,
+        )?;
+        if let Some(bidder_user_pool) = bidder_user_pool {
+            bidder_user_pool.exit(&crate::ID)?;
+        }
+        if let Some(creator_user_pool) = creator_user_pool {
+            creator_user_pool.exit(&crate::ID)?;
+        }
+
+        release_nft(
+            &ctx.accounts.global_authority,
+            NftTransfer {
+                token: ctx.accounts.dest_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.global_authority.to_account_info(),
+                destination: ctx.accounts.user_token_account.to_account_info(),
+                destination_owner: ctx.accounts.bidder.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                amount: 1,
+                payer: ctx.accounts.bidder.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            ctx.accounts.bidder.to_account_info(),
+            pnft.as_ref(),
+        )?;
+
+        Ok(())
+    }
+
+    /**
+     * Cancel auction has no bid until end date
+     */
+    pub fn cancel_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelAuction<'info>>,
+    ) -> Result<()> {
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        msg!("Mint: {:?}", auction_data_info.mint);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Cancel Date: {}", timestamp);
+        // Assert NFT Pubkey with Auction Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert Auction End Date is passed
+        require!(
+            auction_data_info.end_date <= timestamp,
+            MarketplaceError::NotEndedAuction
+        );
+        // Assert Already Ended Or Not Started Auction
+        require_eq!(auction_data_info.status, 1, MarketplaceError::NotListedNFT);
+        // Assert Auction Has No Bidder
+        require!(
+            Pubkey::default().eq(&auction_data_info.last_bidder),
+            MarketplaceError::AuctionHasBid
+        );
+        // Assert Creator Pubkey is same with the Auction Data Creator
+        require!(
+            ctx.accounts.creator.key().eq(&auction_data_info.creator),
+            MarketplaceError::CreatorAccountMismatch
+        );
+
+        auction_data_info.status = 3;
+
+        let pnft = PnftAccounts::load(ctx.remaining_accounts)?;
+        release_nft(
+            &ctx.accounts.global_authority,
+            NftTransfer {
+                token: ctx.accounts.dest_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.global_authority.to_account_info(),
+                destination: ctx.accounts.user_token_account.to_account_info(),
+                destination_owner: ctx.accounts.creator.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                amount: 1,
+                payer: ctx.accounts.creator.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            ctx.accounts.creator.to_account_info(),
+            pnft.as_ref(),
+        )?;
+
+        Ok(())
+    }
+
+    /**
+     * Emergency cancel auction as super_admin even if the auction has bid
+     *
+     * Refund the last bidder from the escrow vault and return the NFT to the creator
+     *
+     * Params: reason - non zero reason code recorded on the auction data
+     */
+    pub fn force_cancel_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ForceCancelAuction<'info>>,
+        reason: u64,
+    ) -> Result<()> {
+        require!(reason > 0, MarketplaceError::NonZeroError);
+
+        let global_authority = &ctx.accounts.global_authority;
+        // Assert payer is the superadmin
+        require_keys_eq!(
+            global_authority.super_admin,
+            ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        msg!("Mint: {:?}, Reason: {}", auction_data_info.mint, reason);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Force Cancel Date: {}", timestamp);
+        // Assert NFT Pubkey with Auction Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert Already Ended Or Not Started Auction
+        require_eq!(auction_data_info.status, 1, MarketplaceError::NotListedNFT);
+        // Assert Creator Pubkey is same with the Auction Data Creator
+        require!(
+            ctx.accounts.creator.key().eq(&auction_data_info.creator),
+            MarketplaceError::CreatorAccountMismatch
+        );
+        // Assert Bidder Pubkey with Auction Data Last Bidder Address
+        require!(
+            Pubkey::default().eq(&auction_data_info.last_bidder)
+                || ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
+            MarketplaceError::BidderAccountMismatch
+        );
+
+        auction_data_info.status = 3;
+        auction_data_info.cancel_reason = reason;
+
+        let token_program = &mut &ctx.accounts.token_program;
+
+        // Refund Last Bidder Escrow
+        if !Pubkey::default().eq(&auction_data_info.last_bidder) {
+            let refund = auction_data_info
+                .highest_bid
+                .safe_add(auction_data_info.bid_fee_deposit)?;
+            let seeds = &[
+                ESCROW_VAULT_SEED.as_bytes(),
+                &[ctx.accounts.global_authority.escrow_bump],
+            ];
+            let signer = &[&seeds[..]];
+
+            if auction_data_info.by_token == 0 {
+                invoke_signed(
+                    &system_instruction::transfer(
+                        ctx.accounts.escrow_vault.key,
+                        ctx.accounts.bidder.key,
+                        refund,
+                    ),
+                    &[
+                        ctx.accounts.bidder.to_account_info().clone(),
+                        ctx.accounts.escrow_vault.to_account_info().clone(),
+                        ctx.accounts.system_program.to_account_info().clone(),
+                    ],
+                    signer,
+                )?;
+            } else {
+                require!(
+                    is_payment_token_account(
+                        &ctx.accounts.bidder_token_account,
+                        ctx.accounts.bidder.key
+                    ),
+                    MarketplaceError::InvalidOwner
+                );
+                transfer_checked(
+                    &token_program.to_account_info(),
+                    &ctx.accounts.escrow_token_account.to_account_info(),
+                    &ctx.accounts.payment_mint,
+                    &ctx.accounts.bidder_token_account.to_account_info(),
+                    &ctx.accounts.escrow_vault.to_account_info(),
+                    refund,
+                    signer,
+                )?;
+            }
+        }
+
+        let pnft = PnftAccounts::load(ctx.remaining_accounts)?;
+        release_nft(
+            &ctx.accounts.global_authority,
+            NftTransfer {
+                token: ctx.accounts.dest_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.global_authority.to_account_info(),
+                destination: ctx.accounts.user_token_account.to_account_info(),
+                destination_owner: ctx.accounts.creator.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                amount: 1,
+                payer: ctx.accounts.admin.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            ctx.accounts.creator.to_account_info(),
+            pnft.as_ref(),
+        )?;
+
+        Ok(())
+    }
+
+    /**
+     * Close claimed or canceled Auction Data PDA to reclaim rent
+     *
+     * The rent is returned to the payer of the Auction Data PDA
+     * The escrow NFT token account of the global authority should be closed or empty
+     */
+    pub fn close_auction_data(ctx: Context<CloseAuctionData>) -> Result<()> {
+        let auction_data_info = &ctx.accounts.auction_data_info;
+        msg!("Mint: {:?}", auction_data_info.mint);
+
+        // Assert Payer is the Auction Data Rent Payer
+        require_keys_eq!(
+            ctx.accounts.payer.key(),
+            auction_data_info.payer,
+            MarketplaceError::RentPayerMismatch
+        );
+        // Assert the auction is claimed or canceled so the highest bid is not escrowed
+        require!(
+            auction_data_info.status == 2 || auction_data_info.status == 3,
+            MarketplaceError::ActiveDataAccount
+        );
+        // Assert the NFT is not escrowed by the auction
+        let dest_nft_token_account = &ctx.accounts.dest_nft_token_account;
+        require_keys_eq!(
+            dest_nft_token_account.key(),
+            nft_ata(
+                &ctx.accounts.global_authority.key(),
+                &ctx.accounts.nft_mint.key(),
+                ctx.accounts.nft_mint.owner
+            ),
+            MarketplaceError::InvalidNftTokenAccount
+        );
+        if !dest_nft_token_account.data_is_empty() {
+            require_eq!(
+                token_amount(dest_nft_token_account)?,
+                0,
+                MarketplaceError::ActiveDataAccount
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+        space = 8 + 379,
+        payer = admin
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+    #[account(
+        init,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump,
+        space = TreasuryConfig::space(0),
+        payer = admin
+    )]
+    pub treasury_config: Account<'info, TreasuryConfig>,
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
+    pub escrow_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+#[derive(Accounts)]
+pub struct SetThreshold<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+}
+#[derive(Accounts)]
+pub struct AddTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Account<'info, TreasuryConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Account<'info, TreasuryConfig>,
+}
+#[derive(Accounts)]
+pub struct RemoveTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Account<'info, TreasuryConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDustPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+
+    #[account(
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Account<'info, TreasuryConfig>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimTreasuryFees<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
+    )]
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
+    )]
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub treasury: SystemAccount<'info>,
+    /// CHECK: This is the abb ATA of the treasury and validated in the instruction
+    #[account(mut)]
+    pub treasury_token_account: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetCollectionFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [COLLECTION_FEE_SEED.as_ref(), collection.key().to_bytes().as_ref()],
+        bump,
+        space = 8 + 81,
+        payer = admin
+    )]
+    pub collection_fee: Account<'info, CollectionFee>,
+
+    /// CHECK: Collection key, only used as the Collection Fee PDA seed
+    pub collection: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveCollectionFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_FEE_SEED.as_ref(), collection_fee.collection.as_ref()],
+        bump = collection_fee.bump,
+    )]
+    pub collection_fee: Account<'info, CollectionFee>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [COLLECTION_INFO_SEED.as_ref(), collection.key().to_bytes().as_ref()],
+        bump,
+        space = 8 + 75,
+        payer = admin
+    )]
+    pub collection_info: Account<'info, CollectionInfo>,
+
+    /// CHECK: Collection key, only used as the Collection Info PDA seed
+    pub collection: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UnregisterCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_INFO_SEED.as_ref(), collection_info.collection.as_ref()],
+        bump = collection_info.bump,
+        close = admin,
+    )]
+    pub collection_info: Account<'info, CollectionInfo>,
+}
+
+#[derive(Accounts)]
+pub struct AddDenyEntry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [DENY_ENTRY_SEED.as_ref(), address.key().to_bytes().as_ref()],
+        bump,
+        space = 8 + 57,
+        payer = admin
+    )]
+    pub deny_entry: Account<'info, DenyEntry>,
+
+    /// CHECK: Mint or wallet to denylist, only used as the Deny Entry PDA seed
+    pub address: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveDenyEntry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [DENY_ENTRY_SEED.as_ref(), deny_entry.address.as_ref()],
+        bump = deny_entry.bump,
+        close = admin,
+    )]
+    pub deny_entry: Account<'info, DenyEntry>,
+}
+
+#[derive(Accounts)]
+pub struct InitUserPool<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        init,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+        space = 8 + 65,
+        payer = owner,
+    )]
+    pub user_pool: Account<'info, UserData>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveReferral<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_DATA_SEED.as_ref(), referral_data.referrer.as_ref()],
+        bump = referral_data.bump,
+    )]
+    pub referral_data: Account<'info, ReferralData>,
+}
+
+#[derive(Accounts)]
+pub struct InitReferral<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [REFERRAL_DATA_SEED.as_ref(), referrer.key().as_ref()],
+        bump,
+        space = 8 + 73,
+        payer = referrer,
+    )]
+    pub referral_data: Account<'info, ReferralData>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_DATA_SEED.as_ref(), referrer.key().as_ref()],
+        bump = referral_data.bump,
+    )]
+    pub referral_data: Account<'info, ReferralData>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
+    )]
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
+    )]
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&referrer_token_account, referrer.key),
+    )]
+    /// CHECK: abb token account of the referrer of the Token or Token-2022 program
+    pub referrer_token_account: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump = user_pool.bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump = global_authority.escrow_bump,
+    )]
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
+    pub escrow_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&user_token_account, owner.key),
+    )]
+    /// CHECK: abb token account of the owner of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
+    )]
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump = user_pool.bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump = global_authority.escrow_bump,
+    )]
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
+    pub escrow_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&user_token_account, owner.key),
+    )]
+    /// CHECK: abb token account of the owner of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
+    )]
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ListNftForSale<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().as_ref()],
+        bump,
+        space = 8 + 217,
+        payer = owner,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&user_token_account, &nft_mint.key(), owner.key, None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
+
+    /// CHECK: NFT mint seeding the Sell Data PDA and checked against the NFT token accounts
+    pub nft_mint: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
+    )]
+    /// CHECK: Metadata PDA of the NFT mint owned by the Token Metadata program, address checked by load_metadata
+    pub mint_metadata: AccountInfo<'info>,
+    /// CHECK: Collection Info PDA of the NFT collection which may not exist, validated for restricted listing
+    pub collection_info: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the NFT mint which may not exist, validated in the instruction
+    pub mint_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub owner_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: Token Metadata program, checked by the constraint
+    #[account(constraint = token_metadata_program.key == &metaplex_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct DelistNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().as_ref()],
+        bump = sell_data_info.bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&user_token_account, &nft_mint.key(), owner.key, None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
+
+    /// CHECK: NFT mint seeding the Sell Data PDA and checked against the NFT token accounts
+    pub nft_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseNft<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), seller.key().as_ref()],
+        bump = sell_data_info.bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump = buyer_user_pool.bump,
+    )]
+    pub buyer_user_pool: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&user_nft_token_account, &nft_mint.key(), buyer.key, None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_nft_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump = seller_user_pool.bump,
+    )]
+    pub seller_user_pool: Account<'info, UserData>,
+
+    /// CHECK: NFT mint seeding the Sell Data PDA and checked against the NFT token accounts
+    pub nft_mint: AccountInfo<'info>,
+    #[account(
+        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
+    )]
+    /// CHECK: Metadata PDA of the NFT mint owned by the Token Metadata program, address checked by load_metadata
+    pub mint_metadata: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the NFT collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Referral Data PDA of the referrer or the program id without referrer, validated in the instruction
+    pub referral_data: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&user_token_account, buyer.key),
+    )]
+    /// CHECK: abb token account of the buyer of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&seller_token_account, seller.key),
+    )]
+    /// CHECK: abb token account of the seller of the Token or Token-2022 program
+    pub seller_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
+    )]
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
+    )]
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the NFT mint which may not exist, validated in the instruction
+    pub mint_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub buyer_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub seller_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
 
-        Ok(())
-    }
+#[derive(Accounts)]
+pub struct CloseSellData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), sell_data_info.seller.as_ref()],
+        bump = sell_data_info.bump,
+        close = payer,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    /// CHECK: NFT mint seeding the Sell Data PDA
+    pub nft_mint: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8, escrow_bump: u8)]
-pub struct Initialize<'info> {
+pub struct MakeOffer<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub owner: Signer<'info>,
+
     #[account(
-        init,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), seller.key().as_ref()],
+        bump = sell_data_info.bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    #[account(
+        init_if_needed,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), seller.key().as_ref(), owner.key().to_bytes().as_ref()],
         bump,
-        space = 8 + 376,
-        payer = admin
+        space = 8 + 169,
+        payer = owner,
     )]
-    pub global_authority: Account<'info, GlobalPool>,
+    pub offer_data_info: Box<Account<'info, OfferData>>,
+
+    /// CHECK: NFT mint seeding the Sell Data and Offer Data PDAs
+    pub nft_mint: AccountInfo<'info>,
+
+    /// CHECK: Seller of the listing, bound to the listing by the Sell Data PDA seeds
+    pub seller: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump = user_pool.bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
     #[account(
         mut,
         seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
+        bump = global_authority.escrow_bump,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
     pub escrow_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&user_token_account, owner.key),
+    )]
+    /// CHECK: abb token account of the owner of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
+    )]
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the listing collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the NFT mint which may not exist, validated in the instruction
+    pub mint_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub owner_deny_entry: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
+
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct SetThreshold<'info> {
+pub struct CancelOffer<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub owner: Signer<'info>,
+
     #[account(
         mut,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), offer_data_info.seller.as_ref(), owner.key().to_bytes().as_ref()],
+        bump = offer_data_info.bump,
     )]
-    pub global_authority: Account<'info, GlobalPool>,
+    pub offer_data_info: Account<'info, OfferData>,
+
+    /// CHECK: NFT mint seeding the Offer Data PDA
+    pub nft_mint: AccountInfo<'info>,
 }
+
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct AddTreasury<'info> {
+pub struct AcceptOffer<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub seller: Signer<'info>,
+
     #[account(
         mut,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), seller.key().as_ref()],
+        bump = sell_data_info.bump,
     )]
-    pub global_authority: Account<'info, GlobalPool>,
-}
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct RemoveTreasury<'info> {
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
     #[account(mut)]
-    pub admin: Signer<'info>,
+    /// Buyer of the offer, bound by the Offer Data PDA seeds
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), seller.key().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump = offer_data_info.bump,
+    )]
+    pub offer_data_info: Box<Account<'info, OfferData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump = seller_user_pool.bump,
+    )]
+    pub seller_user_pool: Box<Account<'info, UserData>>,
+
+    /// CHECK: NFT mint seeding the Sell Data and Offer Data PDAs and checked against the NFT token accounts
+    pub nft_mint: AccountInfo<'info>,
+    #[account(
+        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
+    )]
+    /// CHECK: Metadata PDA of the NFT mint owned by the Token Metadata program, address checked by load_metadata
+    pub mint_metadata: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the NFT collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Referral Data PDA of the referrer or the program id without referrer, validated in the instruction
+    pub referral_data: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
-    pub global_authority: Account<'info, GlobalPool>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump = buyer_user_pool.bump,
+    )]
+    pub buyer_user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&user_nft_token_account, &nft_mint.key(), buyer.key, None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_nft_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump = global_authority.escrow_bump,
+    )]
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
+    pub escrow_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&user_token_account, seller.key),
+    )]
+    /// CHECK: abb token account of the seller of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
+    )]
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
+    )]
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
+    )]
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the NFT mint which may not exist, validated in the instruction
+    pub mint_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub buyer_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub seller_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct InitUserPool<'info> {
+pub struct ListCompressedNft<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
+
     #[account(
-        init,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [SELL_DATA_SEED.as_ref(), asset_id.key().to_bytes().as_ref(), owner.key().as_ref()],
         bump,
-        space = 8 + 64,
+        space = 8 + 217,
         payer = owner,
     )]
-    pub user_pool: Account<'info, UserData>,
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    /// CHECK: Asset id of the compressed NFT, validated in the instruction
+    pub asset_id: AccountInfo<'info>,
+    /// CHECK: Collection Info PDA of the NFT collection which may not exist, validated for restricted listing
+    pub collection_info: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the compressed NFT asset id which may not exist, validated in the instruction
+    pub asset_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub owner_deny_entry: AccountInfo<'info>,
+    /// CHECK: Bubblegum tree authority of the merkle tree, validated by Bubblegum
+    pub tree_authority: AccountInfo<'info>,
+    /// CHECK: Leaf delegate of the compressed NFT, validated by Bubblegum
+    pub leaf_delegate: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: Merkle tree of the compressed NFT, validated by Bubblegum
+    pub merkle_tree: AccountInfo<'info>,
+    /// CHECK: Noop log wrapper program, validated by Bubblegum
+    pub log_wrapper: AccountInfo<'info>,
+    /// CHECK: Account compression program, validated by Bubblegum
+    pub compression_program: AccountInfo<'info>,
+    /// CHECK: Bubblegum program, validated in the instruction
+    pub bubblegum_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
+
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct Deposit<'info> {
+pub struct DelistCompressedNft<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
-        bump,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
     )]
-    pub user_pool: Account<'info, UserData>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
+        seeds = [SELL_DATA_SEED.as_ref(), asset_id.key().to_bytes().as_ref(), owner.key().as_ref()],
+        bump = sell_data_info.bump,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
+    pub sell_data_info: Account<'info, SellData>,
+
+    /// CHECK: Asset id of the compressed NFT, validated in the instruction
+    pub asset_id: AccountInfo<'info>,
+    /// CHECK: Bubblegum tree authority of the merkle tree, validated by Bubblegum
+    pub tree_authority: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: Merkle tree of the compressed NFT, validated by Bubblegum
+    pub merkle_tree: AccountInfo<'info>,
+    /// CHECK: Noop log wrapper program, validated by Bubblegum
+    pub log_wrapper: AccountInfo<'info>,
+    /// CHECK: Account compression program, validated by Bubblegum
+    pub compression_program: AccountInfo<'info>,
+    /// CHECK: Bubblegum program, validated in the instruction
+    pub bubblegum_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseCompressedNft<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = user_token_account.owner == *owner.key,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        constraint = escrow_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = escrow_token_account.owner == *escrow_vault.key,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
     )]
-    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct Withdraw<'info> {
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), asset_id.key().to_bytes().as_ref(), seller.key().as_ref()],
+        bump = sell_data_info.bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump = buyer_user_pool.bump,
+    )]
+    pub buyer_user_pool: Account<'info, UserData>,
+
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub seller: SystemAccount<'info>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
-        bump,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump = seller_user_pool.bump,
     )]
-    pub user_pool: Account<'info, UserData>,
+    pub seller_user_pool: Account<'info, UserData>,
+
+    /// CHECK: Asset id of the compressed NFT, validated in the instruction
+    pub asset_id: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the NFT collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Referral Data PDA of the referrer or the program id without referrer, validated in the instruction
+    pub referral_data: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
+        constraint = is_payment_token_account(&user_token_account, buyer.key),
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
+    /// CHECK: abb token account of the buyer of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&seller_token_account, seller.key),
+    )]
+    /// CHECK: abb token account of the seller of the Token or Token-2022 program
+    pub seller_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = user_token_account.owner == *owner.key,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = escrow_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = escrow_token_account.owner == *escrow_vault.key,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
     )]
-    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the compressed NFT asset id which may not exist, validated in the instruction
+    pub asset_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub buyer_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub seller_deny_entry: AccountInfo<'info>,
+    /// CHECK: Bubblegum tree authority of the merkle tree, validated by Bubblegum
+    pub tree_authority: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: Merkle tree of the compressed NFT, validated by Bubblegum
+    pub merkle_tree: AccountInfo<'info>,
+    /// CHECK: Noop log wrapper program, validated by Bubblegum
+    pub log_wrapper: AccountInfo<'info>,
+    /// CHECK: Account compression program, validated by Bubblegum
+    pub compression_program: AccountInfo<'info>,
+    /// CHECK: Bubblegum program, validated in the instruction
+    pub bubblegum_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(nft: Pubkey, bump: u8)]
-pub struct InitSellData<'info> {
+pub struct AcceptCompressedOffer<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub seller: Signer<'info>,
+
     #[account(
-        init,
-        seeds = [SELL_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
-        bump,
-        space = 8 + 128,
-        payer = payer,
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), asset_id.key().to_bytes().as_ref(), seller.key().as_ref()],
+        bump = sell_data_info.bump,
     )]
-    pub sell_data_info: Account<'info, SellData>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    pub sell_data_info: Box<Account<'info, SellData>>,
 
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct ListNftForSale<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    /// Buyer of the offer, bound by the Offer Data PDA seeds
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), asset_id.key().to_bytes().as_ref(), seller.key().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump = offer_data_info.bump,
+    )]
+    pub offer_data_info: Box<Account<'info, OfferData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump = seller_user_pool.bump,
+    )]
+    pub seller_user_pool: Box<Account<'info, UserData>>,
+
+    /// CHECK: Asset id of the compressed NFT, validated in the instruction
+    pub asset_id: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the NFT collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Referral Data PDA of the referrer or the program id without referrer, validated in the instruction
+    pub referral_data: AccountInfo<'info>,
 
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
     )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump = buyer_user_pool.bump,
+    )]
+    pub buyer_user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump = global_authority.escrow_bump,
+    )]
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
+    pub escrow_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&user_token_account, seller.key),
+    )]
+    /// CHECK: abb token account of the seller of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *owner.key,
-        constraint = user_token_account.amount == 1,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == global_authority.key(),
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
     )]
-    pub dest_nft_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
-    /// the mint metadata
     #[account(
         mut,
-        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub mint_metadata: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    #[account(constraint = token_metadata_program.key == &metaplex_token_metadata::ID)]
-    pub token_metadata_program: AccountInfo<'info>,
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the compressed NFT asset id which may not exist, validated in the instruction
+    pub asset_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub buyer_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub seller_deny_entry: AccountInfo<'info>,
+    /// CHECK: Bubblegum tree authority of the merkle tree, validated by Bubblegum
+    pub tree_authority: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: Merkle tree of the compressed NFT, validated by Bubblegum
+    pub merkle_tree: AccountInfo<'info>,
+    /// CHECK: Noop log wrapper program, validated by Bubblegum
+    pub log_wrapper: AccountInfo<'info>,
+    /// CHECK: Account compression program, validated by Bubblegum
+    pub compression_program: AccountInfo<'info>,
+    /// CHECK: Bubblegum program, validated in the instruction
+    pub bubblegum_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct DelistNft<'info> {
+pub struct ListBundle<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
-        mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        init_if_needed,
+        seeds = [BUNDLE_DATA_SEED.as_ref(), bundle_mint.key().to_bytes().as_ref(), owner.key().as_ref()],
         bump,
+        space = 8 + 337,
+        payer = owner,
     )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub bundle_data_info: Box<Account<'info, BundleData>>,
+
+    /// CHECK: First NFT mint of the bundle, validated in the instruction
+    pub bundle_mint: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub owner_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the bundled NFT mints, validated in the instruction
+    #[account(constraint = is_token_program(nft_token_program.key))]
+    pub nft_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct DelistBundle<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *owner.key,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == global_authority.key(),
-        constraint = dest_nft_token_account.amount == 1,
+        seeds = [BUNDLE_DATA_SEED.as_ref(), bundle_mint.key().to_bytes().as_ref(), owner.key().as_ref()],
+        bump = bundle_data_info.bump,
     )]
-    pub dest_nft_token_account: Account<'info, TokenAccount>,
+    pub bundle_data_info: Box<Account<'info, BundleData>>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: First NFT mint of the bundle, validated by the Bundle Data PDA seeds
+    pub bundle_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the bundled NFT mints
+    #[account(constraint = is_token_program(nft_token_program.key))]
+    pub nft_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct PurchaseNft<'info> {
+pub struct PurchaseBundle<'info> {
     #[account(mut)]
     pub buyer: Signer<'info>,
 
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
-    )]
-    pub sell_data_info: Box<Account<'info, SellData>>,
-
-    #[account(
-        mut,
-        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
-        bump,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
     )]
-    pub buyer_user_pool: Account<'info, UserData>,
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
 
     #[account(
         mut,
-        constraint = user_nft_token_account.mint == nft_mint.key(),
-        constraint = user_nft_token_account.owner == *buyer.key,
+        seeds = [BUNDLE_DATA_SEED.as_ref(), bundle_mint.key().to_bytes().as_ref(), seller.key().as_ref()],
+        bump = bundle_data_info.bump,
     )]
-    pub user_nft_token_account: Box<Account<'info, TokenAccount>>,
+    pub bundle_data_info: Box<Account<'info, BundleData>>,
 
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == global_authority.key(),
-        constraint = dest_nft_token_account.amount == 1,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump = buyer_user_pool.bump,
     )]
-    pub dest_nft_token_account: Box<Account<'info, TokenAccount>>,
+    pub buyer_user_pool: Account<'info, UserData>,
 
     #[account(mut)]
     pub seller: SystemAccount<'info>,
@@ -2538,226 +5155,361 @@ pub struct PurchaseNft<'info> {
     #[account(
         mut,
         seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
-        bump,
+        bump = seller_user_pool.bump,
     )]
     pub seller_user_pool: Account<'info, UserData>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    /// CHECK: First NFT mint of the bundle, validated by the Bundle Data PDA seeds
+    pub bundle_mint: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the bundle collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Referral Data PDA of the referrer or the program id without referrer, validated in the instruction
+    pub referral_data: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&user_token_account, buyer.key),
+    )]
+    /// CHECK: abb token account of the buyer of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&seller_token_account, seller.key),
+    )]
+    /// CHECK: abb token account of the seller of the Token or Token-2022 program
+    pub seller_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = user_token_account.owner == *buyer.key,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = seller_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = seller_token_account.owner == *seller.key,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
     )]
-    pub seller_token_account: Box<Account<'info, TokenAccount>>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub buyer_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub seller_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the bundled NFT mints, validated in the instruction
+    #[account(constraint = is_token_program(nft_token_program.key))]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(nft: Pubkey, bump: u8)]
-pub struct InitOfferData<'info> {
+pub struct CloseBundleData<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+
     #[account(
-        init,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft.to_bytes().as_ref(), payer.key().to_bytes().as_ref()],
-        bump,
-        space = 8 + 96,
-        payer = payer,
+        mut,
+        seeds = [BUNDLE_DATA_SEED.as_ref(), bundle_mint.key().to_bytes().as_ref(), bundle_data_info.seller.as_ref()],
+        bump = bundle_data_info.bump,
+        close = payer,
     )]
-    pub offer_data_info: Account<'info, OfferData>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    pub bundle_data_info: Account<'info, BundleData>,
+
+    /// CHECK: First NFT mint of the bundle, only used as the Bundle Data PDA seed
+    pub bundle_mint: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct MakeOffer<'info> {
+pub struct CloseOfferData<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub payer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), offer_data_info.seller.as_ref(), buyer.key().to_bytes().as_ref()],
+        bump = offer_data_info.bump,
+        close = payer,
     )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub offer_data_info: Account<'info, OfferData>,
+
+    /// CHECK: Buyer of the offer, only used as the Offer Data PDA seed
+    pub buyer: AccountInfo<'info>,
+
+    /// CHECK: NFT mint seeding the Offer Data PDA
+    pub nft_mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MakeSwapOffer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
 
     #[account(
-        mut,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        init_if_needed,
+        seeds = [SWAP_OFFER_SEED.as_ref(), target_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
         bump,
+        space = 8 + 337,
+        payer = owner,
     )]
-    pub offer_data_info: Account<'info, OfferData>,
+    pub swap_offer_info: Box<Account<'info, SwapOfferData>>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    /// CHECK: Target NFT mint seeding the Swap Offer Data PDA
+    pub target_mint: AccountInfo<'info>,
 
     #[account(
         mut,
         seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
-        bump,
+        bump = user_pool.bump,
     )]
     pub user_pool: Account<'info, UserData>,
 
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
     #[account(
         mut,
         seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
+        bump = global_authority.escrow_bump,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
     pub escrow_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = user_token_account.owner == *owner.key,
+        constraint = is_payment_token_account(&user_token_account, owner.key),
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: abb token account of the owner of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = escrow_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = escrow_token_account.owner == *escrow_vault.key,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
+    )]
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+    /// the mint metadata of the target NFT
+    #[account(
+        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
     )]
-    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Metadata of the target NFT, validated with the mint in the instruction
+    pub mint_metadata: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the target NFT collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the target NFT mint which may not exist, validated in the instruction
+    pub target_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub owner_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the offered NFT mints, validated in the instruction
+    #[account(constraint = is_token_program(nft_token_program.key))]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct CancelOffer<'info> {
+pub struct CancelSwapOffer<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
-        bump,
+        seeds = [SWAP_OFFER_SEED.as_ref(), target_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        bump = swap_offer_info.bump,
     )]
-    pub offer_data_info: Account<'info, OfferData>,
+    pub swap_offer_info: Box<Account<'info, SwapOfferData>>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    /// CHECK: Target NFT mint seeding the Swap Offer Data PDA
+    pub target_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump = user_pool.bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+    /// CHECK: Token or Token-2022 program of the offered NFT mints
+    #[account(constraint = is_token_program(nft_token_program.key))]
+    pub nft_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct AcceptOffer<'info> {
+pub struct AcceptSwapOffer<'info> {
     #[account(mut)]
     pub seller: Signer<'info>,
 
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
+        seeds = [SWAP_OFFER_SEED.as_ref(), target_mint.key().to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump = swap_offer_info.bump,
     )]
-    pub sell_data_info: Box<Account<'info, SellData>>,
+    pub swap_offer_info: Box<Account<'info, SwapOfferData>>,
 
     #[account(mut)]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub buyer: SystemAccount<'info>,
+    /// CHECK: NFT token account of the seller, or the escrow NFT token account of the global authority
+    /// when the seller listed the NFT, validated in the instruction
+    pub seller_nft_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        seeds = [SELL_DATA_SEED.as_ref(), target_mint.key().to_bytes().as_ref(), seller.key().as_ref()],
         bump,
     )]
-    pub offer_data_info: Box<Account<'info, OfferData>>,
+    /// CHECK: Sell Data PDA of the target NFT and the seller which may not exist, validated by the seeds
+    /// and loaded in the instruction
+    pub sell_data_info: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
-        bump,
+        constraint = is_nft_token_account(&buyer_nft_token_account, &target_mint.key(), buyer.key, None),
     )]
-    pub seller_user_pool: Box<Account<'info, UserData>>,
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub buyer_nft_token_account: AccountInfo<'info>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    /// CHECK: Target NFT mint seeding the Swap Offer Data PDA and checked against the NFT token accounts
+    pub target_mint: AccountInfo<'info>,
+    #[account(
+        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
+    )]
+    /// CHECK: Metadata PDA of the NFT mint owned by the Token Metadata program, address checked by load_metadata
+    pub mint_metadata: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the NFT collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Referral Data PDA of the referrer or the program id without referrer, validated in the instruction
+    pub referral_data: AccountInfo<'info>,
 
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
-        bump,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
     )]
-    pub buyer_user_pool: Box<Account<'info, UserData>>,
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
 
     #[account(
         mut,
-        constraint = user_nft_token_account.mint == nft_mint.key(),
-        constraint = user_nft_token_account.owner == *buyer.key,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump = buyer_user_pool.bump,
     )]
-    pub user_nft_token_account: Box<Account<'info, TokenAccount>>,
+    pub buyer_user_pool: Box<Account<'info, UserData>>,
 
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == global_authority.key(),
-        constraint = dest_nft_token_account.amount == 1,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump = seller_user_pool.bump,
     )]
-    pub dest_nft_token_account: Box<Account<'info, TokenAccount>>,
+    pub seller_user_pool: Box<Account<'info, UserData>>,
 
     #[account(
         mut,
         seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
+        bump = global_authority.escrow_bump,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
     pub escrow_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = user_token_account.owner == *seller.key,
+        constraint = is_payment_token_account(&user_token_account, seller.key),
+    )]
+    /// CHECK: abb token account of the seller of the Token or Token-2022 program
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
+    )]
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = escrow_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = escrow_token_account.owner == *escrow_vault.key,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
     )]
-    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the target NFT mint which may not exist, validated in the instruction
+    pub target_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the buyer which may not exist, validated in the instruction
+    pub buyer_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub seller_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the target NFT mint
+    #[account(constraint = is_token_program(target_token_program.key) && target_mint.owner == target_token_program.key)]
+    pub target_token_program: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the offered NFT mints
+    #[account(constraint = is_token_program(nft_token_program.key))]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(nft: Pubkey, bump: u8)]
-pub struct InitAuctionData<'info> {
+pub struct CloseSwapOfferData<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+
     #[account(
-        init,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
-        bump,
-        space = 8 + 152,
-        payer = payer,
+        mut,
+        seeds = [SWAP_OFFER_SEED.as_ref(), target_mint.key().to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump = swap_offer_info.bump,
+        close = payer,
     )]
-    pub auction_data_info: Account<'info, AuctionData>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    pub swap_offer_info: Account<'info, SwapOfferData>,
+
+    /// CHECK: Buyer of the swap offer, only used as the Swap Offer Data PDA seed
+    pub buyer: AccountInfo<'info>,
+
+    /// CHECK: Target NFT mint seeding the Swap Offer Data PDA
+    pub target_mint: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct CreateAuction<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -2765,39 +5517,54 @@ pub struct CreateAuction<'info> {
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
-        mut,
+        init_if_needed,
         seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
+        space = 8 + 257,
+        payer = owner,
     )]
-    pub auction_data_info: Account<'info, AuctionData>,
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *owner.key,
-        constraint = user_token_account.amount == 1,
+        constraint = is_nft_token_account(&user_token_account, &nft_mint.key(), owner.key, Some(1)),
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == global_authority.key(),
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), None),
     )]
-    pub dest_nft_token_account: Account<'info, TokenAccount>,
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: NFT mint seeding the Auction Data PDA and checked against the NFT token accounts
     pub nft_mint: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
+    #[account(
+        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
+    )]
+    /// CHECK: Metadata PDA of the NFT mint owned by the Token Metadata program, address checked by load_metadata
+    pub mint_metadata: AccountInfo<'info>,
+    /// CHECK: Collection Info PDA of the NFT collection which may not exist, validated for restricted listing
+    pub collection_info: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the NFT mint which may not exist, validated in the instruction
+    pub mint_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the seller which may not exist, validated in the instruction
+    pub owner_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct PlaceBid<'info> {
     #[account(mut)]
     pub bidder: Signer<'info>,
@@ -2805,52 +5572,73 @@ pub struct PlaceBid<'info> {
     #[account(
         mut,
         seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
+        bump = auction_data_info.bump,
     )]
     pub auction_data_info: Account<'info, AuctionData>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: NFT mint seeding the Auction Data PDA
     pub nft_mint: AccountInfo<'info>,
 
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
     #[account(
         mut,
         seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
+        bump = global_authority.escrow_bump,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
     pub escrow_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = bidder_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = bidder_token_account.owner == *bidder.key,
+        constraint = is_payment_token_account(&bidder_token_account, bidder.key),
     )]
-    pub bidder_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: abb token account of the bidder of the Token or Token-2022 program
+    pub bidder_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = escrow_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = escrow_token_account.owner == *escrow_vault.key,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
     )]
-    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
 
+    // For the first bid the bidder is passed as out_bidder and the out-bidder accounts are not used
     #[account(mut)]
-    /// CHECK: This is not dangerous because we don't read or write from this account
     pub out_bidder: SystemAccount<'info>,
 
-    #[account(
-        mut,
-        constraint = out_bidder_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = out_bidder_token_account.owner == *out_bidder.key,
-    )]
-    pub out_bidder_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    /// CHECK: ABB token account of the out-bidder, validated in the instruction when the token bid is refunded to the wallet
+    pub out_bidder_token_account: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(mut)]
+    /// CHECK: User PDA of the out-bidder which may not exist, validated in the instruction when the out-bid is refunded to the escrow balance
+    pub out_bidder_user_pool: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// CHECK: User PDA of the bidder which may not exist, validated in the instruction when bidding by escrow
+    pub bidder_user_pool: AccountInfo<'info>,
+
+    /// CHECK: Collection Fee PDA of the auction collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the NFT mint which may not exist, validated in the instruction
+    pub mint_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the bidder which may not exist, validated in the instruction
+    pub bidder_deny_entry: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct ClaimAuction<'info> {
     #[account(mut)]
     pub bidder: Signer<'info>,
@@ -2858,81 +5646,121 @@ pub struct ClaimAuction<'info> {
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
+    #[account(
+        mut,
+        seeds = [TREASURY_CONFIG_SEED.as_ref()],
+        bump = treasury_config.bump,
+    )]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
     #[account(
         mut,
         seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
+        bump = auction_data_info.bump,
     )]
     pub auction_data_info: Account<'info, AuctionData>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *bidder.key,
+        constraint = is_nft_token_account(&user_token_account, &nft_mint.key(), bidder.key, None),
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_token_account: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == global_authority.key(),
-        constraint = dest_nft_token_account.amount == 1,
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), Some(1)),
     )]
-    pub dest_nft_token_account: Account<'info, TokenAccount>,
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: NFT mint seeding the Auction Data PDA and checked against the NFT token accounts
     pub nft_mint: AccountInfo<'info>,
+    #[account(
+        constraint = mint_metadata.owner == &metaplex_token_metadata::ID
+    )]
+    /// CHECK: Metadata PDA of the NFT mint owned by the Token Metadata program, address checked by load_metadata
+    pub mint_metadata: AccountInfo<'info>,
+    /// CHECK: Collection Fee PDA of the NFT collection which may not exist, validated in the instruction
+    pub collection_fee: AccountInfo<'info>,
+    /// CHECK: Referral Data PDA of the referrer or the program id without referrer, validated in the instruction
+    pub referral_data: AccountInfo<'info>,
 
     #[account(
         mut,
         seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
+        bump = global_authority.escrow_bump,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
     pub escrow_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = escrow_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = escrow_token_account.owner == *escrow_vault.key,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
     )]
-    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// CHECK: abb token account of the bidder, validated in the instruction when the fee surplus is refunded to the wallet
+    pub bidder_token_account: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// CHECK: User PDA of the bidder which may not exist, validated in the instruction
+    pub bidder_user_pool: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// Auction creator, checked against the Auction Data PDA in the instruction
+    pub creator: SystemAccount<'info>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), bidder.key().as_ref()],
-        bump,
+        constraint = is_payment_token_account(&creator_token_account, creator.key),
     )]
-    pub bidder_user_pool: Box<Account<'info, UserData>>,
+    /// CHECK: abb token account of the creator of the Token or Token-2022 program
+    pub creator_token_account: AccountInfo<'info>,
 
     #[account(mut)]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub creator: SystemAccount<'info>,
+    /// CHECK: User PDA of the creator which may not exist, validated in the instruction
+    pub creator_user_pool: AccountInfo<'info>,
 
     #[account(
         mut,
-        constraint = creator_token_account.mint == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
-        constraint = creator_token_account.owner == *creator.key,
+        seeds = [FEE_VAULT_SEED.as_ref()],
+        bump = global_authority.fee_vault_bump,
     )]
-    pub creator_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Fee vault PDA holding the accrued fees, validated by the seeds
+    pub fee_vault: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), creator.key().as_ref()],
-        bump,
+        constraint = is_payment_token_account(&fee_vault_token_account, fee_vault.key),
     )]
-    pub creator_user_pool: Box<Account<'info, UserData>>,
-
-    pub token_program: Program<'info, Token>,
+    /// CHECK: abb token account of the fee vault of the Token or Token-2022 program
+    pub fee_vault_token_account: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the NFT mint which may not exist, validated in the instruction
+    pub mint_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the bidder which may not exist, validated in the instruction
+    pub bidder_deny_entry: AccountInfo<'info>,
+    /// CHECK: Deny Entry PDA of the creator which may not exist, validated in the instruction
+    pub creator_deny_entry: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct CancelAuction<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -2940,34 +5768,137 @@ pub struct CancelAuction<'info> {
     #[account(
         mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
+        bump = global_authority.bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
         seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
+        bump = auction_data_info.bump,
+    )]
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&user_token_account, &nft_mint.key(), creator.key, None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), Some(1)),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
+
+    /// CHECK: NFT mint seeding the Auction Data PDA and checked against the NFT token accounts
+    pub nft_mint: AccountInfo<'info>,
+
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ForceCancelAuction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump = auction_data_info.bump,
     )]
     pub auction_data_info: Box<Account<'info, AuctionData>>,
 
+    #[account(mut)]
+    /// Auction creator, checked against the Auction Data PDA in the instruction
+    pub creator: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&user_token_account, &nft_mint.key(), creator.key, None),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub user_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = is_nft_token_account(&dest_nft_token_account, &nft_mint.key(), &global_authority.key(), Some(1)),
+    )]
+    /// CHECK: NFT token account of the Token or Token-2022 program, checked by the constraint
+    pub dest_nft_token_account: AccountInfo<'info>,
+
+    /// CHECK: NFT mint seeding the Auction Data PDA and checked against the NFT token accounts
+    pub nft_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump = global_authority.escrow_bump,
+    )]
+    /// CHECK: Escrow vault PDA holding the escrowed SOL and owning the escrow abb account, validated by the seeds
+    pub escrow_vault: AccountInfo<'info>,
+
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *creator.key,
+        constraint = is_payment_token_account(&escrow_token_account, escrow_vault.key),
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: abb token account of the escrow vault of the Token or Token-2022 program
+    pub escrow_token_account: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// Last bidder refunded by the cancellation, checked against the Auction Data PDA in the instruction
+    pub bidder: SystemAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: abb token account of the bidder, validated in the instruction when the token bid is refunded
+    pub bidder_token_account: AccountInfo<'info>,
+
+    /// CHECK: Token or Token-2022 program of the NFT mint
+    #[account(constraint = is_token_program(nft_token_program.key) && nft_mint.owner == nft_token_program.key)]
+    pub nft_token_program: AccountInfo<'info>,
+    /// CHECK: abb mint of the Token or Token-2022 program
+    #[account(constraint = payment_mint.key() == REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap())]
+    pub payment_mint: AccountInfo<'info>,
+    /// CHECK: Token or Token-2022 program of the abb mint
+    #[account(constraint = is_token_program(token_program.key) && payment_mint.owner == token_program.key)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAuctionData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
 
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == global_authority.key(),
-        constraint = dest_nft_token_account.amount == 1,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump = auction_data_info.bump,
+        close = payer,
     )]
-    pub dest_nft_token_account: Box<Account<'info, TokenAccount>>,
+    pub auction_data_info: Account<'info, AuctionData>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: NFT mint seeding the Auction Data PDA
     pub nft_mint: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump = global_authority.bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    /// CHECK: Escrow NFT ATA of the global authority which may be closed, validated in the instruction
+    pub dest_nft_token_account: AccountInfo<'info>,
 }
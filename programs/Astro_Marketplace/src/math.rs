@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PERMYRIAD;
+use crate::error::MarketplaceError;
+
+/**
+ * Checked arithmetic for fee, escrow and volume calculation
+ *
+ * Every operation returns a decodable MarketplaceError instead of panicking
+ */
+pub trait SafeMath: Sized {
+    fn safe_add(self, rhs: Self) -> Result<Self>;
+    fn safe_sub(self, rhs: Self) -> Result<Self>;
+    fn safe_mul(self, rhs: Self) -> Result<Self>;
+    fn safe_div(self, rhs: Self) -> Result<Self>;
+    /// self * rate / PERMYRIAD
+    fn permyriad(self, rate: Self) -> Result<Self>;
+}
+
+impl SafeMath for u64 {
+    fn safe_add(self, rhs: u64) -> Result<u64> {
+        self.checked_add(rhs)
+            .ok_or_else(|| error!(MarketplaceError::MathOverflow))
+    }
+
+    fn safe_sub(self, rhs: u64) -> Result<u64> {
+        self.checked_sub(rhs)
+            .ok_or_else(|| error!(MarketplaceError::MathUnderflow))
+    }
+
+    fn safe_mul(self, rhs: u64) -> Result<u64> {
+        self.checked_mul(rhs)
+            .ok_or_else(|| error!(MarketplaceError::MathOverflow))
+    }
+
+    fn safe_div(self, rhs: u64) -> Result<u64> {
+        self.checked_div(rhs)
+            .ok_or_else(|| error!(MarketplaceError::MathDivisionByZero))
+    }
+
+    fn permyriad(self, rate: u64) -> Result<u64> {
+        // Calculate in u128 so that the multiplication never overflows
+        let amount = (self as u128)
+            .checked_mul(rate as u128)
+            .ok_or_else(|| error!(MarketplaceError::MathOverflow))?
+            / PERMYRIAD as u128;
+        u64::try_from(amount).map_err(|_| error!(MarketplaceError::MathOverflow))
+    }
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program::invoke_signed;
+
+use crate::constants::*;
+use crate::error::*;
+use crate::token_interface::*;
+
+/**
+ * Token Metadata accounts of a programmable NFT transfer
+ *
+ * Passed as remaining accounts in this order after the royalty creators
+ * Optional accounts not used by the NFT are replaced by the Token Metadata program id
+ */
+pub struct PnftAccounts<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub edition: AccountInfo<'info>,
+    pub owner_token_record: AccountInfo<'info>,
+    pub destination_token_record: AccountInfo<'info>,
+    pub authorization_rules_program: AccountInfo<'info>,
+    pub authorization_rules: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+impl<'info> PnftAccounts<'info> {
+    /**
+     * Load the pNFT transfer accounts
+     *
+     * No accounts means the NFT is transferred by the Token program
+     */
+    pub fn load(accounts: &[AccountInfo<'info>]) -> Result<Option<Self>> {
+        if accounts.is_empty() {
+            return Ok(None);
+        }
+        require_eq!(
+            accounts.len(),
+            PNFT_ACCOUNT_COUNT,
+            MarketplaceError::InvalidPnftAccounts
+        );
+        // Token Metadata program validates the other accounts
+        require_keys_eq!(
+            accounts[8].key(),
+            metaplex_token_metadata::id(),
+            MarketplaceError::InvalidPnftAccounts
+        );
+        Ok(Some(PnftAccounts {
+            metadata: accounts[0].clone(),
+            edition: accounts[1].clone(),
+            owner_token_record: accounts[2].clone(),
+            destination_token_record: accounts[3].clone(),
+            authorization_rules_program: accounts[4].clone(),
+            authorization_rules: accounts[5].clone(),
+            sysvar_instructions: accounts[6].clone(),
+            associated_token_program: accounts[7].clone(),
+            token_metadata_program: accounts[8].clone(),
+        }))
+    }
+
+    /**
+     * Account meta of the optional Token Metadata account
+     *
+     * Missing accounts are the Token Metadata program id which can't be writable
+     */
+    fn optional_meta(&self, account: &AccountInfo, is_writable: bool) -> AccountMeta {
+        if account.key() == self.token_metadata_program.key() {
+            AccountMeta::new_readonly(account.key(), false)
+        } else if is_writable {
+            AccountMeta::new(account.key(), false)
+        } else {
+            AccountMeta::new_readonly(account.key(), false)
+        }
+    }
+}
+
+/**
+ * Accounts transferring amount tokens of the NFT mint from token to destination
+ *
+ * amount is 1 for NFTs and the traded units for semi-fungible tokens
+ * token_owner is the transfer authority, the seller wallet or the global authority for escrowed NFTs
+ */
+pub struct NftTransfer<'info> {
+    pub token: AccountInfo<'info>,
+    pub token_owner: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub destination_owner: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub amount: u64,
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> NftTransfer<'info> {
+    /**
+     * Transfer the NFT by the Token or Token-2022 program or by Token Metadata for programmable NFTs
+     *
+     * signer_seeds is empty for the wallet and the global authority seeds otherwise
+     */
+    pub fn execute(
+        &self,
+        pnft: Option<&PnftAccounts<'info>>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let pnft = match pnft {
+            Some(pnft) => pnft,
+            None => {
+                return transfer_checked(
+                    &self.token_program,
+                    &self.token,
+                    &self.mint,
+                    &self.destination,
+                    &self.token_owner,
+                    self.amount,
+                    signer_seeds,
+                );
+            }
+        };
+
+        // Token Metadata Transfer instruction with TransferArgs::V1 { amount, authorization_data: None }
+        let mut data = vec![TOKEN_METADATA_TRANSFER_IX, 0];
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.push(0);
+
+        let instruction = Instruction {
+            program_id: pnft.token_metadata_program.key(),
+            accounts: vec![
+                AccountMeta::new(self.token.key(), false),
+                AccountMeta::new_readonly(self.token_owner.key(), false),
+                AccountMeta::new(self.destination.key(), false),
+                AccountMeta::new_readonly(self.destination_owner.key(), false),
+                AccountMeta::new_readonly(self.mint.key(), false),
+                AccountMeta::new(pnft.metadata.key(), false),
+                pnft.optional_meta(&pnft.edition, false),
+                pnft.optional_meta(&pnft.owner_token_record, true),
+                pnft.optional_meta(&pnft.destination_token_record, true),
+                AccountMeta::new_readonly(self.token_owner.key(), true),
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+                AccountMeta::new_readonly(pnft.sysvar_instructions.key(), false),
+                AccountMeta::new_readonly(self.token_program.key(), false),
+                AccountMeta::new_readonly(pnft.associated_token_program.key(), false),
+                pnft.optional_meta(&pnft.authorization_rules_program, false),
+                pnft.optional_meta(&pnft.authorization_rules, false),
+            ],
+            data,
+        };
+        invoke_signed(
+            &instruction,
+            &[
+                self.token.clone(),
+                self.token_owner.clone(),
+                self.destination.clone(),
+                self.destination_owner.clone(),
+                self.mint.clone(),
+                pnft.metadata.clone(),
+                pnft.edition.clone(),
+                pnft.owner_token_record.clone(),
+                pnft.destination_token_record.clone(),
+                self.payer.clone(),
+                self.system_program.clone(),
+                pnft.sysvar_instructions.clone(),
+                self.token_program.clone(),
+                pnft.associated_token_program.clone(),
+                pnft.authorization_rules_program.clone(),
+                pnft.authorization_rules.clone(),
+                pnft.token_metadata_program.clone(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
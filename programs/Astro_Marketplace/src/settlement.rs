@@ -0,0 +1,636 @@
+use anchor_lang::prelude::*;
+use metaplex_token_metadata::state::Metadata;
+use solana_program::program::invoke_signed;
+use solana_program::system_instruction;
+
+use crate::account::*;
+use crate::constants::*;
+use crate::error::*;
+use crate::math::*;
+use crate::pnft::*;
+use crate::token_interface::*;
+
+/**
+ * Marketplace fee rate of a trade in permyriad
+ *
+ * The Collection Fee PDA overrides the global fee when it exists for the NFT collection
+ * The rate is discounted by the fee tier of the buyer or the seller traded volume before the trade
+ */
+pub fn market_fee_rate(
+    global_authority: &GlobalPool,
+    collection: &Pubkey,
+    collection_fee: &AccountInfo,
+    buyer_user_pool: &UserData,
+    seller_user_pool: &UserData,
+    by_token: bool,
+) -> Result<u64> {
+    let fee_rate = base_fee_rate(global_authority, collection, collection_fee, by_token)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let (fee_tiers, by_seller) = global_authority.fee_tiers(timestamp);
+    let user_pool = if by_seller == 1 {
+        seller_user_pool
+    } else {
+        buyer_user_pool
+    };
+    let volume = if by_token {
+        user_pool.traded_token_volume
+    } else {
+        user_pool.traded_volume
+    };
+    let discount = FeeTier::discount(fee_tiers, volume, by_token);
+    if discount > 0 {
+        msg!(
+            "Trader: {:?}, Fee Discount: {}",
+            user_pool.address,
+            discount
+        );
+    }
+
+    fee_rate.safe_sub(fee_rate.permyriad(discount)?)
+}
+
+/**
+ * Marketplace fee rate of a trade in permyriad before the fee tier discount
+ *
+ * Offers and bids escrow the fee at this rate and the buyer is never charged a higher rate at settlement
+ */
+pub fn base_fee_rate(
+    global_authority: &GlobalPool,
+    collection: &Pubkey,
+    collection_fee: &AccountInfo,
+    by_token: bool,
+) -> Result<u64> {
+    let (address, _) = Pubkey::find_program_address(
+        &[COLLECTION_FEE_SEED.as_bytes(), collection.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        collection_fee.key(),
+        address,
+        MarketplaceError::InvalidCollectionFeeAccount
+    );
+
+    // Collections without override pay the global fee
+    let timestamp = Clock::get()?.unix_timestamp;
+    if collection_fee.owner != &crate::ID || collection_fee.data_is_empty() {
+        return Ok(global_authority.market_fee(by_token, timestamp));
+    }
+
+    let collection_fee = CollectionFee::try_deserialize(&mut &collection_fee.data.borrow()[..])?;
+    match collection_fee.market_fee(by_token, timestamp) {
+        Some(fee) => {
+            msg!("Collection Fee: {:?}, Fee: {}", collection, fee);
+            Ok(fee)
+        }
+        None => Ok(global_authority.market_fee(by_token, timestamp)),
+    }
+}
+
+/**
+ * Resolve the fee payer of a listing or an auction
+ *
+ * Params:  fee_mode - 0: global default, 1: seller, 2: buyer
+ */
+pub fn resolve_buyer_pays_fee(global_authority: &GlobalPool, fee_mode: u8) -> Result<u64> {
+    match fee_mode {
+        0 => Ok(global_authority.buyer_pays_fee),
+        1 => Ok(0),
+        2 => Ok(1),
+        _ => Err(error!(MarketplaceError::InvalidParamInput)),
+    }
+}
+
+/**
+ * Royalty terms of the NFT
+ *
+ * Creator shares are in percent
+ */
+pub struct Royalty {
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<(Pubkey, u8)>,
+}
+
+impl Royalty {
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        let creators = match &metadata.data.creators {
+            Some(creators) => creators
+                .iter()
+                .map(|creator| (creator.address, creator.share))
+                .collect(),
+            None => vec![],
+        };
+        Royalty {
+            seller_fee_basis_points: metadata.data.seller_fee_basis_points,
+            creators,
+        }
+    }
+}
+
+/**
+ * Payout breakdown of a sale price
+ *
+ * total = price + fee_amount when the buyer pays the fee, price otherwise
+ * seller_amount = total - fee_amount - sum of royalties (+ fee dust for the seller dust policy)
+ * The seller and the creators bear the Token-2022 transfer fee of their own abb payouts
+ * while the transfer fee of the fee vault payout is netted out of the fee accruals
+ */
+pub struct Payout {
+    pub price: u64,
+    // Amount paid by the buyer
+    pub total: u64,
+    pub fee_amount: u64,
+    // Referrer share of the fee
+    pub referral_amount: u64,
+    // Fee share of each team treasury in TreasuryConfig order
+    pub treasury_amounts: Vec<u64>,
+    // Royalty of each Metadata creator in Metadata order, item after item for bundles
+    pub royalties: Vec<(Pubkey, u64)>,
+    pub seller_amount: u64,
+    // Token-2022 transfer fee withheld from the fee vault payout
+    pub vault_transfer_fee: u64,
+}
+
+impl Payout {
+    /**
+     * Split the price into marketplace fee, treasury shares, creator royalties and seller proceeds
+     *
+     * Params:  price - sale price
+     *          fee_rate - marketplace fee in permyriad
+     *          with_referral - the trade has a referrer taking the referral share of the fee
+     *          buyer_pays_fee - the fee is charged to the buyer on top of the price
+     *          payment_mint - the abb mint for token payouts, None for SOL payouts
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn compute(
+        price: u64,
+        fee_rate: u64,
+        global_authority: &GlobalPool,
+        treasury_config: &TreasuryConfig,
+        royalty: &Royalty,
+        with_referral: bool,
+        buyer_pays_fee: bool,
+        payment_mint: Option<&AccountInfo>,
+    ) -> Result<Payout> {
+        Self::compute_bundle(
+            price,
+            fee_rate,
+            global_authority,
+            treasury_config,
+            std::slice::from_ref(royalty),
+            with_referral,
+            buyer_pays_fee,
+            payment_mint,
+        )
+    }
+
+    /**
+     * Split the price of a bundle like compute with the royalty of each item
+     *
+     * The price is split evenly between the items and the last item takes the rounding remainder
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn compute_bundle(
+        price: u64,
+        fee_rate: u64,
+        global_authority: &GlobalPool,
+        treasury_config: &TreasuryConfig,
+        royalties: &[Royalty],
+        with_referral: bool,
+        buyer_pays_fee: bool,
+        payment_mint: Option<&AccountInfo>,
+    ) -> Result<Payout> {
+        // At least one treasury should exist to trade NFT
+        require!(
+            !treasury_config.treasuries.is_empty(),
+            MarketplaceError::NoTeamTreasuryYet
+        );
+        let fee_amount = price.permyriad(fee_rate)?;
+        let total = if buyer_pays_fee {
+            price.safe_add(fee_amount)?
+        } else {
+            price
+        };
+        let mut referral_amount = if with_referral {
+            fee_amount.permyriad(global_authority.referral_share)?
+        } else {
+            0
+        };
+        // Team treasuries split the fee after the referral share
+        let team_fee_amount = fee_amount.safe_sub(referral_amount)?;
+
+        let mut treasury_amounts = vec![];
+        let mut treasury_total: u64 = 0;
+        for treasury in treasury_config.treasuries.iter() {
+            let amount = team_fee_amount.permyriad(treasury.rate)?;
+            treasury_total = treasury_total.safe_add(amount)?;
+            treasury_amounts.push(amount);
+        }
+        // Fee part not covered by the treasury rates or lost by rounding
+        let dust = team_fee_amount.safe_sub(treasury_total)?;
+
+        let item_price = price.safe_div(royalties.len() as u64)?;
+        let mut creator_royalties = vec![];
+        let mut royalty_total: u64 = 0;
+        for (i, royalty) in royalties.iter().enumerate() {
+            let item_price = if i + 1 == royalties.len() {
+                price.safe_sub(item_price.safe_mul(i as u64)?)?
+            } else {
+                item_price
+            };
+            let royalty_amount = item_price.permyriad(royalty.seller_fee_basis_points as u64)?;
+            for (address, share) in royalty.creators.iter() {
+                // Creator share is in percent
+                let amount = royalty_amount.safe_mul(*share as u64)?.safe_div(100)?;
+                royalty_total = royalty_total.safe_add(amount)?;
+                creator_royalties.push((*address, amount));
+            }
+        }
+
+        // Primary treasury or the first treasury if not designated
+        let index = treasury_config
+            .position(&global_authority.primary_treasury)
+            .unwrap_or(0);
+        let mut seller_amount = total.safe_sub(fee_amount)?.safe_sub(royalty_total)?;
+        if global_authority.dust_policy == 1 {
+            seller_amount = seller_amount.safe_add(dust)?;
+        } else {
+            treasury_amounts[index] = treasury_amounts[index].safe_add(dust)?;
+        }
+
+        // The fee vault receives the fee accruals less the transfer fee, withheld from the primary
+        // treasury first, then from the other treasuries in order and the referrer last
+        let mut vault_total = referral_amount;
+        for amount in treasury_amounts.iter() {
+            vault_total = vault_total.safe_add(*amount)?;
+        }
+        let vault_transfer_fee = match payment_mint {
+            Some(mint) => transfer_fee(mint, vault_total)?,
+            None => 0,
+        };
+        let mut withheld = vault_transfer_fee;
+        for i in std::iter::once(index).chain((0..treasury_amounts.len()).filter(|i| *i != index)) {
+            let amount = withheld.min(treasury_amounts[i]);
+            treasury_amounts[i] = treasury_amounts[i].safe_sub(amount)?;
+            withheld = withheld.safe_sub(amount)?;
+        }
+        referral_amount = referral_amount.safe_sub(withheld)?;
+        msg!(
+            "Price: {}, Total: {}, Fee: {}, Referral: {}, Dust: {}, Royalty: {}, Seller: {}, Transfer Fee: {}",
+            price,
+            total,
+            fee_amount,
+            referral_amount,
+            dust,
+            royalty_total,
+            seller_amount,
+            vault_transfer_fee
+        );
+
+        // Assert every unit paid by the buyer is distributed
+        let mut distributed = seller_amount
+            .safe_add(royalty_total)?
+            .safe_add(referral_amount)?
+            .safe_add(vault_transfer_fee)?;
+        for amount in treasury_amounts.iter() {
+            distributed = distributed.safe_add(*amount)?;
+        }
+        require_eq!(distributed, total, MarketplaceError::PayoutMismatch);
+
+        Ok(Payout {
+            price,
+            total,
+            fee_amount,
+            referral_amount,
+            treasury_amounts,
+            royalties: creator_royalties,
+            seller_amount,
+            vault_transfer_fee,
+        })
+    }
+}
+
+/**
+ * Accounts paying a Payout in SOL or abb
+ *
+ * payer is the buyer wallet or the escrow vault
+ * payment_mint is the abb mint of the token_program, the Token or the Token-2022 program
+ * signer_seeds is empty for the buyer wallet and the escrow vault seeds otherwise
+ */
+pub struct Settlement<'a, 'info> {
+    pub by_token: bool,
+    pub payer: AccountInfo<'info>,
+    pub payer_token_account: AccountInfo<'info>,
+    pub seller: AccountInfo<'info>,
+    pub seller_token_account: AccountInfo<'info>,
+    pub fee_vault: AccountInfo<'info>,
+    pub fee_vault_token_account: AccountInfo<'info>,
+    pub payment_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub signer_seeds: &'a [&'a [&'a [u8]]],
+}
+
+/**
+ * Settlement of a trade context taking the fee vault, the abb mint and the programs from the context
+ *
+ * Params:  accounts - the trade context accounts
+ *          payer, payer_token_account - the buyer wallet or the escrow vault with its abb account
+ *          seller, seller_token_account - the proceeds recipient with its abb account
+ *          signer_seeds - empty for the buyer wallet and the escrow vault seeds otherwise
+ */
+macro_rules! settlement {
+    (
+        $accounts:expr,
+        $by_token:expr,
+        $payer:ident,
+        $payer_token_account:ident,
+        $seller:ident,
+        $seller_token_account:ident,
+        $signer_seeds:expr
+    ) => {
+        Settlement {
+            by_token: $by_token,
+            payer: $accounts.$payer.to_account_info(),
+            payer_token_account: $accounts.$payer_token_account.to_account_info(),
+            seller: $accounts.$seller.to_account_info(),
+            seller_token_account: $accounts.$seller_token_account.to_account_info(),
+            fee_vault: $accounts.fee_vault.to_account_info(),
+            fee_vault_token_account: $accounts.fee_vault_token_account.to_account_info(),
+            payment_mint: $accounts.payment_mint.to_account_info(),
+            token_program: $accounts.token_program.to_account_info(),
+            system_program: $accounts.system_program.to_account_info(),
+            signer_seeds: $signer_seeds,
+        }
+    };
+}
+pub(crate) use settlement;
+
+impl<'a, 'info> Settlement<'a, 'info> {
+    /**
+     * Pay the seller and royalty creators and accrue the team treasury and referral fees in the fee vault
+     *
+     * remaining_accounts are the Metadata creators
+     * Those are wallets for SOL and abb ATAs for token payouts
+     */
+    pub fn execute(
+        &self,
+        payout: &Payout,
+        treasury_config: &mut TreasuryConfig,
+        referral: Option<&mut Account<'info, ReferralData>>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require_eq!(
+            remaining_accounts.len(),
+            payout.royalties.len(),
+            MarketplaceError::RoyaltyCreatorCountMismatch
+        );
+
+        if self.by_token {
+            self.pay(&self.seller_token_account, payout.seller_amount)?;
+        } else {
+            self.pay(&self.seller, payout.seller_amount)?;
+        }
+
+        // Accrue the treasury fees to be claimed by claim_treasury_fees
+        let mut fee_total: u64 = 0;
+        for (i, amount) in payout.treasury_amounts.iter().enumerate() {
+            let treasury = &mut treasury_config.treasuries[i];
+            if self.by_token {
+                treasury.claimable_token = treasury.claimable_token.safe_add(*amount)?;
+            } else {
+                treasury.claimable_sol = treasury.claimable_sol.safe_add(*amount)?;
+            }
+            fee_total = fee_total.safe_add(*amount)?;
+        }
+        // Accrue the referral fee to be claimed by claim_referral_fees
+        if let Some(referral) = referral {
+            if self.by_token {
+                referral.earned_token = referral.earned_token.safe_add(payout.referral_amount)?;
+                referral.claimable_token =
+                    referral.claimable_token.safe_add(payout.referral_amount)?;
+            } else {
+                referral.earned_sol = referral.earned_sol.safe_add(payout.referral_amount)?;
+                referral.claimable_sol = referral.claimable_sol.safe_add(payout.referral_amount)?;
+            }
+            referral.exit(&crate::ID)?;
+            fee_total = fee_total.safe_add(payout.referral_amount)?;
+        }
+        if self.by_token {
+            // The transfer fee withheld by the abb mint is paid on top of the accruals
+            fee_total = fee_total.safe_add(payout.vault_transfer_fee)?;
+            self.pay(&self.fee_vault_token_account, fee_total)?;
+        } else {
+            self.pay(&self.fee_vault, fee_total)?;
+        }
+
+        for (creator_account, (creator, amount)) in
+            remaining_accounts.iter().zip(payout.royalties.iter())
+        {
+            self.assert_recipient(
+                creator_account,
+                creator,
+                MarketplaceError::RoyaltyCreatorAddressMismatch,
+            )?;
+            self.pay(creator_account, *amount)?;
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Assert the account is the wallet for SOL or the initialized abb ATA of the wallet for token payouts
+     */
+    fn assert_recipient(
+        &self,
+        account: &AccountInfo<'info>,
+        wallet: &Pubkey,
+        error: MarketplaceError,
+    ) -> Result<()> {
+        if self.by_token {
+            // Get ATA of the Wallet
+            let ata = payment_ata(wallet, self.token_program.key);
+            require_keys_eq!(account.key(), ata, error);
+            // Assert ATA is Initialized
+            require_keys_eq!(*account.owner, *self.token_program.key, error);
+        } else {
+            require_keys_eq!(account.key(), *wallet, error);
+        }
+        Ok(())
+    }
+
+    fn pay(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        if self.by_token {
+            transfer_checked(
+                &self.token_program,
+                &self.payer_token_account,
+                &self.payment_mint,
+                to,
+                &self.payer,
+                amount,
+                self.signer_seeds,
+            )?;
+        } else {
+            invoke_signed(
+                &system_instruction::transfer(self.payer.key, to.key, amount),
+                &[self.payer.clone(), to.clone(), self.system_program.clone()],
+                self.signer_seeds,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/**
+ * Load the Referral Data PDA of the trade referrer
+ *
+ * The program id is passed as referral_data for the trade without referrer
+ * Only referrers approved by super_admin earn and the traders can't refer their own trade
+ */
+pub fn load_referral<'info>(
+    referral_data: &AccountInfo<'info>,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+) -> Result<Option<Account<'info, ReferralData>>> {
+    if referral_data.key().eq(&crate::ID) {
+        return Ok(None);
+    }
+
+    let referral = Account::<ReferralData>::try_from(referral_data)?;
+    let address = Pubkey::create_program_address(
+        &[
+            REFERRAL_DATA_SEED.as_bytes(),
+            referral.referrer.as_ref(),
+            &[referral.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| error!(MarketplaceError::InvalidReferralAccount))?;
+    require_keys_eq!(
+        address,
+        referral_data.key(),
+        MarketplaceError::InvalidReferralAccount
+    );
+    // Assert the traders are not referring the own trade
+    require_keys_neq!(referral.referrer, *buyer, MarketplaceError::SelfReferral);
+    require_keys_neq!(referral.referrer, *seller, MarketplaceError::SelfReferral);
+    require_eq!(referral.approved, 1, MarketplaceError::ReferralNotApproved);
+    msg!("Referrer: {:?}", referral.referrer);
+
+    Ok(Some(referral))
+}
+
+/**
+ * Transfer the escrowed NFT to the receiver and close the emptied escrow NFT token account
+ *
+ * nft_transfer.token is the escrow NFT token account owned by the global authority
+ * The rent of the closed token account goes to rent_receiver
+ * The escrow token account stays open while it holds the units left in a listing
+ * The escrow token account of pNFTs stays open as Token Metadata keeps it frozen
+ */
+pub fn release_nft<'info>(
+    global_authority: &Account<'info, GlobalPool>,
+    nft_transfer: NftTransfer<'info>,
+    rent_receiver: AccountInfo<'info>,
+    pnft: Option<&PnftAccounts<'info>>,
+) -> Result<()> {
+    let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_authority.bump]];
+    let signer = &[&seeds[..]];
+
+    nft_transfer.execute(pnft, signer)?;
+    if pnft.is_some() || token_amount(&nft_transfer.token)? > 0 {
+        return Ok(());
+    }
+
+    close_account(
+        &nft_transfer.token_program,
+        &nft_transfer.token,
+        &rent_receiver,
+        &global_authority.to_account_info(),
+        signer,
+    )
+}
+
+/**
+ * Release the NFTs escrowed by the swap offer to the receiver
+ *
+ * items are the offered items in the offered order,
+ * each of the NFT mint, the escrow NFT token account and the receiver NFT token account
+ * The rent of the closed escrow token accounts goes back to the buyer
+ */
+pub fn release_swap_items<'info>(
+    global_authority: &Account<'info, GlobalPool>,
+    swap_offer: &SwapOfferData,
+    items: &[AccountInfo<'info>],
+    receiver: AccountInfo<'info>,
+    buyer: AccountInfo<'info>,
+    nft_token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    require_eq!(
+        items.len(),
+        swap_offer.item_count as usize * SWAP_RELEASE_ACCOUNT_COUNT,
+        MarketplaceError::InvalidSwapItemAccounts
+    );
+    for (i, item) in items.chunks(SWAP_RELEASE_ACCOUNT_COUNT).enumerate() {
+        let (nft_mint, dest_nft_token_account, receiver_token_account) =
+            (&item[0], &item[1], &item[2]);
+        require_keys_eq!(
+            nft_mint.key(),
+            swap_offer.offered_mints[i],
+            MarketplaceError::InvalidSwapItemAccounts
+        );
+        require!(
+            is_nft_token_account(
+                dest_nft_token_account,
+                &nft_mint.key(),
+                &global_authority.key(),
+                Some(1)
+            ) && is_nft_token_account(receiver_token_account, &nft_mint.key(), receiver.key, None),
+            MarketplaceError::InvalidNftTokenAccount
+        );
+
+        release_nft(
+            global_authority,
+            NftTransfer {
+                token: dest_nft_token_account.clone(),
+                token_owner: global_authority.to_account_info(),
+                destination: receiver_token_account.clone(),
+                destination_owner: receiver.clone(),
+                mint: nft_mint.clone(),
+                amount: 1,
+                payer: receiver.clone(),
+                token_program: nft_token_program.clone(),
+                system_program: system_program.clone(),
+            },
+            buyer.clone(),
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+/**
+ * Add the sale price to the traded volume of both traders
+ */
+pub fn record_volume(
+    buyer_user_pool: &mut UserData,
+    seller_user_pool: &mut UserData,
+    by_token: bool,
+    price: u64,
+) -> Result<()> {
+    if by_token {
+        buyer_user_pool.traded_token_volume =
+            buyer_user_pool.traded_token_volume.safe_add(price)?;
+        seller_user_pool.traded_token_volume =
+            seller_user_pool.traded_token_volume.safe_add(price)?;
+    } else {
+        buyer_user_pool.traded_volume = buyer_user_pool.traded_volume.safe_add(price)?;
+        seller_user_pool.traded_volume = seller_user_pool.traded_volume.safe_add(price)?;
+    }
+    Ok(())
+}
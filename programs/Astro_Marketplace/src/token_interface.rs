@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program::invoke_signed;
+use solana_program::program_pack::Pack;
+use spl_token::state::{Account as SplAccount, AccountState, Mint as SplMint};
+
+use crate::constants::*;
+use crate::error::*;
+use crate::math::*;
+
+pub fn token_2022_program_id() -> Pubkey {
+    TOKEN_2022_PROGRAM_ID.parse::<Pubkey>().unwrap()
+}
+
+/// The Token or the Token-2022 program
+pub fn is_token_program(key: &Pubkey) -> bool {
+    key.eq(&spl_token::id()) || key.eq(&token_2022_program_id())
+}
+
+/**
+ * Check the NFT token account of the Token or Token-2022 program
+ *
+ * Token-2022 accounts share the Token base layout and store the extensions after it
+ *
+ * Params:  amount - minimum token amount, None to skip the amount check
+ *          Escrow token accounts of the global authority are shared by the listings,
+ *          auctions, bundles and swap offers of a semi-fungible token
+ */
+pub fn is_nft_token_account(
+    account: &AccountInfo,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: Option<u64>,
+) -> bool {
+    if !is_token_program(account.owner) {
+        return false;
+    }
+    let data = account.data.borrow();
+    if data.len() < SplAccount::LEN {
+        return false;
+    }
+    let token_account = match SplAccount::unpack_from_slice(&data[..SplAccount::LEN]) {
+        Ok(token_account) => token_account,
+        Err(_) => return false,
+    };
+    token_account.state != AccountState::Uninitialized
+        && token_account.mint.eq(mint)
+        && token_account.owner.eq(owner)
+        && match amount {
+            Some(amount) => token_account.amount >= amount,
+            None => true,
+        }
+}
+
+/**
+ * Check the abb token account of the Token or Token-2022 program
+ */
+pub fn is_payment_token_account(account: &AccountInfo, owner: &Pubkey) -> bool {
+    is_nft_token_account(
+        account,
+        &REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
+        owner,
+        None,
+    )
+}
+
+/**
+ * abb ATA of the wallet under the Token or Token-2022 program of the abb mint
+ */
+pub fn payment_ata(wallet: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    nft_ata(
+        wallet,
+        &REWARD_TOKEN_MINT_PUBKEY.parse::<Pubkey>().unwrap(),
+        token_program,
+    )
+}
+
+/**
+ * NFT ATA of the wallet under the Token or Token-2022 program of the NFT mint
+ */
+pub fn nft_ata(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    let (ata, _) = Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &spl_associated_token_account::id(),
+    );
+    ata
+}
+
+/**
+ * Token amount held by the token account of the Token or Token-2022 program
+ */
+pub fn token_amount(account: &AccountInfo) -> Result<u64> {
+    let data = account.data.borrow();
+    require!(
+        is_token_program(account.owner) && data.len() >= SplAccount::LEN,
+        MarketplaceError::InvalidNftTokenAccount
+    );
+    Ok(SplAccount::unpack_from_slice(&data[..SplAccount::LEN])?.amount)
+}
+
+/**
+ * Token-2022 transfer fee charged on the amount in the current epoch
+ *
+ * Mints without the TransferFeeConfig extension have no transfer fee
+ */
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if mint.owner != &token_2022_program_id() {
+        return Ok(0);
+    }
+    let data = mint.data.borrow();
+    // Extensions start after the account type byte following the padded base layout
+    let mut offset = SplAccount::LEN + 1;
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        offset += 4;
+        if extension_type == TRANSFER_FEE_CONFIG_EXTENSION {
+            require!(
+                offset + length <= data.len() && length >= 108,
+                MarketplaceError::InvalidNftTokenAccount
+            );
+            // Skip the authorities and the withheld amount to the older and newer transfer fees
+            let older = &data[offset + 72..offset + 90];
+            let newer = &data[offset + 90..offset + 108];
+            let epoch = Clock::get()?.epoch;
+            let newer_epoch = u64::from_le_bytes(newer[..8].try_into().unwrap());
+            let fee = if epoch >= newer_epoch { newer } else { older };
+            let maximum_fee = u64::from_le_bytes(fee[8..16].try_into().unwrap());
+            let basis_points = u16::from_le_bytes([fee[16], fee[17]]) as u64;
+            if basis_points == 0 || amount == 0 {
+                return Ok(0);
+            }
+            // Transfer fee is rounded up
+            let fee_amount = amount
+                .safe_mul(basis_points)?
+                .safe_add(PERMYRIAD - 1)?
+                .safe_div(PERMYRIAD)?;
+            return Ok(fee_amount.min(maximum_fee));
+        }
+        offset += length;
+    }
+    Ok(0)
+}
+
+/**
+ * Amount to transfer for the destination to receive at least the amount after the Token-2022 transfer fee
+ */
+pub fn gross_transfer_amount(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let mut gross = amount;
+    loop {
+        let received = gross.safe_sub(transfer_fee(mint, gross)?)?;
+        if received >= amount {
+            return Ok(gross);
+        }
+        gross = gross.safe_add(amount.safe_sub(received)?)?;
+    }
+}
+
+/**
+ * Assert the NFT arrives in full at the destination
+ *
+ * A Token-2022 transfer fee on a single token would keep the whole NFT as the fee
+ */
+pub fn assert_no_transfer_fee(mint: &AccountInfo) -> Result<()> {
+    require_eq!(transfer_fee(mint, 1)?, 0, MarketplaceError::NftTransferFee);
+    Ok(())
+}
+
+/**
+ * TransferChecked by the Token or the Token-2022 program
+ *
+ * signer_seeds is empty for the wallet authority
+ */
+pub fn transfer_checked<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require!(
+        is_token_program(token_program.key),
+        MarketplaceError::InvalidTokenProgram
+    );
+    let decimals = {
+        let data = mint.data.borrow();
+        require!(
+            data.len() >= SplMint::LEN,
+            MarketplaceError::InvalidNftTokenAccount
+        );
+        SplMint::unpack_from_slice(&data[..SplMint::LEN])?.decimals
+    };
+
+    let mut data = vec![TOKEN_TRANSFER_CHECKED_IX];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    invoke_signed(
+        &Instruction {
+            program_id: token_program.key(),
+            accounts: vec![
+                AccountMeta::new(from.key(), false),
+                AccountMeta::new_readonly(mint.key(), false),
+                AccountMeta::new(to.key(), false),
+                AccountMeta::new_readonly(authority.key(), true),
+            ],
+            data,
+        },
+        &[
+            from.clone(),
+            mint.clone(),
+            to.clone(),
+            authority.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/**
+ * CloseAccount by the Token or the Token-2022 program
+ */
+pub fn close_account<'info>(
+    token_program: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require!(
+        is_token_program(token_program.key),
+        MarketplaceError::InvalidTokenProgram
+    );
+    invoke_signed(
+        &Instruction {
+            program_id: token_program.key(),
+            accounts: vec![
+                AccountMeta::new(account.key(), false),
+                AccountMeta::new(destination.key(), false),
+                AccountMeta::new_readonly(owner.key(), true),
+            ],
+            data: vec![TOKEN_CLOSE_ACCOUNT_IX],
+        },
+        &[
+            account.clone(),
+            destination.clone(),
+            owner.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
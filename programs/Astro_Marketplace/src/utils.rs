@@ -1,17 +1,254 @@
-use {
-    crate::StakingError,
-    anchor_lang::{
-        prelude::{AccountInfo, ProgramError, ProgramResult, Pubkey},
-        solana_program::{
-            program_pack::{IsInitialized, Pack},
-        },
-    },
-};
-
-pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
-    if account.owner != owner {
-        Err(ProgramError::from(StakingError::InvalidOwner))
+use anchor_lang::prelude::*;
+use metaplex_token_metadata::state::Metadata;
+use solana_program::program::invoke;
+use solana_program::system_instruction;
+
+use crate::account::*;
+use crate::constants::*;
+use crate::error::MarketplaceError;
+use crate::math::*;
+
+pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
+    require_keys_eq!(*account.owner, *owner, MarketplaceError::InvalidOwner);
+    Ok(())
+}
+
+/**
+ * Load the Metaplex Metadata of the NFT
+ *
+ * Asserts the account is the Metadata PDA of the mint owned by the Metadata program
+ */
+pub fn load_metadata(nft_mint: &Pubkey, mint_metadata: &AccountInfo) -> Result<Metadata> {
+    msg!("Metadata Account: {:?}", mint_metadata.key());
+    let (metadata, _) = Pubkey::find_program_address(
+        &[
+            metaplex_token_metadata::state::PREFIX.as_bytes(),
+            metaplex_token_metadata::id().as_ref(),
+            nft_mint.as_ref(),
+        ],
+        &metaplex_token_metadata::id(),
+    );
+    require_keys_eq!(
+        metadata,
+        mint_metadata.key(),
+        MarketplaceError::InvaliedMetadata
+    );
+    assert_owned_by(mint_metadata, &metaplex_token_metadata::id())?;
+
+    // verify metadata is legit
+    Ok(Metadata::from_account_info(mint_metadata)?)
+}
+
+/**
+ * Metaplex Collection stored after the edition nonce and the token standard
+ */
+#[derive(AnchorDeserialize)]
+struct MetadataCollection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/**
+ * Get the verified Metaplex Collection mint of the NFT
+ *
+ * Legacy Metadata without the collection field has no verified collection
+ */
+pub fn get_verified_collection(mint_metadata: &AccountInfo) -> Option<Pubkey> {
+    let data = mint_metadata.data.borrow();
+    let mut data: &[u8] = &data;
+    Metadata::deserialize(&mut data).ok()?;
+    // Token Standard
+    Option::<u8>::deserialize(&mut data).ok()?;
+    let collection = Option::<MetadataCollection>::deserialize(&mut data).ok()??;
+    if collection.verified {
+        Some(collection.key)
+    } else {
+        None
+    }
+}
+
+/**
+ * Resolve the collection of the NFT
+ *
+ * Returns the collection address and the method used to resolve it
+ * 1: verified Metaplex Collection, 2: first verified creator when the creator fallback is enabled
+ */
+pub fn resolve_collection(
+    metadata: &Metadata,
+    mint_metadata: &AccountInfo,
+    creator_fallback: u64,
+) -> Result<(Pubkey, u64)> {
+    if let Some(collection) = get_verified_collection(mint_metadata) {
+        msg!("Verified Collection= {:?}", collection);
+        return Ok((collection, 1));
+    }
+    require_eq!(creator_fallback, 1, MarketplaceError::NoVerifiedCollection);
+    Ok((get_collection(metadata)?, 2))
+}
+
+/**
+ * Assert the verified collection is registered in the Collection Info PDA
+ */
+pub fn assert_registered_collection(
+    collection: &Pubkey,
+    collection_method: u64,
+    collection_info: &AccountInfo,
+) -> Result<()> {
+    let (address, _) = Pubkey::find_program_address(
+        &[COLLECTION_INFO_SEED.as_bytes(), collection.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        collection_info.key(),
+        address,
+        MarketplaceError::InvalidCollectionInfoAccount
+    );
+    // Creator fallback collections can't be registered
+    require!(
+        collection_method == 1
+            && collection_info.owner == &crate::ID
+            && !collection_info.data_is_empty(),
+        MarketplaceError::CollectionNotRegistered
+    );
+    Ok(())
+}
+
+/**
+ * Assert the traded mint and the traders have no Deny Entry PDA
+ *
+ * Params:  entries - the mint and the trader wallets with their Deny Entry PDA accounts
+ */
+pub fn assert_none_denied(entries: &[(Pubkey, &AccountInfo)]) -> Result<()> {
+    for (address, deny_entry) in entries.iter() {
+        assert_not_denied(address, deny_entry)?;
+    }
+    Ok(())
+}
+
+/**
+ * Assert the buyer may buy a listing, private listings are reserved for one buyer
+ */
+pub fn assert_reserved_buyer(reserved_buyer: &Pubkey, buyer: &Pubkey) -> Result<()> {
+    require!(
+        reserved_buyer.eq(&Pubkey::default()) || reserved_buyer.eq(buyer),
+        MarketplaceError::ReservedListing
+    );
+    Ok(())
+}
+
+/**
+ * Assert the mint or the wallet has no Deny Entry PDA
+ */
+pub fn assert_not_denied(address: &Pubkey, deny_entry: &AccountInfo) -> Result<()> {
+    let (entry, _) =
+        Pubkey::find_program_address(&[DENY_ENTRY_SEED.as_bytes(), address.as_ref()], &crate::ID);
+    require_keys_eq!(
+        deny_entry.key(),
+        entry,
+        MarketplaceError::InvalidDenyEntryAccount
+    );
+    require!(
+        deny_entry.owner != &crate::ID || deny_entry.data_is_empty(),
+        MarketplaceError::DeniedAddress
+    );
+    Ok(())
+}
+
+/**
+ * Load the User PDA of the wallet which may not exist
+ *
+ * Returns None when the wallet has not initialized the User PDA
+ */
+pub fn load_user_pool<'info>(
+    user: &Pubkey,
+    user_pool: &AccountInfo<'info>,
+) -> Result<Option<Account<'info, UserData>>> {
+    let (address, _) =
+        Pubkey::find_program_address(&[USER_DATA_SEED.as_bytes(), user.as_ref()], &crate::ID);
+    require_keys_eq!(
+        user_pool.key(),
+        address,
+        MarketplaceError::InvalidUserPoolAccount
+    );
+    if user_pool.owner != &crate::ID || user_pool.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Account::<UserData>::try_from(user_pool)?))
+}
+
+/**
+ * Load the Sell Data PDA of the NFT and the seller which may not exist
+ *
+ * Returns None when the seller never listed the NFT
+ */
+pub fn load_sell_data<'info>(
+    nft_mint: &Pubkey,
+    seller: &Pubkey,
+    sell_data: &AccountInfo<'info>,
+) -> Result<Option<Account<'info, SellData>>> {
+    let (address, _) = Pubkey::find_program_address(
+        &[
+            SELL_DATA_SEED.as_bytes(),
+            nft_mint.as_ref(),
+            seller.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        sell_data.key(),
+        address,
+        MarketplaceError::InvalidNFTDataAcount
+    );
+    if sell_data.owner != &crate::ID || sell_data.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Account::<SellData>::try_from(sell_data)?))
+}
+
+/**
+ * Get Collection address from Metadata
+ *
+ * The first verified creator is used as the collection
+ */
+pub fn get_collection(metadata: &Metadata) -> Result<Pubkey> {
+    if let Some(creators) = &metadata.data.creators {
+        let mut collection: Pubkey = Pubkey::default();
+        for creator in creators {
+            if creator.verified {
+                collection = creator.address;
+                break;
+            }
+        }
+        msg!("Collection= {:?}", collection);
+        Ok(collection)
     } else {
-        Ok(())
+        Err(error!(MarketplaceError::MetadataCreatorParseError))
+    }
+}
+
+/**
+ * Resize a program owned account keeping it rent exempt
+ *
+ * The payer funds the extra rent when growing and receives the released rent when shrinking
+ */
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_size: usize,
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(new_size);
+    let lamports = account.lamports();
+    if rent_exempt > lamports {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, rent_exempt.safe_sub(lamports)?),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    } else if lamports > rent_exempt {
+        let payer_lamports = payer.lamports().safe_add(lamports.safe_sub(rent_exempt)?)?;
+        **account.try_borrow_mut_lamports()? = rent_exempt;
+        **payer.try_borrow_mut_lamports()? = payer_lamports;
     }
+    account.realloc(new_size, false)?;
+    Ok(())
 }
@@ -1,6 +1,6 @@
 import * as anchor from "@project-serum/anchor";
 import { Program } from "@project-serum/anchor";
-import { Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { assert } from "chai";
 import fs from "fs";
 import {
@@ -12,10 +12,7 @@ import {
   createCreateAuctionTx,
   createDelistNftTx,
   createDepositTx,
-  createInitAuctionDataTx,
   createInitializeTx,
-  createInitOfferDataTx,
-  createInitSellDataTx,
   createInitUserTx,
   createListForSellNftTx,
   createMakeOfferTx,
@@ -25,13 +22,47 @@ import {
   createUpdateFeeTx,
   createWithdrawTx,
   getAuctionDataState,
+  getCollectionInfo,
+  getDenyEntry,
   getGlobalState,
   getNFTPoolState,
   getOfferDataState,
+  getReferralData,
+  getTreasuryConfigState,
   getUserPoolState,
 } from "../lib/scripts";
-import { ABB_TOKEN_DECIMAL, ABB_TOKEN_MINT, MARKETPLACE_PROGRAM_ID, SELL_DATA_SEED, USER_DATA_SEED } from "../lib/types";
-import { airdropSOL, createTokenMint, getAssociatedTokenAccount, getATokenAccountsNeedCreate, getEscrowBalance, getTokenAccountBalance, isExistAccount } from "../lib/utils";
+import {
+  ABB_TOKEN_DECIMAL,
+  ABB_TOKEN_MINT,
+  AUCTION_DATA_SEED,
+  BUNDLE_DATA_SEED,
+  COLLECTION_FEE_SEED,
+  ESCROW_VAULT_SEED,
+  FEE_VAULT_SEED,
+  GLOBAL_AUTHORITY_SEED,
+  MARKETPLACE_PROGRAM_ID,
+  OFFER_DATA_SEED,
+  SELL_DATA_SEED,
+  SWAP_OFFER_SEED,
+  TREASURY_CONFIG_SEED,
+  USER_DATA_SEED,
+} from "../lib/types";
+import {
+  airdropSOL,
+  createMasterEditionInstruction,
+  createMetadataInstruction,
+  createTokenMint,
+  getAssociatedTokenAccount,
+  getATokenAccountsNeedCreate,
+  getEscrowBalance,
+  getMasterEdition,
+  getMetadata,
+  getTokenAccountBalance,
+  isExistAccount,
+  METAPLEX,
+  MetadataCreator,
+  verifyCollectionInstruction,
+} from "../lib/utils";
 import { AstroMarketplace } from "../target/types/astro_marketplace";
 
 // Configure the client to use the local cluster.
@@ -48,6 +79,142 @@ let user1 = null;
 let reward = null;
 let nft = null;
 
+/** Get the marketplace PDA of the seed and the keys */
+const getPda = async (seed: string, ...keys: anchor.web3.PublicKey[]) => {
+  const [address] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from(seed), ...keys.map((key) => key.toBuffer())],
+    MARKETPLACE_PROGRAM_ID,
+  );
+  return address;
+}
+
+/** Send the transaction and wait for the confirmation */
+const sendTx = async (tx: anchor.web3.Transaction, signers: anchor.web3.Keypair[]) => {
+  const txId = await provider.connection.sendTransaction(tx, signers);
+  await provider.connection.confirmTransaction(txId, 'confirmed');
+  console.log("TxHash=", txId);
+  return txId;
+}
+
+/** Assert the transaction fails with the marketplace error */
+const assertError = async (transaction: Promise<any>, error: string) => {
+  try {
+    await transaction;
+  } catch (e) {
+    const logs = (e.logs || []).join('\n');
+    assert(e.toString().includes(error) || logs.includes(error), `Expected ${error} but got ${e}`);
+    return;
+  }
+  assert.fail(`Expected ${error} but the transaction succeeded`);
+}
+
+/**
+ * Create the metaplex metadata of the NFT minted by superOwner
+ * superOwner is the verified creator by default, the collection is verified when given
+ */
+const createNftMetadata = async (
+  mint: anchor.web3.PublicKey,
+  sellerFeeBasisPoints: number = 0,
+  creators: MetadataCreator[] = [{ address: superOwner.publicKey, verified: true, share: 100 }],
+  collection?: anchor.web3.PublicKey,
+) => {
+  const tx = new anchor.web3.Transaction();
+  tx.add(await createMetadataInstruction(mint, superOwner.publicKey, 'Astro Test', 'ASTRO', sellerFeeBasisPoints, creators, collection));
+  if (collection) tx.add(await verifyCollectionInstruction(mint, collection, superOwner.publicKey));
+  const txId = await provider.connection.sendTransaction(tx, [superOwner]);
+  await provider.connection.confirmTransaction(txId, 'confirmed');
+}
+
+/**
+ * Mint a new NFT with metadata in the owner ATA
+ * Semi-fungible tokens are minted with the amount more than one
+ */
+const createTestNft = async (
+  owner: anchor.web3.Keypair,
+  amount: number = 1,
+  sellerFeeBasisPoints: number = 0,
+  creators?: MetadataCreator[],
+  collection?: anchor.web3.PublicKey,
+) => {
+  const mint = await Token.createMint(
+    provider.connection,
+    superOwner,
+    superOwner.publicKey,
+    superOwner.publicKey,
+    0,
+    TOKEN_PROGRAM_ID,
+  );
+  const ownerNFTAccount = await mint.createAssociatedTokenAccount(owner.publicKey);
+  await mint.mintTo(ownerNFTAccount, superOwner, [], amount);
+  await createNftMetadata(mint.publicKey, sellerFeeBasisPoints, creators, collection);
+  return mint;
+}
+
+/** Create the collection NFT with the master edition held by superOwner */
+const createCollectionNft = async () => {
+  const collection = await createTestNft(superOwner);
+  const tx = new anchor.web3.Transaction();
+  tx.add(await createMasterEditionInstruction(collection.publicKey, superOwner.publicKey));
+  const txId = await provider.connection.sendTransaction(tx, [superOwner]);
+  await provider.connection.confirmTransaction(txId, 'confirmed');
+  return collection;
+}
+
+/** List the NFT of the seller for 1 SOL or 100 ABB, the collection is given for the restricted listing */
+const listNft = async (
+  mint: anchor.web3.PublicKey,
+  seller: anchor.web3.Keypair = user,
+  collection: anchor.web3.PublicKey = anchor.web3.PublicKey.default,
+) => {
+  await sendTx(await createListForSellNftTx(
+    mint,
+    seller.publicKey,
+    program as unknown as anchor.Program,
+    provider.connection,
+    1 * 1e9,
+    100 * ABB_TOKEN_DECIMAL,
+    0,
+    1,
+    anchor.web3.PublicKey.default,
+    collection,
+  ), [seller]);
+}
+
+/** Purchase the listed NFT of the seller by user1 with SOL */
+const purchaseNft = async (
+  mint: anchor.web3.PublicKey,
+  seller: anchor.web3.Keypair = user,
+  referrer?: anchor.web3.PublicKey,
+) => {
+  await sendTx(await createPurchaseTx(
+    mint,
+    seller.publicKey,
+    user1.publicKey,
+    false,
+    1,
+    program as unknown as anchor.Program,
+    provider.connection,
+    referrer,
+  ), [user1]);
+}
+
+/** List a new NFT of the seller and purchase it by user1, return the seller and treasury income of the sale */
+const purchaseNewNft = async (seller: anchor.web3.Keypair = user, referrer?: anchor.web3.PublicKey) => {
+  const newNft = await createTestNft(seller);
+  await listNft(newNft.publicKey, seller);
+  const sellerSol = await provider.connection.getBalance(seller.publicKey);
+  let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+  const claimableSol = treasuryInfo.treasuries[0].claimableSol.toNumber();
+
+  await purchaseNft(newNft.publicKey, seller, referrer);
+
+  treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+  return {
+    seller: (await provider.connection.getBalance(seller.publicKey)) - sellerSol,
+    treasury: treasuryInfo.treasuries[0].claimableSol.toNumber() - claimableSol,
+  };
+}
+
 describe("Astro_Marketplace Load Program Object & Prepare testers", () => {
   assert(program.programId.toBase58() == MARKETPLACE_PROGRAM_ID.toBase58(), "Program load Failure!");
 
@@ -76,6 +243,19 @@ describe("Astro_Marketplace Load Program Object & Prepare testers", () => {
     );
 
     assert(await isExistAccount(reward.publicKey, provider.connection), 'Create ABB Token mint failure!');
+
+    // superOwner is the royalty creator of the test NFTs
+    let {instructions} = await getATokenAccountsNeedCreate(
+      provider.connection,
+      superOwner.publicKey,
+      superOwner.publicKey,
+      [ABB_TOKEN_MINT],
+    );
+    if (instructions.length > 0) {
+      const tx = new anchor.web3.Transaction();
+      tx.add(instructions[0]);
+      await anchor.web3.sendAndConfirmTransaction(provider.connection, tx, [superOwner]);
+    }
   });
   it('Airdrop SOL for Testers', async () => {
     await airdropSOL(user.publicKey, 1000 * 1e9, provider.connection);
@@ -125,23 +305,26 @@ describe('Contract Creation', async () => {
 
     const tx = await createAddTreasuryTx(
       superOwner.publicKey,
-      program as unknown as anchor.Program,
       superOwner.publicKey,
       500,
+      'team',
+      program as unknown as anchor.Program,
+      provider.connection,
     );
     const txId = await provider.connection.sendTransaction(tx, [superOwner]);
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    globalInfo = await getGlobalState(program as unknown as anchor.Program);
-    assert(globalInfo.teamCount.toNumber() == 1, "No team treasury added");
-    assert(globalInfo.teamTreasury[0].toBase58() == superOwner.publicKey.toBase58(), "Superowner is team");
-    assert(globalInfo.treasuryRate[0].toNumber() == 500, "Superowner is treasury rate is 50%");
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries.length == 1, "No team treasury added");
+    assert(treasuryInfo.treasuries[0].address.toBase58() == superOwner.publicKey.toBase58(), "Superowner is team");
+    assert(treasuryInfo.treasuries[0].rate.toNumber() == 500, "Superowner is treasury rate is 5%");
   });
   it('Admin can remove himself from team', async () => {
     let globalInfo = await getGlobalState(program as unknown as anchor.Program);
     assert(globalInfo.superAdmin.toBase58() == superOwner.publicKey.toBase58(), "GlobalInfo Admin Address mismatch with SuperOwner Pubkey");
-    assert(globalInfo.teamCount.toNumber() == 1, "GlobalInfo Team Treasury Count is not 1");
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries.length == 1, "TreasuryConfig Team Treasury Count is not 1");
 
     const tx = await createRemoveTreasuryTx(
       superOwner.publicKey,
@@ -152,8 +335,8 @@ describe('Contract Creation', async () => {
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    globalInfo = await getGlobalState(program as unknown as anchor.Program);
-    assert(globalInfo.teamCount.toNumber() == 0, "Team treasury is still exist");
+    treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries.length == 0, "Team treasury is still exist");
   });
   it('Admin can add himself as team', async () => {
     let globalInfo = await getGlobalState(program as unknown as anchor.Program);
@@ -161,18 +344,32 @@ describe('Contract Creation', async () => {
 
     const tx = await createAddTreasuryTx(
       superOwner.publicKey,
-      program as unknown as anchor.Program,
       superOwner.publicKey,
       30,
+      'team',
+      program as unknown as anchor.Program,
+      provider.connection,
     );
     const txId = await provider.connection.sendTransaction(tx, [superOwner]);
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    globalInfo = await getGlobalState(program as unknown as anchor.Program);
-    assert(globalInfo.teamCount.toNumber() == 1, "No team treasury added");
-    assert(globalInfo.teamTreasury[0].toBase58() == superOwner.publicKey.toBase58(), "Superowner is team");
-    assert(globalInfo.treasuryRate[0].toNumber() == 30, "Superowner is treasury rate is 3% finally");
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries.length == 1, "No team treasury added");
+    assert(treasuryInfo.treasuries[0].address.toBase58() == superOwner.publicKey.toBase58(), "Superowner is team");
+    assert(treasuryInfo.treasuries[0].rate.toNumber() == 30, "Superowner is treasury rate is 0.3% finally");
+  });
+  it('Admin can allow the first verified creator as the collection', async () => {
+    await program.rpc.updateCollectionFallback(new anchor.BN(1), {
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+      },
+      signers: [superOwner],
+    });
+
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.creatorFallback.toNumber() == 1, "Creator Fallback is not enabled");
   });
 });
 
@@ -188,26 +385,9 @@ describe('NFT Listing / Cancel Listing', async () => {
     );
     console.log('NFT Address:', nft.publicKey.toBase58())
     assert(await isExistAccount(nft.publicKey, provider.connection), 'NFT Create Mint Failure');
-  });
-  it('User can init NFT SellData PDA', async () => {
-    const [nftData, _] = await anchor.web3.PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), nft.publicKey.toBuffer()],
-        MARKETPLACE_PROGRAM_ID,
-    );
-    
-    assert((await isExistAccount(nftData, provider.connection)) != true, 'NFT SellData PDA is Already Initialized');
 
-    const tx = await createInitSellDataTx(
-      nft.publicKey,
-      user.publicKey,
-      program as unknown as anchor.Program,
-    );
-    const txId = await provider.connection.sendTransaction(tx, [user]);
-    await provider.connection.confirmTransaction(txId, 'confirmed');
-    console.log("TxHash=", txId);
-
-    let nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
-    assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
+    await createNftMetadata(nft.publicKey);
+    assert(await isExistAccount(await getMetadata(nft.publicKey), provider.connection), 'NFT Create Metadata Failure');
   });
   it('Mint one NFT in my ATA for testing', async () => {
     const userNFTAccount = await nft.createAssociatedTokenAccount(
@@ -226,11 +406,11 @@ describe('NFT Listing / Cancel Listing', async () => {
   });
   it('User can list NFT for sale', async () => {
     const [nftData, _] = await anchor.web3.PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), nft.publicKey.toBuffer()],
+        [Buffer.from(SELL_DATA_SEED), nft.publicKey.toBuffer(), user.publicKey.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
     
-    assert((await isExistAccount(nftData, provider.connection)) == true, 'NFT SellData PDA is Not Initialized');
+    assert((await isExistAccount(nftData, provider.connection)) != true, 'NFT SellData PDA is Already Initialized');
 
     const tx = await createListForSellNftTx(
       nft.publicKey,
@@ -244,7 +424,7 @@ describe('NFT Listing / Cancel Listing', async () => {
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    let nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
+    let nftInfo = await getNFTPoolState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
     assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
     assert(nftInfo.priceSol.toNumber() == 1.2 * 1e9, "NFT SellData solPrice is not 1.2");
     assert(nftInfo.priceToken.toNumber() == 150 * ABB_TOKEN_DECIMAL, "NFT SellData TokenPrice is not 150");
@@ -252,13 +432,13 @@ describe('NFT Listing / Cancel Listing', async () => {
   });
   // it('User can cancel listing', async () => {
   //   const [nftData, _] = await anchor.web3.PublicKey.findProgramAddress(
-  //       [Buffer.from(SELL_DATA_SEED), nft.publicKey.toBuffer()],
+  //       [Buffer.from(SELL_DATA_SEED), nft.publicKey.toBuffer(), user.publicKey.toBuffer()],
   //       MARKETPLACE_PROGRAM_ID,
   //   );
     
   //   assert((await isExistAccount(nftData, provider.connection)) == true, 'NFT SellData PDA is Not Initialized');
 
-  //   let nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
+  //   let nftInfo = await getNFTPoolState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
   //   assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
   //   assert(nftInfo.seller.toBase58() == user.publicKey.toBase58(), "NFT Seller is not User Pubkey");
   //   assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
@@ -273,7 +453,7 @@ describe('NFT Listing / Cancel Listing', async () => {
   //   await provider.connection.confirmTransaction(txId, 'confirmed');
   //   console.log("TxHash=", txId);
 
-  //   nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
+  //   nftInfo = await getNFTPoolState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
   //   assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
   //   assert(nftInfo.active.toNumber() == 0, "NFT SellData is still actived");
   // });
@@ -351,7 +531,7 @@ describe('user1 Can Purchase NFT', async () => {
     assert(userInfo.address.toBase58() == user1.publicKey.toBase58(), "UserData Address mismatch with User1 Pubkey");
   });
   it('user1 can purchase NFT from user with ABB Token payment', async () => {
-    let nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
+    let nftInfo = await getNFTPoolState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
     assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
     assert(nftInfo.priceSol.toNumber() == 1.2 * 1e9, "NFT SellData solPrice is not 1.2");
     assert(nftInfo.priceToken.toNumber() == 150 * ABB_TOKEN_DECIMAL, "NFT SellData TokenPrice is not 150");
@@ -360,8 +540,10 @@ describe('user1 Can Purchase NFT', async () => {
 
     const tx = await createPurchaseTx(
       nft.publicKey,
+      user.publicKey,
       user1.publicKey,
       true,
+      1,
       program as unknown as anchor.Program,
       provider.connection,
     );
@@ -372,7 +554,8 @@ describe('user1 Can Purchase NFT', async () => {
     const user1ATA = await getAssociatedTokenAccount(user1.publicKey, nft.publicKey);
     assert((await getTokenAccountBalance(user1ATA, provider.connection)) == 1, 'Buyer NFT Account balance is zero');
     const userATA = await getAssociatedTokenAccount(user.publicKey, ABB_TOKEN_MINT);
-    assert((await getTokenAccountBalance(userATA, provider.connection)) == nftInfo.priceToken.toNumber() / ABB_TOKEN_DECIMAL, 'Seller ABB Account balance is not same with TokenPrice');
+    // The seller pays the 0.12% token fee by default
+    assert((await getTokenAccountBalance(userATA, provider.connection)) == nftInfo.priceToken.toNumber() * (10_000 - 12) / 10_000 / ABB_TOKEN_DECIMAL, 'Seller ABB Account balance is not TokenPrice without the fee');
     let buyerUserInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
     assert(buyerUserInfo.address.toBase58() == user1.publicKey.toBase58(), "UserData Address mismatch with User1 Pubkey");
     assert(buyerUserInfo.tradedTokenVolume.toNumber() == nftInfo.priceToken.toNumber(), "UserData TradeVolume is not priceToken");
@@ -382,11 +565,11 @@ describe('user1 Can Purchase NFT', async () => {
   });
   it('User1 can list NFT for sale', async () => {
     const [nftData, _] = await anchor.web3.PublicKey.findProgramAddress(
-        [Buffer.from(SELL_DATA_SEED), nft.publicKey.toBuffer()],
+        [Buffer.from(SELL_DATA_SEED), nft.publicKey.toBuffer(), user1.publicKey.toBuffer()],
         MARKETPLACE_PROGRAM_ID,
     );
     
-    assert((await isExistAccount(nftData, provider.connection)) == true, 'NFT SellData PDA is Not Initialized');
+    assert((await isExistAccount(nftData, provider.connection)) != true, 'NFT SellData PDA is Already Initialized');
 
     const tx = await createListForSellNftTx(
       nft.publicKey,
@@ -400,7 +583,7 @@ describe('user1 Can Purchase NFT', async () => {
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    let nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
+    let nftInfo = await getNFTPoolState(nft.publicKey, user1.publicKey, program as unknown as anchor.Program);
     assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
     assert(nftInfo.priceSol.toNumber() == 1.2 * 1e9, "NFT SellData solPrice is not 1.2");
     assert(nftInfo.priceToken.toNumber() == 150 * ABB_TOKEN_DECIMAL, "NFT SellData TokenPrice is not 150");
@@ -492,27 +675,6 @@ describe('Deposit / Withdraw Escrow Balance', async () => {
 });
 
 describe('Offer for Listed NFT', async () => {
-  it('User can init Offer Data for listed NFT', async () => {
-    let nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
-    assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
-    assert(nftInfo.seller.toBase58() == user1.publicKey.toBase58(), "NFT SellData Seller mismatch with User1 Pubkey");
-    assert(nftInfo.priceSol.toNumber() == 1.2 * 1e9, "NFT SellData solPrice is not 1.2");
-    assert(nftInfo.priceToken.toNumber() == 150 * ABB_TOKEN_DECIMAL, "NFT SellData TokenPrice is not 150");
-    assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
-
-    const tx = await createInitOfferDataTx(
-      nft.publicKey,
-      user.publicKey,
-      program as unknown as anchor.Program,
-    );
-    const txId = await provider.connection.sendTransaction(tx, [user]);
-    await provider.connection.confirmTransaction(txId, 'confirmed');
-    console.log("TxHash=", txId);
-
-    let offerInfo = await getOfferDataState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
-    assert(offerInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT OfferData Mint mismatch with NFT Pubkey");
-    assert(offerInfo.buyer.toBase58() == user.publicKey.toBase58(), "NFT OfferData Buyer mismatch with User Pubkey");
-  });
   it('User can make offer for listed NFT with depositing escrow', async () => {
     let userInfo = await getUserPoolState(user.publicKey, program as unknown as anchor.Program);
     assert(userInfo.address.toBase58() == user.publicKey.toBase58(), "UserData Address mismatch with User Pubkey");
@@ -521,6 +683,7 @@ describe('Offer for Listed NFT', async () => {
     
     const tx = await createMakeOfferTx(
       nft.publicKey,
+      user1.publicKey,
       user.publicKey,
       0.7 * 1e9,
       false,
@@ -531,7 +694,7 @@ describe('Offer for Listed NFT', async () => {
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    let offerInfo = await getOfferDataState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    let offerInfo = await getOfferDataState(nft.publicKey, user1.publicKey, user.publicKey, program as unknown as anchor.Program);
     assert(offerInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT OfferData Mint mismatch with NFT Pubkey");
     assert(offerInfo.buyer.toBase58() == user.publicKey.toBase58(), "NFT OfferData Buyer mismatch with User Pubkey");
     assert(offerInfo.offerPrice.toNumber() == 0.7 * 1e9, "NFT OfferData Offer Price is not 0.7 sol");
@@ -550,6 +713,7 @@ describe('Offer for Listed NFT', async () => {
     
     const tx = await createCancelOfferTx(
       nft.publicKey,
+      user1.publicKey,
       user.publicKey,
       program as unknown as anchor.Program,
     );
@@ -557,10 +721,10 @@ describe('Offer for Listed NFT', async () => {
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    let offerInfo = await getOfferDataState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    let offerInfo = await getOfferDataState(nft.publicKey, user1.publicKey, user.publicKey, program as unknown as anchor.Program);
     assert(offerInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT OfferData Mint mismatch with NFT Pubkey");
     assert(offerInfo.buyer.toBase58() == user.publicKey.toBase58(), "NFT OfferData Buyer mismatch with User Pubkey");
-    assert(offerInfo.active.toNumber() == 0, "NFT OfferData Active is not 1");
+    assert(offerInfo.active.toNumber() == 0, "NFT OfferData Active is not 0");
 
     userInfo = await getUserPoolState(user.publicKey, program as unknown as anchor.Program);
     assert(userInfo.escrowSolBalance.toNumber() == 0.7 * 1e9, "UserData Sol Balance is not 0.7");
@@ -574,6 +738,7 @@ describe('Offer for Listed NFT', async () => {
     
     const tx = await createMakeOfferTx(
       nft.publicKey,
+      user1.publicKey,
       user.publicKey,
       0.6 * 1e9,
       false,
@@ -584,7 +749,7 @@ describe('Offer for Listed NFT', async () => {
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    let offerInfo = await getOfferDataState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    let offerInfo = await getOfferDataState(nft.publicKey, user1.publicKey, user.publicKey, program as unknown as anchor.Program);
     assert(offerInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT OfferData Mint mismatch with NFT Pubkey");
     assert(offerInfo.buyer.toBase58() == user.publicKey.toBase58(), "NFT OfferData Buyer mismatch with User Pubkey");
     assert(offerInfo.offerPrice.toNumber() == 0.6 * 1e9, "NFT OfferData Offer Price is not 0.6 sol");
@@ -596,14 +761,14 @@ describe('Offer for Listed NFT', async () => {
     assert(userInfo.escrowTokenBalance.toNumber() == 0, "UserData Token Balance is not 0");
   });
   it('User1 can accept user\'s offer', async () => {
-    let nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
+    let nftInfo = await getNFTPoolState(nft.publicKey, user1.publicKey, program as unknown as anchor.Program);
     assert(nftInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
-    assert(nftInfo.seller.toBase58() == user1.publicKey, "NFT SellData seller is not User1 Pubkey");
+    assert(nftInfo.seller.toBase58() == user1.publicKey.toBase58(), "NFT SellData seller is not User1 Pubkey");
     assert(nftInfo.priceSol.toNumber() == 1.2 * 1e9, "NFT SellData solPrice is not 1.2");
     assert(nftInfo.priceToken.toNumber() == 150 * ABB_TOKEN_DECIMAL, "NFT SellData TokenPrice is not 150");
     assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
 
-    let offerInfo = await getOfferDataState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    let offerInfo = await getOfferDataState(nft.publicKey, user1.publicKey, user.publicKey, program as unknown as anchor.Program);
     assert(offerInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT OfferData Mint mismatch with NFT Pubkey");
     assert(offerInfo.buyer.toBase58() == user.publicKey.toBase58(), "NFT OfferData Buyer mismatch with User Pubkey");
     assert(offerInfo.offerPrice.toNumber() == 0.6 * 1e9, "NFT OfferData Offer Price is not 0.6 sol");
@@ -624,6 +789,7 @@ describe('Offer for Listed NFT', async () => {
 
     const tx = await createAcceptOfferTx(
       nft.publicKey,
+      user1.publicKey,
       user.publicKey,
       program as unknown as anchor.Program,
       provider.connection,
@@ -632,9 +798,9 @@ describe('Offer for Listed NFT', async () => {
     await provider.connection.confirmTransaction(txId, 'confirmed');
     console.log("TxHash=", txId);
 
-    nftInfo = await getNFTPoolState(nft.publicKey, program as unknown as anchor.Program);
+    nftInfo = await getNFTPoolState(nft.publicKey, user1.publicKey, program as unknown as anchor.Program);
     assert(nftInfo.active.toNumber() == 0, "NFT SellData is not 0");
-    offerInfo = await getOfferDataState(nft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    offerInfo = await getOfferDataState(nft.publicKey, user1.publicKey, user.publicKey, program as unknown as anchor.Program);
     assert(offerInfo.active.toNumber() == 0, "NFT OfferData Active is not 0");
     escrowBalance = await getEscrowBalance(provider.connection);
     assert(escrowBalance.sol == 1.6 * 1e9, 'Escrow Sol Balance is not 1.6');
@@ -647,19 +813,6 @@ describe('Offer for Listed NFT', async () => {
 });
 
 describe('Auction and Bid', async () => {
-  it('User can init auction Data for Creating Auction', async () => {
-    const tx = await createInitAuctionDataTx(
-      nft.publicKey,
-      user.publicKey,
-      program as unknown as anchor.Program,
-    );
-    const txId = await provider.connection.sendTransaction(tx, [user]);
-    await provider.connection.confirmTransaction(txId, 'confirmed');
-    console.log("TxHash=", txId);
-
-    let auctionInfo = await getAuctionDataState(nft.publicKey, program as unknown as anchor.Program);
-    assert(auctionInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT AuctionData Mint mismatch with NFT Pubkey");
-  });
   it('User can Create Auction',  async () => {
     let now = Math.floor(Date.now() / 1000);
     const tx = await createCreateAuctionTx(
@@ -690,6 +843,7 @@ describe('Auction and Bid', async () => {
       nft.publicKey,
       user1.publicKey,
       1.5 * 1e9,
+      false,
       program as unknown as anchor.Program,
       provider.connection,
     );
@@ -778,9 +932,1957 @@ describe('Auction and Bid', async () => {
 
     let auctionInfo = await getAuctionDataState(nft.publicKey, program as unknown as anchor.Program);
     assert(auctionInfo.mint.toBase58() == nft.publicKey.toBase58(), "NFT AuctionData Mint mismatch with NFT Pubkey");
-    assert(auctionInfo.status.toNumber() == 0, 'Status is not 0');
+    assert(auctionInfo.status.toNumber() == 3, 'Status is not 3');
 
     const user1ATA = await getAssociatedTokenAccount(user1.publicKey, nft.publicKey);
     assert((await getTokenAccountBalance(user1ATA, provider.connection)) == 1, 'Creator NFT Account balance is zero');
   });
-});
\ No newline at end of file
+});
+
+describe('Bid From Escrow Balance', async () => {
+  let auctionNft = null;
+
+  it('User can create SOL auction for a new NFT', async () => {
+    auctionNft = await createTestNft(user);
+    let now = Math.floor(Date.now() / 1000);
+    const tx = await createCreateAuctionTx(
+      auctionNft.publicKey,
+      user.publicKey,
+      1 * 1e9,
+      0.2 * 1e9,
+      false,
+      now + 1000,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await sendTx(tx, [user]);
+
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.creator.toBase58() == user.publicKey.toBase58(), "NFT AuctionData Creator mismatch with User Pubkey");
+    assert(auctionInfo.status.toNumber() == 1, 'Status is not 1');
+  });
+  it('User1 can bid from the escrow balance', async () => {
+    await sendTx(await createDepositTx(
+      user1.publicKey,
+      2 * 1e9,
+      0,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+    let userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    const escrowSol = userInfo.escrowSolBalance.toNumber();
+    const walletSol = await provider.connection.getBalance(user1.publicKey);
+
+    const tx = await createPlaceBidTx(
+      auctionNft.publicKey,
+      user1.publicKey,
+      1.2 * 1e9,
+      true,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await sendTx(tx, [user1]);
+
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.lastBidder.toBase58() == user1.publicKey.toBase58(), "LastBidder mismatch with User1 Pubkey");
+    assert(auctionInfo.highestBid.toNumber() == 1.2 * 1e9, 'HighestBid is not 1.2');
+    userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    assert(userInfo.escrowSolBalance.toNumber() == escrowSol - 1.2 * 1e9, "UserData Sol Balance is not reduced by the bid");
+    assert(auctionInfo.bidByEscrow.toNumber() == 1, "Escrow bid is not recorded as funded from the escrow balance");
+    // Only the transaction fee is paid from the wallet
+    assert(walletSol - (await provider.connection.getBalance(user1.publicKey)) < 0.01 * 1e9, "Bid is paid from the wallet");
+  });
+  it('User1 out-bid refund is credited to the escrow balance', async () => {
+    let userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    const escrowSol = userInfo.escrowSolBalance.toNumber();
+
+    const tx = await createPlaceBidTx(
+      auctionNft.publicKey,
+      superOwner.publicKey,
+      1.5 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await sendTx(tx, [superOwner]);
+
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.lastBidder.toBase58() == superOwner.publicKey.toBase58(), "LastBidder mismatch with SuperOwner Pubkey");
+    userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    assert(userInfo.escrowSolBalance.toNumber() == escrowSol + 1.2 * 1e9, "Out-bid refund is not credited to the escrow balance");
+  });
+  it('User1 can not bid from the escrow more than the escrow balance', async () => {
+    let userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+
+    const tx = await createPlaceBidTx(
+      auctionNft.publicKey,
+      user1.publicKey,
+      userInfo.escrowSolBalance.toNumber() + 100 * 1e9,
+      true,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await assertError(sendTx(tx, [user1]), 'InsufficientBidderSolBalance');
+
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.lastBidder.toBase58() == superOwner.publicKey.toBase58(), "LastBidder is changed by the rejected bid");
+  });
+  it('User1 out-bid refund of the wallet bid is sent to the wallet', async () => {
+    await sendTx(await createPlaceBidTx(
+      auctionNft.publicKey,
+      user1.publicKey,
+      1.8 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.bidByEscrow.toNumber() == 0, "Wallet bid is recorded as funded from the escrow balance");
+
+    let userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    const escrowSol = userInfo.escrowSolBalance.toNumber();
+    const walletSol = await provider.connection.getBalance(user1.publicKey);
+
+    await sendTx(await createPlaceBidTx(
+      auctionNft.publicKey,
+      superOwner.publicKey,
+      2 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [superOwner]);
+
+    userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    assert(userInfo.escrowSolBalance.toNumber() == escrowSol, "Out-bid refund of the wallet bid is credited to the escrow balance");
+    assert((await provider.connection.getBalance(user1.publicKey)) == walletSol + 1.8 * 1e9, "Out-bid refund is not sent to the wallet");
+  });
+  it('Bidder without User PDA can claim the won auction', async () => {
+    const walletBidder = anchor.web3.Keypair.generate();
+    await airdropSOL(walletBidder.publicKey, 10 * 1e9, provider.connection);
+    const claimNft = await createTestNft(user);
+    let now = Math.floor(Date.now() / 1000);
+    await sendTx(await createCreateAuctionTx(
+      claimNft.publicKey,
+      user.publicKey,
+      1 * 1e9,
+      0.2 * 1e9,
+      false,
+      now + 10,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user]);
+    await sendTx(await createPlaceBidTx(
+      claimNft.publicKey,
+      walletBidder.publicKey,
+      1.2 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [walletBidder]);
+    assert((await isExistAccount(await getPda(USER_DATA_SEED, walletBidder.publicKey), provider.connection)) != true, 'Bidder User PDA is initialized');
+
+    await new Promise((resolve) => setTimeout(resolve, 11000));
+    await sendTx(await createClaimAuctionTx(
+      claimNft.publicKey,
+      walletBidder.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [walletBidder]);
+
+    let auctionInfo = await getAuctionDataState(claimNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.status.toNumber() == 2, 'Status is not 2');
+    const bidderATA = await getAssociatedTokenAccount(walletBidder.publicKey, claimNft.publicKey);
+    assert((await getTokenAccountBalance(bidderATA, provider.connection)) == 1, 'Winner NFT Account balance is zero');
+  });
+});
+
+describe('Admin Force Cancel Auction', async () => {
+  let auctionNft = null;
+
+  const forceCancel = async (admin: anchor.web3.Keypair, reason: number, bidderTokenAccount?: anchor.web3.PublicKey) => {
+    const globalAuthority = await getPda(GLOBAL_AUTHORITY_SEED);
+    const escrowVault = await getPda(ESCROW_VAULT_SEED);
+    await program.rpc.forceCancelAuction(new anchor.BN(reason), {
+      accounts: {
+        admin: admin.publicKey,
+        globalAuthority,
+        auctionDataInfo: await getPda(AUCTION_DATA_SEED, auctionNft.publicKey),
+        creator: user.publicKey,
+        userTokenAccount: await getAssociatedTokenAccount(user.publicKey, auctionNft.publicKey),
+        destNftTokenAccount: await getAssociatedTokenAccount(globalAuthority, auctionNft.publicKey),
+        nftMint: auctionNft.publicKey,
+        escrowVault,
+        escrowTokenAccount: await getAssociatedTokenAccount(escrowVault, ABB_TOKEN_MINT),
+        bidder: user1.publicKey,
+        bidderTokenAccount: bidderTokenAccount || await getAssociatedTokenAccount(user1.publicKey, ABB_TOKEN_MINT),
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: ABB_TOKEN_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      },
+      signers: [admin],
+    });
+  }
+
+  it('User1 can bid for the new SOL auction of user', async () => {
+    auctionNft = await createTestNft(user);
+    let now = Math.floor(Date.now() / 1000);
+    await sendTx(await createCreateAuctionTx(
+      auctionNft.publicKey,
+      user.publicKey,
+      1 * 1e9,
+      0.2 * 1e9,
+      false,
+      now + 1000,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user]);
+    await sendTx(await createPlaceBidTx(
+      auctionNft.publicKey,
+      user1.publicKey,
+      1.2 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.lastBidder.toBase58() == user1.publicKey.toBase58(), "LastBidder mismatch with User1 Pubkey");
+  });
+  it('Creator can not cancel the auction with bid', async () => {
+    const tx = await createCancelAuctionTx(
+      auctionNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await assertError(sendTx(tx, [user]), 'AuctionHasBid');
+  });
+  it('Only the admin can force cancel the auction', async () => {
+    await assertError(forceCancel(user, 1), 'InvalidSuperOwner');
+
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.status.toNumber() == 1, 'Status is not 1');
+  });
+  it('Admin can force cancel the auction with bid', async () => {
+    const bidderSol = await provider.connection.getBalance(user1.publicKey);
+
+    // The abb account of the bidder is not needed to refund the SOL bid
+    await forceCancel(superOwner, 3, user1.publicKey);
+
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.status.toNumber() == 3, 'Status is not 3');
+    assert(auctionInfo.cancelReason.toNumber() == 3, 'CancelReason is not 3');
+    assert((await provider.connection.getBalance(user1.publicKey)) == bidderSol + 1.2 * 1e9, 'Last bidder is not refunded');
+    const userATA = await getAssociatedTokenAccount(user.publicKey, auctionNft.publicKey);
+    assert((await getTokenAccountBalance(userATA, provider.connection)) == 1, 'NFT is not returned to the creator');
+  });
+});
+
+describe('Close Finished Data Accounts', async () => {
+  let listedNft = null;
+
+  const closeSellData = async (payer: anchor.web3.Keypair) => {
+    await program.rpc.closeSellData({
+      accounts: {
+        payer: payer.publicKey,
+        sellDataInfo: await getPda(SELL_DATA_SEED, listedNft.publicKey, user.publicKey),
+        nftMint: listedNft.publicKey,
+      },
+      signers: [payer],
+    });
+  }
+
+  const closeAuctionData = async (payer: anchor.web3.Keypair, mint: anchor.web3.PublicKey) => {
+    const globalAuthority = await getPda(GLOBAL_AUTHORITY_SEED);
+    await program.rpc.closeAuctionData({
+      accounts: {
+        payer: payer.publicKey,
+        auctionDataInfo: await getPda(AUCTION_DATA_SEED, mint),
+        nftMint: mint,
+        globalAuthority,
+        destNftTokenAccount: await getAssociatedTokenAccount(globalAuthority, mint),
+      },
+      signers: [payer],
+    });
+  }
+
+  it('User can not close the live listing', async () => {
+    listedNft = await createTestNft(user);
+    await sendTx(await createListForSellNftTx(
+      listedNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+
+    await assertError(closeSellData(user), 'ActiveDataAccount');
+    assert(await isExistAccount(await getPda(SELL_DATA_SEED, listedNft.publicKey, user.publicKey), provider.connection), 'Live SellData PDA is closed');
+  });
+  it('Only the rent payer can close the delisted listing', async () => {
+    await sendTx(await createDelistNftTx(
+      listedNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user]);
+
+    await assertError(closeSellData(user1), 'RentPayerMismatch');
+  });
+  it('User can close the delisted listing to reclaim rent', async () => {
+    const sellData = await getPda(SELL_DATA_SEED, listedNft.publicKey, user.publicKey);
+    const rent = await provider.connection.getBalance(sellData);
+    const userSol = await provider.connection.getBalance(user.publicKey);
+
+    await closeSellData(user);
+
+    assert((await isExistAccount(sellData, provider.connection)) != true, 'SellData PDA is not closed');
+    // The rent payer also pays the transaction fee
+    assert((await provider.connection.getBalance(user.publicKey)) > userSol + rent - 0.01 * 1e9, 'SellData rent is not returned');
+  });
+  it('User can close the accepted offer data', async () => {
+    const offerData = await getPda(OFFER_DATA_SEED, nft.publicKey, user1.publicKey, user.publicKey);
+    let offerInfo = await getOfferDataState(nft.publicKey, user1.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(offerInfo.active.toNumber() == 0, "NFT OfferData Active is not 0");
+
+    await program.rpc.closeOfferData({
+      accounts: {
+        payer: user.publicKey,
+        offerDataInfo: offerData,
+        buyer: user.publicKey,
+        nftMint: nft.publicKey,
+      },
+      signers: [user],
+    });
+
+    assert((await isExistAccount(offerData, provider.connection)) != true, 'OfferData PDA is not closed');
+  });
+  it('User can not close the live auction data', async () => {
+    const auctionNft = await createTestNft(user);
+    let now = Math.floor(Date.now() / 1000);
+    await sendTx(await createCreateAuctionTx(
+      auctionNft.publicKey,
+      user.publicKey,
+      1 * 1e9,
+      0.2 * 1e9,
+      false,
+      now + 1000,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user]);
+
+    await assertError(closeAuctionData(user, auctionNft.publicKey), 'ActiveDataAccount');
+  });
+  it('The rent of the canceled auction data is returned to the first creator', async () => {
+    const auctionData = await getPda(AUCTION_DATA_SEED, nft.publicKey);
+    let auctionInfo = await getAuctionDataState(nft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.status.toNumber() == 3, 'Status is not 3');
+    assert(auctionInfo.payer.toBase58() == user.publicKey.toBase58(), 'AuctionData Payer is not User Pubkey');
+
+    // User1 created the last auction but user paid the rent
+    await assertError(closeAuctionData(user1, nft.publicKey), 'RentPayerMismatch');
+    await closeAuctionData(user, nft.publicKey);
+
+    assert((await isExistAccount(auctionData, provider.connection)) != true, 'AuctionData PDA is not closed');
+  });
+});
+
+describe('Create Data Accounts In The Action Instructions', async () => {
+  let listedNft = null;
+
+  it('User can list a new NFT with one instruction', async () => {
+    listedNft = await createTestNft(user);
+    const sellData = await getPda(SELL_DATA_SEED, listedNft.publicKey, user.publicKey);
+    assert((await isExistAccount(sellData, provider.connection)) != true, 'NFT SellData PDA is Already Initialized');
+
+    const tx = await createListForSellNftTx(
+      listedNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    );
+    assert(tx.instructions.filter((ix) => ix.programId.toBase58() == MARKETPLACE_PROGRAM_ID.toBase58()).length == 1, 'Listing needs more than one instruction');
+    await sendTx(tx, [user]);
+
+    let nftInfo = await getNFTPoolState(listedNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
+    assert(nftInfo.payer.toBase58() == user.publicKey.toBase58(), "NFT SellData Payer is not User Pubkey");
+  });
+  it('User1 can make offer with one instruction', async () => {
+    const tx = await createMakeOfferTx(
+      listedNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      0.8 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    assert(tx.instructions.filter((ix) => ix.programId.toBase58() == MARKETPLACE_PROGRAM_ID.toBase58()).length == 1, 'Offer needs more than one instruction');
+    await sendTx(tx, [user1]);
+
+    let offerInfo = await getOfferDataState(listedNft.publicKey, user.publicKey, user1.publicKey, program as unknown as anchor.Program);
+    assert(offerInfo.active.toNumber() == 1, "NFT OfferData Active is not 1");
+    assert(offerInfo.payer.toBase58() == user1.publicKey.toBase58(), "NFT OfferData Payer is not User1 Pubkey");
+  });
+  it('User1 can not make offer for the delisted NFT', async () => {
+    await sendTx(await createDelistNftTx(
+      listedNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user]);
+
+    const tx = await createMakeOfferTx(
+      listedNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      0.9 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await assertError(sendTx(tx, [user1]), 'OfferForNotListedNFT');
+
+    let offerInfo = await getOfferDataState(listedNft.publicKey, user.publicKey, user1.publicKey, program as unknown as anchor.Program);
+    assert(offerInfo.offerPrice.toNumber() == 0.8 * 1e9, "NFT OfferData is overwritten");
+  });
+  it('User can relist the delisted NFT in the same SellData PDA', async () => {
+    await sendTx(await createListForSellNftTx(
+      listedNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      2 * 1e9,
+      200 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+
+    let nftInfo = await getNFTPoolState(listedNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.mint.toBase58() == listedNft.publicKey.toBase58(), "NFT SellData Mint mismatch with NFT Pubkey");
+    assert(nftInfo.priceSol.toNumber() == 2 * 1e9, "NFT SellData solPrice is not 2");
+    assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
+    assert(nftInfo.payer.toBase58() == user.publicKey.toBase58(), "NFT SellData Payer is not User Pubkey");
+  });
+});
+
+describe('Canonical Bumps', async () => {
+  let listedNft = null;
+
+  it('Data accounts store the canonical bumps', async () => {
+    listedNft = await createTestNft(user);
+    await sendTx(await createListForSellNftTx(
+      listedNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+
+    const [, globalBump] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+      MARKETPLACE_PROGRAM_ID,
+    );
+    const [, sellBump] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from(SELL_DATA_SEED), listedNft.publicKey.toBuffer(), user.publicKey.toBuffer()],
+      MARKETPLACE_PROGRAM_ID,
+    );
+    const [, userBump] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from(USER_DATA_SEED), user.publicKey.toBuffer()],
+      MARKETPLACE_PROGRAM_ID,
+    );
+
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.bump == globalBump, "GlobalPool Bump is not canonical");
+    let nftInfo = await getNFTPoolState(listedNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.bump == sellBump, "NFT SellData Bump is not canonical");
+    let userInfo = await getUserPoolState(user.publicKey, program as unknown as anchor.Program);
+    assert(userInfo.bump == userBump, "UserData Bump is not canonical");
+  });
+  it('Data account seeds are checked with the stored bump', async () => {
+    // The SellData PDA of another NFT mint
+    await assertError(program.rpc.closeSellData({
+      accounts: {
+        payer: user.publicKey,
+        sellDataInfo: await getPda(SELL_DATA_SEED, listedNft.publicKey, user.publicKey),
+        nftMint: nft.publicKey,
+      },
+      signers: [user],
+    }), 'ConstraintSeeds');
+  });
+});
+
+describe('Checked Math Errors', async () => {
+  it('Purchase splits the price with the checked math', async () => {
+    const mathNft = await createTestNft(user);
+    await sendTx(await createListForSellNftTx(
+      mathNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    const claimableSol = treasuryInfo.treasuries[0].claimableSol.toNumber();
+
+    await sendTx(await createPurchaseTx(
+      mathNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+
+    // The seller pays the 1% sol fee by default
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 0.99 * 1e9, 'Seller Sol is not the price without the fee');
+    treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries[0].claimableSol.toNumber() == claimableSol + 0.01 * 1e9, 'Treasury claimable Sol is not the fee');
+  });
+  it('Purchase overflowing the price is rejected with MathOverflow', async () => {
+    const mathNft = await createTestNft(user, 2);
+    const globalAuthority = await getPda(GLOBAL_AUTHORITY_SEED);
+    let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+      provider.connection,
+      user.publicKey,
+      globalAuthority,
+      [mathNft.publicKey],
+    );
+    // Two units at 10^19 lamports overflow the u64 total price
+    await program.rpc.listNftForSale(
+      new anchor.BN('10000000000000000000'),
+      new anchor.BN(100 * ABB_TOKEN_DECIMAL),
+      0,
+      new anchor.BN(2),
+      anchor.web3.PublicKey.default, {
+      accounts: {
+        owner: user.publicKey,
+        globalAuthority,
+        sellDataInfo: await getPda(SELL_DATA_SEED, mathNft.publicKey, user.publicKey),
+        userTokenAccount: await getAssociatedTokenAccount(user.publicKey, mathNft.publicKey),
+        destNftTokenAccount: destinationAccounts[0],
+        nftMint: mathNft.publicKey,
+        mintMetadata: await getMetadata(mathNft.publicKey),
+        collectionInfo: await getCollectionInfo(anchor.web3.PublicKey.default),
+        mintDenyEntry: await getDenyEntry(mathNft.publicKey),
+        ownerDenyEntry: await getDenyEntry(user.publicKey),
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METAPLEX,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      instructions,
+      signers: [user],
+    });
+
+    const tx = await createPurchaseTx(
+      mathNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      2,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await assertError(sendTx(tx, [user1]), 'MathOverflow');
+
+    let nftInfo = await getNFTPoolState(mathNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.quantity.toNumber() == 2, "NFT SellData Quantity is changed by the rejected purchase");
+  });
+});
+
+describe('Shared Settlement Of Purchase And Offer', async () => {
+  const royaltyCreator = anchor.web3.Keypair.generate();
+  let royaltyNft = null;
+
+  // 5% royalty split 60% to superOwner and 40% to the royalty creator
+  const createRoyaltyNft = () => createTestNft(user, 1, 500, [
+    { address: superOwner.publicKey, verified: true, share: 60 },
+    { address: royaltyCreator.publicKey, verified: false, share: 40 },
+  ]);
+
+  it('Purchase with the royalty creators out of the metadata order is rejected', async () => {
+    royaltyNft = await createRoyaltyNft();
+    await sendTx(await createListForSellNftTx(
+      royaltyNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+
+    const tx = await createPurchaseTx(
+      royaltyNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    const keys = tx.instructions[tx.instructions.length - 1].keys;
+    const last = keys.length - 1;
+    [keys[last - 1], keys[last]] = [keys[last], keys[last - 1]];
+    await assertError(sendTx(tx, [user1]), 'RoyaltyCreatorAddressMismatch');
+  });
+  it('Purchase pays the seller, the treasury and the royalty creators', async () => {
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    const claimableSol = treasuryInfo.treasuries[0].claimableSol.toNumber();
+
+    await sendTx(await createPurchaseTx(
+      royaltyNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+
+    // 1 SOL - 1% fee - 5% royalty
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 940_000_000, 'Seller Sol is not 0.94');
+    assert((await provider.connection.getBalance(royaltyCreator.publicKey)) == 20_000_000, 'Royalty creator Sol is not 0.02');
+    treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries[0].claimableSol.toNumber() == claimableSol + 10_000_000, 'Treasury claimable Sol is not 0.01');
+  });
+  it('Accepting offer settles with the same payout breakdown', async () => {
+    royaltyNft = await createRoyaltyNft();
+    await sendTx(await createListForSellNftTx(
+      royaltyNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+    await sendTx(await createMakeOfferTx(
+      royaltyNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      0.8 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+    const creatorSol = await provider.connection.getBalance(royaltyCreator.publicKey);
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    const claimableSol = treasuryInfo.treasuries[0].claimableSol.toNumber();
+
+    await sendTx(await createAcceptOfferTx(
+      royaltyNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user]);
+
+    // 0.8 SOL - 1% fee - 5% royalty
+    assert((await provider.connection.getBalance(royaltyCreator.publicKey)) == creatorSol + 16_000_000, 'Royalty creator Sol is not increased by 0.016');
+    treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries[0].claimableSol.toNumber() == claimableSol + 8_000_000, 'Treasury claimable Sol is not increased by 0.008');
+    const user1ATA = await getAssociatedTokenAccount(user1.publicKey, royaltyNft.publicKey);
+    assert((await getTokenAccountBalance(user1ATA, provider.connection)) == 1, 'Buyer NFT Account balance is zero');
+  });
+});
+
+describe('Fee Dust Policy', async () => {
+  const updateDustPolicy = async (policy: number, primaryTreasury: anchor.web3.PublicKey) => {
+    await program.rpc.updateDustPolicy(new anchor.BN(policy), primaryTreasury, {
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        treasuryConfig: await getPda(TREASURY_CONFIG_SEED),
+      },
+      signers: [superOwner],
+    });
+  }
+
+  it('Admin can not designate the primary treasury out of the team treasuries', async () => {
+    await assertError(updateDustPolicy(0, user.publicKey), 'TreasuryAddressNotFound');
+  });
+  it('Fee dust goes to the seller with the seller policy', async () => {
+    await updateDustPolicy(1, anchor.web3.PublicKey.default);
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.dustPolicy.toNumber() == 1, "Dust Policy is not seller");
+
+    // The 0.3% treasury rate takes 30_000 of the 0.01 SOL fee
+    const income = await purchaseNewNft();
+    assert(income.treasury == 30_000, 'Treasury claimable Sol is not the treasury rate of the fee');
+    assert(income.seller == 1 * 1e9 - 30_000, 'Seller Sol does not include the fee dust');
+  });
+  it('Fee dust goes to the primary treasury with the treasury policy', async () => {
+    await updateDustPolicy(0, superOwner.publicKey);
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.dustPolicy.toNumber() == 0, "Dust Policy is not primary treasury");
+    assert(globalInfo.primaryTreasury.toBase58() == superOwner.publicKey.toBase58(), "Primary Treasury is not SuperOwner");
+
+    const income = await purchaseNewNft();
+    assert(income.treasury == 10_000_000, 'Treasury claimable Sol is not the whole fee');
+    assert(income.seller == 990_000_000, 'Seller Sol includes the fee dust');
+  });
+});
+
+describe('Claim Treasury Fees', async () => {
+  const claimTreasuryFees = async (treasury: anchor.web3.PublicKey, byToken: boolean) => {
+    const feeVault = await getPda(FEE_VAULT_SEED);
+    // Anyone can crank the claim
+    await program.rpc.claimTreasuryFees(byToken ? 1 : 0, {
+      accounts: {
+        payer: user1.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        treasuryConfig: await getPda(TREASURY_CONFIG_SEED),
+        feeVault,
+        feeVaultTokenAccount: await getAssociatedTokenAccount(feeVault, ABB_TOKEN_MINT),
+        treasury,
+        treasuryTokenAccount: await getAssociatedTokenAccount(treasury, ABB_TOKEN_MINT),
+        paymentMint: ABB_TOKEN_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      },
+      signers: [user1],
+    });
+  }
+
+  it('Treasury can claim the accrued SOL fees', async () => {
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    const claimableSol = treasuryInfo.treasuries[0].claimableSol.toNumber();
+    assert(claimableSol > 0, 'Treasury has no accrued Sol fees');
+    const treasurySol = await provider.connection.getBalance(superOwner.publicKey);
+
+    await claimTreasuryFees(superOwner.publicKey, false);
+
+    treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries[0].claimableSol.toNumber() == 0, 'Treasury claimable Sol is not 0');
+    assert((await provider.connection.getBalance(superOwner.publicKey)) == treasurySol + claimableSol, 'Treasury Sol is not increased by the claimable fees');
+  });
+  it('Treasury can claim the accrued ABB fees', async () => {
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    const claimableToken = treasuryInfo.treasuries[0].claimableToken.toNumber();
+    assert(claimableToken > 0, 'Treasury has no accrued ABB fees');
+    const treasuryATA = await getAssociatedTokenAccount(superOwner.publicKey, ABB_TOKEN_MINT);
+    const treasuryToken = await getTokenAccountBalance(treasuryATA, provider.connection);
+
+    await claimTreasuryFees(superOwner.publicKey, true);
+
+    treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries[0].claimableToken.toNumber() == 0, 'Treasury claimable ABB is not 0');
+    assert((await getTokenAccountBalance(treasuryATA, provider.connection)) == treasuryToken + claimableToken / ABB_TOKEN_DECIMAL, 'Treasury ABB is not increased by the claimable fees');
+  });
+  it('Fees can not be claimed twice or for non treasury wallets', async () => {
+    await assertError(claimTreasuryFees(superOwner.publicKey, false), 'NonZeroError');
+    await assertError(claimTreasuryFees(user.publicKey, false), 'TreasuryAddressNotFound');
+  });
+});
+
+describe('Resizable Treasury Config', async () => {
+  const treasuries = [...Array(9)].map(() => anchor.web3.Keypair.generate().publicKey);
+
+  const updateTeamTreasury = async (address: anchor.web3.PublicKey, rate: number, label: string) => {
+    let labelBytes = Buffer.alloc(32);
+    labelBytes.write(label);
+    await program.rpc.updateTeamTreasury(address, new anchor.BN(rate), [...labelBytes], {
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        treasuryConfig: await getPda(TREASURY_CONFIG_SEED),
+      },
+      signers: [superOwner],
+    });
+  }
+
+  it('Admin can add more than 8 team treasuries', async () => {
+    for (const address of treasuries) {
+      await sendTx(await createAddTreasuryTx(
+        superOwner.publicKey,
+        address,
+        10,
+        'partner',
+        program as unknown as anchor.Program,
+        provider.connection,
+      ), [superOwner]);
+    }
+
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries.length == 10, "Team treasury count is not 10");
+    assert(treasuryInfo.treasuries[9].address.toBase58() == treasuries[8].toBase58(), "Last team treasury mismatch");
+  });
+  it('Admin can update the team treasury rate and label in place', async () => {
+    await updateTeamTreasury(treasuries[0], 20, 'partner-a');
+
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries[1].address.toBase58() == treasuries[0].toBase58(), "Updated team treasury is moved");
+    assert(treasuryInfo.treasuries[1].rate.toNumber() == 20, "Team treasury rate is not 20");
+    assert(Buffer.from(treasuryInfo.treasuries[1].label).toString().replace(/\0/g, '') == 'partner-a', "Team treasury label is not updated");
+  });
+  it('Admin can not update unknown treasury or exceed the full rate', async () => {
+    await assertError(updateTeamTreasury(user.publicKey, 20, 'unknown'), 'TreasuryAddressNotFound');
+    await assertError(updateTeamTreasury(treasuries[0], 10_000, 'partner-a'), 'MaxTreasuryRateSumExceed');
+  });
+  it('Admin can remove the added team treasuries', async () => {
+    for (const address of treasuries) {
+      await sendTx(await createRemoveTreasuryTx(
+        superOwner.publicKey,
+        program as unknown as anchor.Program,
+        address,
+      ), [superOwner]);
+    }
+
+    let treasuryInfo = await getTreasuryConfigState(program as unknown as anchor.Program);
+    assert(treasuryInfo.treasuries.length == 1, "Team treasury count is not 1");
+    assert(treasuryInfo.treasuries[0].address.toBase58() == superOwner.publicKey.toBase58(), "Superowner is not the first team treasury");
+  });
+});
+
+describe('Collection Fee Override', async () => {
+  let collection = null;
+
+  const setCollectionFee = async (admin: anchor.web3.Keypair, collectionKey: anchor.web3.PublicKey, solFee: number, tokenFee: number) => {
+    await program.rpc.setCollectionFee(new anchor.BN(solFee), new anchor.BN(tokenFee), {
+      accounts: {
+        admin: admin.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        collectionFee: await getPda(COLLECTION_FEE_SEED, collectionKey),
+        collection: collectionKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      signers: [admin],
+    });
+  }
+
+  it('Admin can lower the fee of a partnered collection', async () => {
+    collection = await createCollectionNft();
+    await setCollectionFee(superOwner, collection.publicKey, 50, 6);
+
+    const collectionFee = await program.account.collectionFee.fetch(await getPda(COLLECTION_FEE_SEED, collection.publicKey));
+    assert(collectionFee.marketFeeSol.toNumber() == 50, "Collection Sol Fee is not 0.5%");
+    assert(collectionFee.marketFeeToken.toNumber() == 6, "Collection Token Fee is not 0.06%");
+  });
+  it('Purchase of the collection NFT pays the collection fee', async () => {
+    const collectionNft = await createTestNft(user, 1, 0, undefined, collection.publicKey);
+    await sendTx(await createListForSellNftTx(
+      collectionNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+    let nftInfo = await getNFTPoolState(collectionNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.collection.toBase58() == collection.publicKey.toBase58(), "NFT SellData Collection is not the verified collection");
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+
+    await sendTx(await createPurchaseTx(
+      collectionNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 995_000_000, 'Seller Sol is not the price without the 0.5% collection fee');
+  });
+  it('Only the admin can set the collection fee for a real collection', async () => {
+    await assertError(setCollectionFee(user, collection.publicKey, 10, 1), 'InvalidSuperOwner');
+    await assertError(setCollectionFee(superOwner, anchor.web3.PublicKey.default, 10, 1), 'InvalidParamInput');
+  });
+});
+
+describe('Traded Volume Fee Tiers', async () => {
+  const whale = anchor.web3.Keypair.generate();
+  const whaleSeller = anchor.web3.Keypair.generate();
+
+  const updateFeeTiers = async (tiers: { solVolume: anchor.BN, tokenVolume: anchor.BN, discount: anchor.BN }[], bySeller: number) => {
+    await program.rpc.updateFeeTiers(tiers, new anchor.BN(bySeller), {
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+      },
+      signers: [superOwner],
+    });
+  }
+
+  // Sell a new NFT from whaleSeller to whale and return the seller income
+  const tradeNewNft = async (priceSol: number) => {
+    const tierNft = await createTestNft(whaleSeller);
+    await sendTx(await createListForSellNftTx(
+      tierNft.publicKey,
+      whaleSeller.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      priceSol,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [whaleSeller]);
+    const sellerSol = await provider.connection.getBalance(whaleSeller.publicKey);
+
+    await sendTx(await createPurchaseTx(
+      tierNft.publicKey,
+      whaleSeller.publicKey,
+      whale.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [whale]);
+    return (await provider.connection.getBalance(whaleSeller.publicKey)) - sellerSol;
+  }
+
+  it('Whale trades 500 SOL volume', async () => {
+    await airdropSOL(whale.publicKey, 1000 * 1e9, provider.connection);
+    await airdropSOL(whaleSeller.publicKey, 10 * 1e9, provider.connection);
+    await sendTx(await createInitUserTx(whale.publicKey, program as unknown as anchor.Program), [whale]);
+    await sendTx(await createInitUserTx(whaleSeller.publicKey, program as unknown as anchor.Program), [whaleSeller]);
+
+    // The whale pays the full 1% fee without tiers
+    assert((await tradeNewNft(500 * 1e9)) == 495 * 1e9, 'Seller Sol is not the price without the 1% fee');
+    let userInfo = await getUserPoolState(whale.publicKey, program as unknown as anchor.Program);
+    assert(userInfo.tradedVolume.toNumber() == 500 * 1e9, "UserData TradeVolume is not 500");
+  });
+  it('Admin can not set fee tiers out of order or more than 4 tiers', async () => {
+    const tier = (sol: number, discount: number) => {
+      return { solVolume: new anchor.BN(sol), tokenVolume: new anchor.BN(sol), discount: new anchor.BN(discount) };
+    };
+    await assertError(updateFeeTiers([tier(2, 1000), tier(1, 2000)], 0), 'InvalidFeeTierOrder');
+    await assertError(updateFeeTiers([tier(1, 1000), tier(2, 2000), tier(3, 3000), tier(4, 4000), tier(5, 5000)], 0), 'MaxFeeTierCountExceed');
+  });
+  it('Buyer above the volume tier pays the discounted fee', async () => {
+    // 50% discount from 500 SOL buyer volume, token volume is out of reach
+    await updateFeeTiers([{
+      solVolume: new anchor.BN(500 * 1e9),
+      tokenVolume: new anchor.BN('1000000000000000000'),
+      discount: new anchor.BN(5000),
+    }], 0);
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.feeTierCount.toNumber() == 1, "Fee Tier is not applied immediately");
+
+    assert((await tradeNewNft(1 * 1e9)) == 995_000_000, 'Seller Sol is not the price without the 0.5% discounted fee');
+  });
+});
+
+describe('Timelocked Fee Changes', async () => {
+  it('Fee increase is pending until the timelock passes', async () => {
+    let now = Math.floor(Date.now() / 1000);
+    await sendTx(await createUpdateFeeTx(
+      superOwner.publicKey,
+      program as unknown as anchor.Program,
+      200,
+      12,
+    ), [superOwner]);
+
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.marketFeeSol.toNumber() == 100, "Sol Fee is increased before the timelock");
+    assert(globalInfo.pendingFeeSol.toNumber() == 200, "Pending Sol Fee is not 2%");
+    assert(globalInfo.pendingFeeToken.toNumber() == 0, "Unchanged Token Fee is pending");
+    // The 2 days timelock from the update time
+    assert(globalInfo.feeActivationDate.toNumber() >= now + 172_800 - 60, "Fee Activation Date is earlier than the timelock");
+
+    const feeNft = await createTestNft(user);
+    await sendTx(await createListForSellNftTx(
+      feeNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+    ), [user]);
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+    await sendTx(await createPurchaseTx(
+      feeNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 990_000_000, 'Pending fee is charged before the timelock');
+  });
+  it('Fee decrease applies immediately and drops the pending increase', async () => {
+    await sendTx(await createUpdateFeeTx(
+      superOwner.publicKey,
+      program as unknown as anchor.Program,
+      100,
+      12,
+    ), [superOwner]);
+
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.marketFeeSol.toNumber() == 100, "Sol Fee is not 1%");
+    assert(globalInfo.pendingFeeSol.toNumber() == 0, "Pending Sol Fee is not dropped");
+    assert(globalInfo.feeActivationDate.toNumber() == 0, "Fee Activation Date is not cleared");
+  });
+  it('Fee above the cap is rejected', async () => {
+    const tx = await createUpdateFeeTx(
+      superOwner.publicKey,
+      program as unknown as anchor.Program,
+      1_001,
+      12,
+    );
+    await assertError(sendTx(tx, [superOwner]), 'InvalidFeePercent');
+  });
+});
+
+describe('Approved Referrals', async () => {
+  const referrer = anchor.web3.Keypair.generate();
+  const unapproved = anchor.web3.Keypair.generate();
+
+  const initReferral = async (owner: anchor.web3.Keypair) => {
+    await program.rpc.initReferral({
+      accounts: {
+        referrer: owner.publicKey,
+        referralData: await getReferralData(owner.publicKey),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      signers: [owner],
+    });
+  }
+  const approveReferral = async (admin: anchor.web3.Keypair, address: anchor.web3.PublicKey, approved: number) => {
+    await program.rpc.approveReferral(new anchor.BN(approved), {
+      accounts: {
+        admin: admin.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        referralData: await getReferralData(address),
+      },
+      signers: [admin],
+    });
+  }
+  const updateReferralShare = async (share: number) => {
+    await program.rpc.updateReferralShare(new anchor.BN(share), {
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+      },
+      signers: [superOwner],
+    });
+  }
+  it('Referrers can register and only the admin can approve them', async () => {
+    await airdropSOL(referrer.publicKey, 2 * 1e9, provider.connection);
+    await airdropSOL(unapproved.publicKey, 1 * 1e9, provider.connection);
+    await initReferral(referrer);
+    await initReferral(unapproved);
+
+    await assertError(approveReferral(user, referrer.publicKey, 1), 'InvalidSuperOwner');
+    await approveReferral(superOwner, referrer.publicKey, 1);
+    await updateReferralShare(2000);
+
+    const referralInfo = await program.account.referralData.fetch(await getReferralData(referrer.publicKey));
+    assert(referralInfo.approved.toNumber() == 1, "Referrer is not approved");
+  });
+  it('Approved referrer earns the referral share of the fee', async () => {
+    await purchaseNewNft(user, referrer.publicKey);
+
+    // 20% of the 1% fee of 1 SOL
+    const referralInfo = await program.account.referralData.fetch(await getReferralData(referrer.publicKey));
+    assert(referralInfo.earnedSol.toNumber() == 2_000_000, "Referral earning is not 20% of the fee");
+    assert(referralInfo.claimableSol.toNumber() == 2_000_000, "Referral claimable Sol is not 20% of the fee");
+  });
+  it('Referrer can claim the accrued referral fees', async () => {
+    const feeVault = await getPda(FEE_VAULT_SEED);
+    const ret = await getATokenAccountsNeedCreate(provider.connection, referrer.publicKey, referrer.publicKey, [ABB_TOKEN_MINT]);
+    if (ret.instructions.length > 0) await sendTx(new anchor.web3.Transaction().add(...ret.instructions), [referrer]);
+    const referrerSol = await provider.connection.getBalance(referrer.publicKey);
+
+    await program.rpc.claimReferralFees(0, {
+      accounts: {
+        referrer: referrer.publicKey,
+        referralData: await getReferralData(referrer.publicKey),
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        feeVault,
+        feeVaultTokenAccount: await getAssociatedTokenAccount(feeVault, ABB_TOKEN_MINT),
+        referrerTokenAccount: await getAssociatedTokenAccount(referrer.publicKey, ABB_TOKEN_MINT),
+        paymentMint: ABB_TOKEN_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      },
+      signers: [referrer],
+    });
+
+    const referralInfo = await program.account.referralData.fetch(await getReferralData(referrer.publicKey));
+    assert(referralInfo.claimableSol.toNumber() == 0, "Referral claimable Sol is not 0");
+    // The referrer pays the transaction fee of 5000 lamports
+    assert((await provider.connection.getBalance(referrer.publicKey)) == referrerSol + 2_000_000 - 5000, 'Referrer Sol is not increased by the referral fees');
+  });
+  it('Unapproved referrers and the traders can not refer the trade', async () => {
+    await assertError(purchaseNewNft(user, unapproved.publicKey), 'ReferralNotApproved');
+    await sendTx(await createInitUserTx(referrer.publicKey, program as unknown as anchor.Program), [referrer]);
+    await assertError(purchaseNewNft(referrer, referrer.publicKey), 'SelfReferral');
+
+    await updateReferralShare(0);
+  });
+});
+
+describe('Buyer Pays Fee', async () => {
+  const updateFeePayer = async (buyerPaysFee: number) => {
+    await program.rpc.updateFeePayer(new anchor.BN(buyerPaysFee), {
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+      },
+      signers: [superOwner],
+    });
+  }
+  const listNewNft = async (feeMode: number) => {
+    const feeNft = await createTestNft(user);
+    await sendTx(await createListForSellNftTx(
+      feeNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+      feeMode,
+    ), [user]);
+    return feeNft;
+  }
+
+  it('Seller can list NFT with the fee charged to the buyer', async () => {
+    const feeNft = await listNewNft(2);
+    let nftInfo = await getNFTPoolState(feeNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.buyerPaysFee.toNumber() == 1, "NFT SellData Fee Payer is not the buyer");
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+    const buyerSol = await provider.connection.getBalance(user1.publicKey);
+
+    await sendTx(await createPurchaseTx(
+      feeNft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 1e9, 'Seller Sol is not the full price');
+    assert((await provider.connection.getBalance(user1.publicKey)) <= buyerSol - 1_010_000_000, 'Buyer Sol is not charged the price with the 1% fee');
+  });
+  it('Default fee mode follows the global fee payer', async () => {
+    await updateFeePayer(1);
+    const feeNft = await listNewNft(0);
+    let nftInfo = await getNFTPoolState(feeNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.buyerPaysFee.toNumber() == 1, "NFT SellData Fee Payer is not the global default");
+
+    await updateFeePayer(0);
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(globalInfo.buyerPaysFee.toNumber() == 0, "Global Fee Payer is not reset to the seller");
+  });
+  it('Unknown fee modes are rejected', async () => {
+    await assertError(listNewNft(3), 'InvalidParamInput');
+    await assertError(updateFeePayer(2), 'InvalidParamInput');
+  });
+});
+
+describe('Verified Collection', async () => {
+  let collection = null;
+
+  const updateCollectionFallback = async (admin: anchor.web3.Keypair, creatorFallback: number) => {
+    await program.rpc.updateCollectionFallback(new anchor.BN(creatorFallback), {
+      accounts: {
+        admin: admin.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+      },
+      signers: [admin],
+    });
+  }
+
+  it('Listing stores the verified collection of the NFT', async () => {
+    collection = await createCollectionNft();
+    const collectionNft = await createTestNft(user, 1, 0, undefined, collection.publicKey);
+
+    await listNft(collectionNft.publicKey);
+    const nftInfo = await getNFTPoolState(collectionNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.collection.toBase58() == collection.publicKey.toBase58(), "NFT SellData Collection is not the verified collection");
+    assert(nftInfo.collectionMethod.toNumber() == 1, "NFT SellData Collection Method is not the verified collection");
+  });
+  it('Listing falls back to the first verified creator without collection', async () => {
+    const legacyNft = await createTestNft(user);
+
+    await listNft(legacyNft.publicKey);
+    const nftInfo = await getNFTPoolState(legacyNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.collection.toBase58() == superOwner.publicKey.toBase58(), "NFT SellData Collection is not the first verified creator");
+    assert(nftInfo.collectionMethod.toNumber() == 2, "NFT SellData Collection Method is not the creator fallback");
+  });
+  it('NFT without verified collection can not be listed with the fallback disabled', async () => {
+    await assertError(updateCollectionFallback(user, 0), 'InvalidSuperOwner');
+    await assertError(updateCollectionFallback(superOwner, 2), 'InvalidParamInput');
+    await updateCollectionFallback(superOwner, 0);
+
+    const legacyNft = await createTestNft(user);
+    await assertError(listNft(legacyNft.publicKey), 'NoVerifiedCollection');
+    const collectionNft = await createTestNft(user, 1, 0, undefined, collection.publicKey);
+    await listNft(collectionNft.publicKey);
+    const nftInfo = await getNFTPoolState(collectionNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.collectionMethod.toNumber() == 1, "NFT SellData Collection Method is not the verified collection");
+
+    await updateCollectionFallback(superOwner, 1);
+  });
+});
+
+describe('Registered Collections', async () => {
+  let collection = null;
+  let other = null;
+
+  // Zero padded bytes of the fixed size string field
+  const toFixedBytes = (value: string, length: number) => {
+    const bytes = Array(length).fill(0);
+    Buffer.from(value).forEach((byte, i) => bytes[i] = byte);
+    return bytes;
+  }
+  const registerCollection = async (admin: anchor.web3.Keypair, collectionKey: anchor.web3.PublicKey) => {
+    await program.rpc.registerCollection(toFixedBytes('Astro Test', 32), toFixedBytes('ASTRO', 10), {
+      accounts: {
+        admin: admin.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        collectionInfo: await getCollectionInfo(collectionKey),
+        collection: collectionKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      signers: [admin],
+    });
+  }
+  const updateListingMode = async (restricted: number) => {
+    await program.rpc.updateListingMode(new anchor.BN(restricted), {
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+      },
+      signers: [superOwner],
+    });
+  }
+
+  it('Admin can register the collection', async () => {
+    collection = await createCollectionNft();
+    other = await createCollectionNft();
+    await assertError(registerCollection(user, collection.publicKey), 'InvalidSuperOwner');
+    await registerCollection(superOwner, collection.publicKey);
+
+    const collectionInfo = await program.account.collectionInfo.fetch(await getCollectionInfo(collection.publicKey));
+    assert(collectionInfo.collection.toBase58() == collection.publicKey.toBase58(), "Collection Info is not the registered collection");
+    assert(Buffer.from(collectionInfo.symbol).toString().startsWith('ASTRO'), "Collection Info Symbol is not ASTRO");
+  });
+  it('Restricted listing accepts the registered collection NFT', async () => {
+    await updateListingMode(1);
+    const registeredNft = await createTestNft(user, 1, 0, undefined, collection.publicKey);
+
+    await listNft(registeredNft.publicKey, user, collection.publicKey);
+    let nftInfo = await getNFTPoolState(registeredNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.active.toNumber() == 1, "Registered collection NFT is not listed");
+  });
+  it('Restricted listing rejects the unregistered and the creator fallback collections', async () => {
+    const otherNft = await createTestNft(user, 1, 0, undefined, other.publicKey);
+    await assertError(listNft(otherNft.publicKey, user, other.publicKey), 'CollectionNotRegistered');
+    const legacyNft = await createTestNft(user);
+    await assertError(listNft(legacyNft.publicKey, user, superOwner.publicKey), 'CollectionNotRegistered');
+    // Collection Info PDA of the other collection is not accepted for the NFT
+    await assertError(listNft(otherNft.publicKey, user, collection.publicKey), 'InvalidCollectionInfoAccount');
+
+    await updateListingMode(0);
+  });
+  it('Admin can unregister the collection', async () => {
+    await program.rpc.unregisterCollection({
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        collectionInfo: await getCollectionInfo(collection.publicKey),
+      },
+      signers: [superOwner],
+    });
+    assert(!(await isExistAccount(await getCollectionInfo(collection.publicKey), provider.connection)), "Collection Info is not closed");
+  });
+});
+
+describe('Denylist', async () => {
+  let deniedNft = null;
+
+  const addDenyEntry = async (admin: anchor.web3.Keypair, address: anchor.web3.PublicKey, kind: number) => {
+    await program.rpc.addDenyEntry(new anchor.BN(kind), new anchor.BN(1), {
+      accounts: {
+        admin: admin.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        denyEntry: await getDenyEntry(address),
+        address,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      signers: [admin],
+    });
+  }
+  const removeDenyEntry = async (address: anchor.web3.PublicKey) => {
+    await program.rpc.removeDenyEntry({
+      accounts: {
+        admin: superOwner.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        denyEntry: await getDenyEntry(address),
+      },
+      signers: [superOwner],
+    });
+  }
+
+  it('Admin can denylist the stolen NFT mint', async () => {
+    deniedNft = await createTestNft(user);
+    await listNft(deniedNft.publicKey);
+    await assertError(addDenyEntry(user, deniedNft.publicKey, 0), 'InvalidSuperOwner');
+    await assertError(addDenyEntry(superOwner, deniedNft.publicKey, 2), 'InvalidParamInput');
+    await addDenyEntry(superOwner, deniedNft.publicKey, 0);
+
+    const denyEntry = await program.account.denyEntry.fetch(await getDenyEntry(deniedNft.publicKey));
+    assert(denyEntry.address.toBase58() == deniedNft.publicKey.toBase58(), "Deny Entry is not the stolen mint");
+    assert(denyEntry.kind.toNumber() == 0, "Deny Entry Kind is not the mint");
+  });
+  it('Denylisted mint can not be purchased', async () => {
+    await assertError(purchaseNft(deniedNft.publicKey), 'DeniedAddress');
+  });
+  it('Denylisted wallet can not list NFT', async () => {
+    await addDenyEntry(superOwner, user.publicKey, 1);
+    const nft = await createTestNft(user);
+    await assertError(listNft(nft.publicKey), 'DeniedAddress');
+    await removeDenyEntry(user.publicKey);
+
+    await listNft(nft.publicKey);
+  });
+  it('Mint removed from the denylist can be purchased again', async () => {
+    await removeDenyEntry(deniedNft.publicKey);
+    assert(!(await isExistAccount(await getDenyEntry(deniedNft.publicKey), provider.connection)), "Deny Entry is not closed");
+
+    await purchaseNft(deniedNft.publicKey);
+    let nftInfo = await getNFTPoolState(deniedNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.active.toNumber() == 0, "Purchased NFT is still listed");
+  });
+  it('Denylisted bidder can not claim the won auction', async () => {
+    const auctionNft = await createTestNft(user);
+    let now = Math.floor(Date.now() / 1000);
+    await sendTx(await createCreateAuctionTx(
+      auctionNft.publicKey,
+      user.publicKey,
+      1 * 1e9,
+      0.2 * 1e9,
+      false,
+      now + 10,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user]);
+    await sendTx(await createPlaceBidTx(
+      auctionNft.publicKey,
+      user1.publicKey,
+      1.2 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+    await addDenyEntry(superOwner, user1.publicKey, 1);
+
+    await new Promise((resolve) => setTimeout(resolve, 11000));
+    const claimAuction = async () => sendTx(await createClaimAuctionTx(
+      auctionNft.publicKey,
+      user1.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+    await assertError(claimAuction(), 'DeniedAddress');
+    await removeDenyEntry(user1.publicKey);
+
+    await claimAuction();
+    let auctionInfo = await getAuctionDataState(auctionNft.publicKey, program as unknown as anchor.Program);
+    assert(auctionInfo.status.toNumber() == 2, 'Status is not 2');
+  });
+});
+
+describe('Programmable NFT Accounts', async () => {
+  // pNFT transfer accounts without token records and rule set, missing accounts are the Token Metadata program id
+  const getPnftAccounts = async (mint: anchor.web3.PublicKey) => {
+    return [
+      await getMetadata(mint),
+      await getMasterEdition(mint),
+      METAPLEX,
+      METAPLEX,
+      METAPLEX,
+      METAPLEX,
+      anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      METAPLEX,
+    ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }));
+  }
+  // Listing transaction with the remaining accounts appended to the listing instruction
+  const listNftWithAccounts = async (
+    mint: anchor.web3.PublicKey,
+    quantity: number,
+    remainingAccounts: anchor.web3.AccountMeta[],
+  ) => {
+    const tx = await createListForSellNftTx(
+      mint,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+      0,
+      quantity,
+    );
+    tx.instructions[tx.instructions.length - 1].keys.push(...remainingAccounts);
+    await sendTx(tx, [user]);
+  }
+
+  it('Legacy NFT is listed by the Token program without pNFT accounts', async () => {
+    const legacyNft = await createTestNft(user);
+    await listNftWithAccounts(legacyNft.publicKey, 1, []);
+
+    const escrowAccount = await getAssociatedTokenAccount(await getPda(GLOBAL_AUTHORITY_SEED), legacyNft.publicKey);
+    assert((await getTokenAccountBalance(escrowAccount, provider.connection)) == 1, "Legacy NFT is not escrowed");
+  });
+  it('Incomplete pNFT accounts are rejected', async () => {
+    const nft = await createTestNft(user);
+    const pnftAccounts = await getPnftAccounts(nft.publicKey);
+
+    await assertError(listNftWithAccounts(nft.publicKey, 1, pnftAccounts.slice(0, 3)), 'InvalidPnftAccounts');
+    // The last account should be the Token Metadata program
+    pnftAccounts[8] = { pubkey: anchor.web3.SystemProgram.programId, isSigner: false, isWritable: false };
+    await assertError(listNftWithAccounts(nft.publicKey, 1, pnftAccounts), 'InvalidPnftAccounts');
+  });
+  it('pNFTs can only be listed as single units', async () => {
+    const sft = await createTestNft(user, 2);
+    await assertError(listNftWithAccounts(sft.publicKey, 2, await getPnftAccounts(sft.publicKey)), 'InvalidParamInput');
+  });
+});
+
+describe('Token-2022 NFT', async () => {
+  const TOKEN_2022_PROGRAM_ID = new anchor.web3.PublicKey('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb');
+  let token2022Nft = null;
+  let userTokenAccount = null;
+  let escrowTokenAccount = null;
+
+  const listToken2022Nft = async (nftTokenProgram: anchor.web3.PublicKey) => {
+    await program.rpc.listNftForSale(
+      new anchor.BN(1 * 1e9), new anchor.BN(100 * ABB_TOKEN_DECIMAL), 0, new anchor.BN(1), anchor.web3.PublicKey.default, {
+      accounts: {
+        owner: user.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        sellDataInfo: await getPda(SELL_DATA_SEED, token2022Nft.publicKey, user.publicKey),
+        userTokenAccount,
+        destNftTokenAccount: escrowTokenAccount,
+        nftMint: token2022Nft.publicKey,
+        mintMetadata: await getMetadata(token2022Nft.publicKey),
+        collectionInfo: await getCollectionInfo(anchor.web3.PublicKey.default),
+        mintDenyEntry: await getDenyEntry(token2022Nft.publicKey),
+        ownerDenyEntry: await getDenyEntry(user.publicKey),
+        nftTokenProgram,
+        tokenMetadataProgram: METAPLEX,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      signers: [user],
+    });
+  }
+
+  it('Mint one Token-2022 NFT for testing', async () => {
+    token2022Nft = await Token.createMint(
+      provider.connection,
+      superOwner,
+      superOwner.publicKey,
+      superOwner.publicKey,
+      0,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    userTokenAccount = await token2022Nft.createAccount(user.publicKey);
+    // Escrow token account owned by the global authority
+    escrowTokenAccount = await token2022Nft.createAccount(await getPda(GLOBAL_AUTHORITY_SEED));
+    await token2022Nft.mintTo(userTokenAccount, superOwner, [], 1);
+    await createNftMetadata(token2022Nft.publicKey);
+  });
+  it('Listing with the Token program for the Token-2022 NFT is rejected', async () => {
+    await assertError(listToken2022Nft(TOKEN_PROGRAM_ID), 'ConstraintRaw');
+  });
+  it('User can list the Token-2022 NFT by the Token-2022 program', async () => {
+    await listToken2022Nft(TOKEN_2022_PROGRAM_ID);
+
+    let nftInfo = await getNFTPoolState(token2022Nft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.active.toNumber() == 1, "Token-2022 NFT is not listed");
+    assert((await getTokenAccountBalance(escrowTokenAccount, provider.connection)) == 1, "Token-2022 NFT is not escrowed");
+  });
+});
+
+/**
+ * The Bubblegum transfer of a minted compressed NFT needs a concurrent merkle tree and keccak leaf hashes,
+ * these tests cover the leaf checks done before the Bubblegum transfer
+ */
+describe('Compressed NFT Leaf Checks', async () => {
+  const BUBBLEGUM_PROGRAM_ID = new anchor.web3.PublicKey('BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfxbNRSqbg4Z');
+  const merkleTree = anchor.web3.Keypair.generate().publicKey;
+  const metadata = {
+    name: 'Astro Test',
+    symbol: 'ASTRO',
+    uri: '',
+    sellerFeeBasisPoints: 0,
+    primarySaleHappened: false,
+    isMutable: true,
+    editionNonce: null,
+    tokenStandard: 0,
+    collection: null,
+    uses: null,
+    tokenProgramVersion: 0,
+    creators: [],
+  };
+
+  const getAssetId = async (nonce: number) => {
+    const [assetId] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from('asset'), merkleTree.toBuffer(), new anchor.BN(nonce).toArrayLike(Buffer, 'le', 8)],
+      BUBBLEGUM_PROGRAM_ID,
+    );
+    return assetId;
+  }
+  const listCompressedNft = async (assetId: anchor.web3.PublicKey, nonce: number) => {
+    const [treeAuthority] = await anchor.web3.PublicKey.findProgramAddress([merkleTree.toBuffer()], BUBBLEGUM_PROGRAM_ID);
+    const leaf = {
+      root: Array(32).fill(0),
+      dataHash: Array(32).fill(1),
+      creatorHash: Array(32).fill(2),
+      nonce: new anchor.BN(nonce),
+      index: nonce,
+    };
+    await program.rpc.listCompressedNft(
+      new anchor.BN(1 * 1e9), new anchor.BN(100 * ABB_TOKEN_DECIMAL), 0, anchor.web3.PublicKey.default, leaf, metadata, {
+      accounts: {
+        owner: user.publicKey,
+        globalAuthority: await getPda(GLOBAL_AUTHORITY_SEED),
+        sellDataInfo: await getPda(SELL_DATA_SEED, assetId, user.publicKey),
+        assetId,
+        collectionInfo: await getCollectionInfo(anchor.web3.PublicKey.default),
+        assetDenyEntry: await getDenyEntry(assetId),
+        ownerDenyEntry: await getDenyEntry(user.publicKey),
+        treeAuthority,
+        leafDelegate: user.publicKey,
+        merkleTree,
+        logWrapper: anchor.web3.SystemProgram.programId,
+        compressionProgram: anchor.web3.SystemProgram.programId,
+        bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      signers: [user],
+    });
+  }
+
+  it('Asset id derived from the merkle tree and the leaf nonce is accepted', async () => {
+    // The leaf passes the asset id check and fails on the hashes of the metadata
+    await assertError(listCompressedNft(await getAssetId(0), 0), 'InvalidCompressedMetadata');
+  });
+  it('Asset id of the other leaf is rejected', async () => {
+    await assertError(listCompressedNft(await getAssetId(1), 0), 'InvalidCompressedNftAccounts');
+    await assertError(listCompressedNft(anchor.web3.Keypair.generate().publicKey, 0), 'InvalidCompressedNftAccounts');
+  });
+});
+
+describe('NFT Bundles', async () => {
+  let bundle = [];
+
+  const meta = (pubkey: anchor.web3.PublicKey, isWritable: boolean = false) => ({ pubkey, isSigner: false, isWritable });
+  const listBundle = async (mints: anchor.web3.PublicKey[]) => {
+    const globalAuthority = await getPda(GLOBAL_AUTHORITY_SEED);
+    const ret = await getATokenAccountsNeedCreate(provider.connection, user.publicKey, globalAuthority, mints);
+    let remainingAccounts = [];
+    for (let i = 0; i < mints.length; i++) {
+      remainingAccounts.push(
+        meta(mints[i]),
+        meta(await getMetadata(mints[i])),
+        meta(await getAssociatedTokenAccount(user.publicKey, mints[i]), true),
+        meta(ret.destinationAccounts[i], true),
+        meta(await getDenyEntry(mints[i])),
+        meta(await getCollectionInfo(superOwner.publicKey)),
+      );
+    }
+    await program.rpc.listBundle(
+      new anchor.BN(2 * 1e9), new anchor.BN(200 * ABB_TOKEN_DECIMAL), 0, anchor.web3.PublicKey.default, {
+      accounts: {
+        owner: user.publicKey,
+        globalAuthority,
+        bundleDataInfo: await getPda(BUNDLE_DATA_SEED, mints[0], user.publicKey),
+        bundleMint: mints[0],
+        ownerDenyEntry: await getDenyEntry(user.publicKey),
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      instructions: ret.instructions,
+      remainingAccounts,
+      signers: [user],
+    });
+  }
+  const purchaseBundle = async (bundleMint: anchor.web3.PublicKey, mints: anchor.web3.PublicKey[]) => {
+    const globalAuthority = await getPda(GLOBAL_AUTHORITY_SEED);
+    const feeVault = await getPda(FEE_VAULT_SEED);
+    const ret = await getATokenAccountsNeedCreate(provider.connection, user1.publicKey, user1.publicKey, mints);
+    // superOwner is the only royalty creator of every item
+    let remainingAccounts = mints.map(() => meta(superOwner.publicKey, true));
+    for (let i = 0; i < mints.length; i++) {
+      remainingAccounts.push(
+        meta(mints[i]),
+        meta(await getMetadata(mints[i])),
+        meta(await getAssociatedTokenAccount(globalAuthority, mints[i]), true),
+        meta(ret.destinationAccounts[i], true),
+        meta(await getDenyEntry(mints[i])),
+      );
+    }
+    await program.rpc.purchaseBundle(0, {
+      accounts: {
+        buyer: user1.publicKey,
+        globalAuthority,
+        treasuryConfig: await getPda(TREASURY_CONFIG_SEED),
+        bundleDataInfo: await getPda(BUNDLE_DATA_SEED, bundleMint, user.publicKey),
+        buyerUserPool: await getPda(USER_DATA_SEED, user1.publicKey),
+        seller: user.publicKey,
+        sellerUserPool: await getPda(USER_DATA_SEED, user.publicKey),
+        bundleMint,
+        collectionFee: await getPda(COLLECTION_FEE_SEED, superOwner.publicKey),
+        referralData: await getReferralData(),
+        userTokenAccount: await getAssociatedTokenAccount(user1.publicKey, ABB_TOKEN_MINT),
+        sellerTokenAccount: await getAssociatedTokenAccount(user.publicKey, ABB_TOKEN_MINT),
+        feeVault,
+        feeVaultTokenAccount: await getAssociatedTokenAccount(feeVault, ABB_TOKEN_MINT),
+        buyerDenyEntry: await getDenyEntry(user1.publicKey),
+        sellerDenyEntry: await getDenyEntry(user.publicKey),
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: ABB_TOKEN_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      },
+      instructions: ret.instructions,
+      remainingAccounts,
+      signers: [user1],
+    });
+  }
+
+  it('User can list two NFTs as a bundle', async () => {
+    bundle = [(await createTestNft(user)).publicKey, (await createTestNft(user)).publicKey];
+    await listBundle(bundle);
+
+    const bundleInfo = await program.account.bundleData.fetch(await getPda(BUNDLE_DATA_SEED, bundle[0], user.publicKey));
+    assert(bundleInfo.active.toNumber() == 1, "Bundle is not listed");
+    assert(bundleInfo.itemCount.toNumber() == 2, "Bundle Item Count is not 2");
+    assert(bundleInfo.mints[1].toBase58() == bundle[1].toBase58(), "Bundle second item is not the second NFT");
+  });
+  it('Bundle of NFTs of different collections is rejected', async () => {
+    const collection = await createCollectionNft();
+    const collectionNft = await createTestNft(user, 1, 0, undefined, collection.publicKey);
+    await assertError(listBundle([collectionNft.publicKey, (await createTestNft(user)).publicKey]), 'MixedBundleCollections');
+  });
+  it('Bundle of one NFT is rejected', async () => {
+    await assertError(listBundle([(await createTestNft(user)).publicKey]), 'InvalidBundleSize');
+  });
+  it('Bundle items out of the listed order are rejected', async () => {
+    await assertError(purchaseBundle(bundle[0], [bundle[1], bundle[0]]), 'InvalidBundleAccounts');
+  });
+  it('User1 can purchase the bundle at one price', async () => {
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+    await purchaseBundle(bundle[0], bundle);
+
+    const bundleInfo = await program.account.bundleData.fetch(await getPda(BUNDLE_DATA_SEED, bundle[0], user.publicKey));
+    assert(bundleInfo.active.toNumber() == 0, "Bundle is still listed");
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 1_980_000_000, 'Seller Sol is not the bundle price without the 1% fee');
+    for (const mint of bundle) {
+      const userNFTAccount = await getAssociatedTokenAccount(user1.publicKey, mint);
+      assert((await getTokenAccountBalance(userNFTAccount, provider.connection)) == 1, "Bundle item is not transferred to user1");
+    }
+  });
+});
+
+describe('NFT Swap Offers', async () => {
+  let target = null;
+  let offered = null;
+
+  const meta = (pubkey: anchor.web3.PublicKey, isWritable: boolean = false) => ({ pubkey, isSigner: false, isWritable });
+  // Create the missing NFT token accounts of the owner in a separate transaction
+  const createTokenAccounts = async (payer: anchor.web3.Keypair, owner: anchor.web3.PublicKey, mints: anchor.web3.PublicKey[]) => {
+    const ret = await getATokenAccountsNeedCreate(provider.connection, payer.publicKey, owner, mints);
+    if (ret.instructions.length > 0) await sendTx(new anchor.web3.Transaction().add(...ret.instructions), [payer]);
+  }
+  const makeSwapOffer = async (targetMint: anchor.web3.PublicKey, items: anchor.web3.PublicKey[], price: number) => {
+    const globalAuthority = await getPda(GLOBAL_AUTHORITY_SEED);
+    const escrowVault = await getPda(ESCROW_VAULT_SEED);
+    await createTokenAccounts(user1, globalAuthority, items);
+    let remainingAccounts = [];
+    for (const mint of items) {
+      remainingAccounts.push(
+        meta(mint),
+        meta(await getAssociatedTokenAccount(user1.publicKey, mint), true),
+        meta(await getAssociatedTokenAccount(globalAuthority, mint), true),
+        meta(await getDenyEntry(mint)),
+      );
+    }
+    await program.rpc.makeSwapOffer(new anchor.BN(price), new anchor.BN(0), {
+      accounts: {
+        owner: user1.publicKey,
+        swapOfferInfo: await getPda(SWAP_OFFER_SEED, targetMint, user1.publicKey),
+        targetMint,
+        userPool: await getPda(USER_DATA_SEED, user1.publicKey),
+        globalAuthority,
+        escrowVault,
+        userTokenAccount: await getAssociatedTokenAccount(user1.publicKey, ABB_TOKEN_MINT),
+        escrowTokenAccount: await getAssociatedTokenAccount(escrowVault, ABB_TOKEN_MINT),
+        mintMetadata: await getMetadata(targetMint),
+        collectionFee: await getPda(COLLECTION_FEE_SEED, superOwner.publicKey),
+        targetDenyEntry: await getDenyEntry(targetMint),
+        ownerDenyEntry: await getDenyEntry(user1.publicKey),
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: ABB_TOKEN_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      },
+      remainingAccounts,
+      signers: [user1],
+    });
+  }
+  const acceptSwapOffer = async (
+    targetMint: anchor.web3.PublicKey,
+    items: anchor.web3.PublicKey[],
+    sellerNftTokenAccount: anchor.web3.PublicKey,
+  ) => {
+    const globalAuthority = await getPda(GLOBAL_AUTHORITY_SEED);
+    const escrowVault = await getPda(ESCROW_VAULT_SEED);
+    const feeVault = await getPda(FEE_VAULT_SEED);
+    let remainingAccounts = [
+      // superOwner is the royalty creator of the target NFT
+      meta(superOwner.publicKey, true),
+    ];
+    for (const mint of items) {
+      remainingAccounts.push(
+        meta(mint),
+        meta(await getAssociatedTokenAccount(globalAuthority, mint), true),
+        meta(await getAssociatedTokenAccount(user.publicKey, mint), true),
+      );
+    }
+    await program.rpc.acceptSwapOffer({
+      accounts: {
+        seller: user.publicKey,
+        buyer: user1.publicKey,
+        swapOfferInfo: await getPda(SWAP_OFFER_SEED, targetMint, user1.publicKey),
+        sellerNftTokenAccount,
+        sellDataInfo: await getPda(SELL_DATA_SEED, targetMint, user.publicKey),
+        buyerNftTokenAccount: await getAssociatedTokenAccount(user1.publicKey, targetMint),
+        targetMint,
+        mintMetadata: await getMetadata(targetMint),
+        collectionFee: await getPda(COLLECTION_FEE_SEED, superOwner.publicKey),
+        referralData: await getReferralData(),
+        globalAuthority,
+        treasuryConfig: await getPda(TREASURY_CONFIG_SEED),
+        buyerUserPool: await getPda(USER_DATA_SEED, user1.publicKey),
+        sellerUserPool: await getPda(USER_DATA_SEED, user.publicKey),
+        escrowVault,
+        userTokenAccount: await getAssociatedTokenAccount(user.publicKey, ABB_TOKEN_MINT),
+        escrowTokenAccount: await getAssociatedTokenAccount(escrowVault, ABB_TOKEN_MINT),
+        feeVault,
+        feeVaultTokenAccount: await getAssociatedTokenAccount(feeVault, ABB_TOKEN_MINT),
+        targetDenyEntry: await getDenyEntry(targetMint),
+        buyerDenyEntry: await getDenyEntry(user1.publicKey),
+        sellerDenyEntry: await getDenyEntry(user.publicKey),
+        targetTokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: ABB_TOKEN_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      },
+      remainingAccounts,
+      signers: [user],
+    });
+  }
+
+  it('User1 can offer an NFT with SOL for the NFT of user', async () => {
+    target = await createTestNft(user);
+    offered = await createTestNft(user1);
+    const escrowSol = await provider.connection.getBalance(await getPda(ESCROW_VAULT_SEED));
+    await makeSwapOffer(target.publicKey, [offered.publicKey], 0.1 * 1e9);
+
+    const swapInfo = await program.account.swapOfferData.fetch(await getPda(SWAP_OFFER_SEED, target.publicKey, user1.publicKey));
+    assert(swapInfo.active.toNumber() == 1, "Swap Offer is not active");
+    assert(swapInfo.itemCount.toNumber() == 1, "Swap Offer Item Count is not 1");
+    // The price and the 1% fee are locked in the escrow vault
+    assert(swapInfo.deposit.toNumber() == 101_000_000, "Swap Offer Deposit is not the price with the fee");
+    assert((await provider.connection.getBalance(await getPda(ESCROW_VAULT_SEED))) == escrowSol + 101_000_000, "Escrow Vault Sol is not increased by the deposit");
+  });
+  it('Swap offers without items or offering the target are rejected', async () => {
+    const other = await createTestNft(user);
+    await assertError(makeSwapOffer(other.publicKey, [], 0), 'InvalidSwapItemCount');
+    await assertError(makeSwapOffer(other.publicKey, [other.publicKey], 0), 'InvalidSwapItemAccounts');
+  });
+  it('User can accept the swap offer', async () => {
+    await createTokenAccounts(user, user.publicKey, [offered.publicKey]);
+    await createTokenAccounts(user1, user1.publicKey, [target.publicKey]);
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+    let userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    const buyerEscrowSol = userInfo.escrowSolBalance.toNumber();
+
+    await acceptSwapOffer(
+      target.publicKey,
+      [offered.publicKey],
+      await getAssociatedTokenAccount(user.publicKey, target.publicKey),
+    );
+
+    const swapInfo = await program.account.swapOfferData.fetch(await getPda(SWAP_OFFER_SEED, target.publicKey, user1.publicKey));
+    assert(swapInfo.active.toNumber() == 0, "Swap Offer is still active");
+    assert((await getTokenAccountBalance(await getAssociatedTokenAccount(user1.publicKey, target.publicKey), provider.connection)) == 1, "Target NFT is not transferred to user1");
+    assert((await getTokenAccountBalance(await getAssociatedTokenAccount(user.publicKey, offered.publicKey), provider.connection)) == 1, "Offered NFT is not transferred to user");
+    // The seller pays the 1% fee and the transaction fee of 5000 lamports
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 99_000_000 - 5000, 'Seller Sol is not the price without the 1% fee');
+    // The fee locked for the buyer is unlocked to the escrow balance
+    userInfo = await getUserPoolState(user1.publicKey, program as unknown as anchor.Program);
+    assert(userInfo.escrowSolBalance.toNumber() == buyerEscrowSol + 1_000_000, "Buyer escrow balance is not credited with the unused fee");
+  });
+  it('User can accept the swap offer for the listed NFT from the listing escrow', async () => {
+    const listedTarget = await createTestNft(user);
+    const listedOffered = await createTestNft(user1);
+    await listNft(listedTarget.publicKey);
+    await makeSwapOffer(listedTarget.publicKey, [listedOffered.publicKey], 0.1 * 1e9);
+    await createTokenAccounts(user, user.publicKey, [listedOffered.publicKey]);
+    await createTokenAccounts(user1, user1.publicKey, [listedTarget.publicKey]);
+
+    await acceptSwapOffer(
+      listedTarget.publicKey,
+      [listedOffered.publicKey],
+      await getAssociatedTokenAccount(await getPda(GLOBAL_AUTHORITY_SEED), listedTarget.publicKey),
+    );
+
+    let nftInfo = await getNFTPoolState(listedTarget.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.active.toNumber() == 0, "Swapped NFT is still listed");
+    assert((await getTokenAccountBalance(await getAssociatedTokenAccount(user1.publicKey, listedTarget.publicKey), provider.connection)) == 1, "Listed NFT is not transferred to user1");
+  });
+});
+
+describe('Semi-Fungible Token Partial Fills', async () => {
+  let sft = null;
+
+  const purchaseSft = async (quantity: number) => {
+    await sendTx(await createPurchaseTx(
+      sft.publicKey,
+      user.publicKey,
+      user1.publicKey,
+      false,
+      quantity,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [user1]);
+  }
+
+  it('User can list 5 units of the semi-fungible token', async () => {
+    sft = await createTestNft(user, 5);
+    await sendTx(await createListForSellNftTx(
+      sft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      0.1 * 1e9,
+      10 * ABB_TOKEN_DECIMAL,
+      0,
+      5,
+    ), [user]);
+
+    let nftInfo = await getNFTPoolState(sft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.quantity.toNumber() == 5, "SFT SellData Quantity is not 5");
+    const escrowAccount = await getAssociatedTokenAccount(await getPda(GLOBAL_AUTHORITY_SEED), sft.publicKey);
+    assert((await getTokenAccountBalance(escrowAccount, provider.connection)) == 5, "SFT units are not escrowed");
+  });
+  it('User can not list more units than the held', async () => {
+    const other = await createTestNft(user, 2);
+    const tx = await createListForSellNftTx(
+      other.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      0.1 * 1e9,
+      10 * ABB_TOKEN_DECIMAL,
+      0,
+      3,
+    );
+    await assertError(sendTx(tx, [user]), 'InvalidNftTokenAccount');
+  });
+  it('User1 can purchase 2 units at the unit price', async () => {
+    const sellerSol = await provider.connection.getBalance(user.publicKey);
+    await purchaseSft(2);
+
+    let nftInfo = await getNFTPoolState(sft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.quantity.toNumber() == 3, "SFT SellData Quantity is not 3");
+    assert(nftInfo.active.toNumber() == 1, "SFT listing is not active with the units left");
+    assert((await provider.connection.getBalance(user.publicKey)) == sellerSol + 198_000_000, 'Seller Sol is not the price of 2 units without the 1% fee');
+    const userSftAccount = await getAssociatedTokenAccount(user1.publicKey, sft.publicKey);
+    assert((await getTokenAccountBalance(userSftAccount, provider.connection)) == 2, "User1 does not hold 2 units");
+  });
+  it('User1 can not purchase more units than left', async () => {
+    await assertError(purchaseSft(4), 'InvalidParamInput');
+  });
+  it('Purchase of the last units closes the listing', async () => {
+    await purchaseSft(3);
+
+    let nftInfo = await getNFTPoolState(sft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.quantity.toNumber() == 0, "SFT SellData Quantity is not 0");
+    assert(nftInfo.active.toNumber() == 0, "SFT listing is still active");
+    const userSftAccount = await getAssociatedTokenAccount(user1.publicKey, sft.publicKey);
+    assert((await getTokenAccountBalance(userSftAccount, provider.connection)) == 5, "User1 does not hold all 5 units");
+  });
+});
+
+describe('Private Listings', async () => {
+  const stranger = anchor.web3.Keypair.generate();
+  let privateNft = null;
+
+  const purchasePrivateNft = async (buyer: anchor.web3.Keypair) => {
+    await sendTx(await createPurchaseTx(
+      privateNft.publicKey,
+      user.publicKey,
+      buyer.publicKey,
+      false,
+      1,
+      program as unknown as anchor.Program,
+      provider.connection,
+    ), [buyer]);
+  }
+
+  it('User can list NFT only for user1', async () => {
+    await airdropSOL(stranger.publicKey, 5 * 1e9, provider.connection);
+    await sendTx(await createInitUserTx(stranger.publicKey, program as unknown as anchor.Program), [stranger]);
+    privateNft = await createTestNft(user);
+    await sendTx(await createListForSellNftTx(
+      privateNft.publicKey,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1 * 1e9,
+      100 * ABB_TOKEN_DECIMAL,
+      0,
+      1,
+      user1.publicKey,
+    ), [user]);
+
+    let nftInfo = await getNFTPoolState(privateNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.reservedBuyer.toBase58() == user1.publicKey.toBase58(), "NFT SellData Reserved Buyer is not user1");
+  });
+  it('Other buyers can not purchase or make offer for the private listing', async () => {
+    await assertError(purchasePrivateNft(stranger), 'ReservedListing');
+    const tx = await createMakeOfferTx(
+      privateNft.publicKey,
+      user.publicKey,
+      stranger.publicKey,
+      0.8 * 1e9,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+    );
+    await assertError(sendTx(tx, [stranger]), 'ReservedListing');
+  });
+  it('Reserved buyer can purchase the private listing', async () => {
+    await purchasePrivateNft(user1);
+
+    let nftInfo = await getNFTPoolState(privateNft.publicKey, user.publicKey, program as unknown as anchor.Program);
+    assert(nftInfo.active.toNumber() == 0, "Private listing is still active");
+    const userNFTAccount = await getAssociatedTokenAccount(user1.publicKey, privateNft.publicKey);
+    assert((await getTokenAccountBalance(userNFTAccount, provider.connection)) == 1, "NFT is not transferred to the reserved buyer");
+  });
+});